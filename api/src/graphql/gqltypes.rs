@@ -9,6 +9,7 @@ pub enum Equipment {
     MultiPly,
     Unlimited,
     Straps,
+    Unknown,
 }
 
 impl From<opltypes::Equipment> for Equipment {
@@ -20,6 +21,7 @@ impl From<opltypes::Equipment> for Equipment {
             opltypes::Equipment::Multi => Equipment::MultiPly,
             opltypes::Equipment::Unlimited => Equipment::Unlimited,
             opltypes::Equipment::Straps => Equipment::Straps,
+            opltypes::Equipment::Unknown => Equipment::Unknown,
         }
     }
 }
@@ -16,6 +16,11 @@ pub struct CheckResult {
     pub config: Option<Config>,
 }
 
+/// The default high-water mark for entries in a single entries.csv.
+///
+/// Files larger than this are usually several meets concatenated by mistake.
+pub const DEFAULT_MAX_ENTRIES: usize = 3000;
+
 #[derive(Debug)]
 pub struct Config {
     pub options: Option<OptionConfig>,
@@ -34,6 +39,10 @@ pub struct OptionConfig {
     /// If set to true, pending disambiguations governed by this configuration
     /// become errors.
     pub require_manual_disambiguation: bool,
+
+    /// Overrides the high-water mark for the number of entries in a meet,
+    /// for federations that legitimately hold enormous meets.
+    pub max_entries: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -55,6 +64,18 @@ pub struct DivisionConfig {
     pub place: Option<Place>,
 }
 
+impl DivisionConfig {
+    /// The eligibility requirements for lifters in this division.
+    pub fn spec(&self) -> DivisionSpec {
+        DivisionSpec {
+            min_age: self.min,
+            max_age: self.max,
+            sex: self.sex,
+            equipment: self.equipment.clone(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct WeightClassConfig {
     /// The name of the TOML table member.
@@ -135,6 +156,15 @@ impl Config {
             false
         }
     }
+
+    /// Returns options.max_entries if present, defaulting to
+    /// `DEFAULT_MAX_ENTRIES`.
+    pub fn max_entries(&self) -> usize {
+        self.options
+            .as_ref()
+            .and_then(|o| o.max_entries)
+            .unwrap_or(DEFAULT_MAX_ENTRIES)
+    }
 }
 
 fn parse_options(value: &Value, report: &mut Report) -> Option<OptionConfig> {
@@ -170,9 +200,22 @@ fn parse_options(value: &Value, report: &mut Report) -> Option<OptionConfig> {
         }
     }
 
+    let mut max_entries = None;
+    if let Some(v) = table.get("max_entries") {
+        match v.as_integer().filter(|&n| n > 0) {
+            Some(n) => {
+                max_entries = Some(n as usize);
+            }
+            None => {
+                report.error("Value 'max_entries' must be a positive integer");
+            }
+        }
+    }
+
     Some(OptionConfig {
         valid_since,
         require_manual_disambiguation,
+        max_entries,
     })
 }
 
@@ -1,8 +1,10 @@
 //! Checks for entries.csv files.
 
 use coefficients::{glossbrenner, wilks};
+use fxhash::{FxHashMap, FxHashSet};
 use opltypes::states::*;
 use opltypes::*;
+use rayon::prelude::*;
 use strum::IntoEnumIterator;
 use unicode_normalization::UnicodeNormalization;
 
@@ -10,10 +12,10 @@ use std::error::Error;
 use std::io;
 use std::path::PathBuf;
 
-use crate::checklib::config::{Config, Exemption, WeightClassConfig};
+use crate::checklib::config::{Config, Exemption, WeightClassConfig, DEFAULT_MAX_ENTRIES};
 use crate::checklib::lifterdata::LifterDataMap;
 use crate::checklib::meet::Meet;
-use crate::{EntryIndex, Report};
+use crate::{EntryIndex, Message, Report};
 
 /// List of all plausible weightclasses, for non-configured federations.
 const DEFAULT_WEIGHTCLASSES: [WeightClassKg; 51] = [
@@ -85,6 +87,9 @@ impl HeaderIndexMap {
     }
 }
 
+/// How many rows are read into memory and checked together.
+const ROW_CHUNK_SIZE: usize = 1024;
+
 pub struct EntriesCheckResult {
     pub report: Report,
     pub entries: Option<Vec<Entry>>,
@@ -264,6 +269,7 @@ enum Header {
     AgeRange,
     Country,
     EntryDate,
+    Federation,
 
     WeightClassKg,
     BodyweightKg,
@@ -313,6 +319,11 @@ fn check_headers(
         return HeaderIndexMap(header_index_vec);
     }
 
+    // A byte-order mark gets read as part of the first header.
+    if headers[0].starts_with('\u{feff}') {
+        report.fixable_error("File must not begin with a byte-order mark");
+    }
+
     let mut has_squat = false;
     let mut has_bench = false;
     let mut has_deadlift = false;
@@ -482,7 +493,7 @@ fn check_column_name(name: &str, line: u64, report: &mut Report) -> String {
         // Name components must usually start capitalized, with exceptions.
         for c in word.chars().take(1) {
             if !c.is_uppercase() {
-                report.error_on(
+                report.fixable_error_on(
                     line,
                     format!("Name '{}' must have '{}' capitalized", name, word),
                 );
@@ -505,7 +516,7 @@ fn check_column_name(name: &str, line: u64, report: &mut Report) -> String {
 
     // Suffices that must be fully-capitalized.
     if s.ends_with("Ii") || s.ends_with("Iii") {
-        report.error_on(
+        report.fixable_error_on(
             line,
             format!("Name '{}' must have suffix fully-capitalized", name),
         );
@@ -665,6 +676,10 @@ fn check_column_sex(s: &str, line: u64, report: &mut Report) -> Sex {
 
 fn check_column_equipment(s: &str, line: u64, report: &mut Report) -> Equipment {
     match s.parse::<Equipment>() {
+        Ok(Equipment::Unknown) => {
+            report.error_on(line, "Equipment cannot be blank");
+            Equipment::Multi
+        }
         Ok(eq) => eq,
         Err(_) => {
             report.error_on(line, format!("Invalid Equipment '{}'", s));
@@ -850,6 +865,28 @@ fn check_column_event(s: &str, line: u64, headers: &HeaderIndexMap, report: &mut
     }
 }
 
+/// Gets the lift described by a weight column, if any.
+fn lift_for(header: Header) -> Option<Lift> {
+    match header {
+        Header::Squat1Kg
+        | Header::Squat2Kg
+        | Header::Squat3Kg
+        | Header::Squat4Kg
+        | Header::Best3SquatKg => Some(Lift::Squat),
+        Header::Bench1Kg
+        | Header::Bench2Kg
+        | Header::Bench3Kg
+        | Header::Bench4Kg
+        | Header::Best3BenchKg => Some(Lift::Bench),
+        Header::Deadlift1Kg
+        | Header::Deadlift2Kg
+        | Header::Deadlift3Kg
+        | Header::Deadlift4Kg
+        | Header::Best3DeadliftKg => Some(Lift::Deadlift),
+        _ => None,
+    }
+}
+
 /// Tests a column describing the amount of weight lifted.
 fn check_weight(s: &str, line: u64, header: Header, report: &mut Report) -> WeightKg {
     // Disallow zeros.
@@ -870,6 +907,11 @@ fn check_weight(s: &str, line: u64, header: Header, report: &mut Report) -> Weig
                     line,
                     format!("{} '{}' exceeds maximum expected weight", header, s),
                 )
+            } else if lift_for(header).is_some_and(|lift| !w.is_plausible_lift(lift)) {
+                report.warning_on(
+                    line,
+                    format!("{} '{}' is implausibly heavy: is it a typo?", header, s),
+                )
             }
             w
         }
@@ -887,11 +929,9 @@ fn check_nonnegative_weight(s: &str, line: u64, header: Header, report: &mut Rep
     check_weight(s, line, header, report)
 }
 
-fn check_column_bodyweightkg(s: &str, line: u64, report: &mut Report) -> WeightKg {
+fn check_column_bodyweightkg(s: &str, sex: Sex, line: u64, report: &mut Report) -> WeightKg {
     let weight = check_nonnegative_weight(s, line, Header::BodyweightKg, report);
-    if weight != WeightKg::from_i32(0)
-        && (weight < WeightKg::from_i32(15) || weight > WeightKg::from_i32(300))
-    {
+    if weight != WeightKg::from_i32(0) && !weight.is_plausible_bodyweight(sex) {
         report.error_on(line, format!("Implausible BodyweightKg '{}'", s));
     }
     weight
@@ -988,6 +1028,26 @@ fn check_column_entrydate(s: &str, line: u64, report: &mut Report) -> Option<Dat
     }
 }
 
+/// Checks the "Federation" column against the meet.csv Federation.
+fn check_column_federation(s: &str, meet: Option<&Meet>, line: u64, report: &mut Report) {
+    match s.parse::<Federation>() {
+        Ok(fed) => {
+            if let Some(meet) = meet {
+                if fed != meet.federation {
+                    let msg = format!(
+                        "Federation '{}' does not match the meet.csv Federation '{}'",
+                        s, meet.federation
+                    );
+                    report.warning_on(line, msg);
+                }
+            }
+        }
+        Err(_) => {
+            report.error_on(line, format!("Unknown Federation '{}'", s));
+        }
+    }
+}
+
 /// Checks the "State" column.
 ///
 /// If the lifter's Country is explicitly specified, the State is checked
@@ -1134,13 +1194,24 @@ fn check_event_and_total_consistency(entry: &Entry, line: u64, report: &mut Repo
 
     // Check that a non-DQ lifter's total is the sum of their best attempts,
     // if their lifts have been recorded.
+    //
+    // Where attempts are recorded, the best successful attempt is used
+    // instead of the Best3 column, which is checked against the attempts
+    // separately. Otherwise, only the Best3 column is available.
+    let squat_attempt = best_successful_attempt(entry.squat1kg, entry.squat2kg, entry.squat3kg);
+    let bench_attempt = best_successful_attempt(entry.bench1kg, entry.bench2kg, entry.bench3kg);
+    let deadlift_attempt =
+        best_successful_attempt(entry.deadlift1kg, entry.deadlift2kg, entry.deadlift3kg);
+
+    let squat = squat_attempt.unwrap_or(entry.best3squatkg);
+    let bench = bench_attempt.unwrap_or(entry.best3benchkg);
+    let deadlift = deadlift_attempt.unwrap_or(entry.best3deadliftkg);
+
     if !entry.place.is_dq()
         && has_totalkg
-        && (entry.best3squatkg.is_non_zero()
-            || entry.best3benchkg.is_non_zero()
-            || entry.best3deadliftkg.is_non_zero())
+        && (squat.is_non_zero() || bench.is_non_zero() || deadlift.is_non_zero())
     {
-        let calculated = entry.best3squatkg + entry.best3benchkg + entry.best3deadliftkg;
+        let calculated = squat + bench + deadlift;
 
         if (calculated - entry.totalkg).abs() > WeightKg::from_f32(0.5) {
             let s = format!(
@@ -1151,6 +1222,27 @@ fn check_event_and_total_consistency(entry: &Entry, line: u64, report: &mut Repo
         }
     }
 
+    // A lifter who failed every recorded attempt bombed out, so a TotalKg
+    // is probably left over from another row.
+    let attempts = [squat_attempt, bench_attempt, deadlift_attempt];
+    let recorded = || attempts.iter().flatten();
+    if has_totalkg && recorded().count() > 0 && recorded().all(|w| w.is_zero()) {
+        let s = format!("Every attempt failed, but TotalKg is '{}'", entry.totalkg);
+        report.error_on(line, s);
+    } else if has_totalkg {
+        // A single bombed lift usually also means there's no total.
+        let lifts = ["squat", "bench", "deadlift"];
+        for (lift, attempt) in lifts.iter().zip(attempts.iter()) {
+            if attempt.is_some_and(|w| w.is_zero()) {
+                let s = format!(
+                    "Every {} attempt failed, but TotalKg is '{}'",
+                    lift, entry.totalkg
+                );
+                report.warning_on(line, s);
+            }
+        }
+    }
+
     // Check that the TotalKg isn't something completely nonsensical.
     // Usually this occurs when pounds were mislabeled as kilograms.
     // The current Multi-ply record is 1407.5.
@@ -1165,6 +1257,25 @@ fn check_event_and_total_consistency(entry: &Entry, line: u64, report: &mut Repo
     }
 }
 
+/// Gets the heaviest successful attempt of the first three, or zero if all failed.
+///
+/// Returns `None` if no attempts were recorded.
+fn best_successful_attempt(
+    attempt1: WeightKg,
+    attempt2: WeightKg,
+    attempt3: WeightKg,
+) -> Option<WeightKg> {
+    if attempt1.is_zero() && attempt2.is_zero() && attempt3.is_zero() {
+        return None;
+    }
+    Some(
+        attempt1
+            .max(attempt2)
+            .max(attempt3)
+            .max(WeightKg::from_i32(0)),
+    )
+}
+
 // Compares an attempt versus the current ascending weight.
 // Returns the new value for maxweight.
 fn process_attempt_pair(
@@ -1215,6 +1326,10 @@ fn process_attempt_pair(
     }
 }
 
+/// Checks the attempts of a lift, and that they agree with its Best3 column.
+///
+/// The Best3 column must be the heaviest successful attempt. If every attempt
+/// failed, it may either be blank or the lightest failed attempt.
 #[allow(clippy::too_many_arguments)]
 fn check_attempt_consistency_helper(
     lift: &str,
@@ -1331,7 +1446,7 @@ fn check_equipment_year(entry: &Entry, meet: Option<&Meet>, line: u64, report: &
     // Helper function for checking equipped status.
     fn is_equipped(e: Option<Equipment>) -> bool {
         e.map_or(false, |eq| match eq {
-            Equipment::Raw | Equipment::Wraps | Equipment::Straps => false,
+            Equipment::Raw | Equipment::Wraps | Equipment::Straps | Equipment::Unknown => false,
             Equipment::Single | Equipment::Multi | Equipment::Unlimited => true,
         })
     }
@@ -1608,6 +1723,11 @@ fn check_weightclass_consistency(
 /// Checks internal age consistency, and checks that the CONFIG-controlled Age
 /// range is consistent with the known data.
 ///
+/// The Age range is checked through the division's `DivisionSpec`, like Sex
+/// and Equipment. BirthYear-based divisions get an additional stricter check
+/// here, since the spec deliberately accepts any Approximate Age that could
+/// be in range.
+///
 /// Returns the (min_age, max_age) associated with the Division.
 fn check_division_age_consistency(
     entry: &Entry,
@@ -1629,7 +1749,7 @@ fn check_division_age_consistency(
     // figure out how old the lifter would be on the meet date.
     let age_from_birthdate: Option<Age> = entry.birthdate.map(|birthdate| {
         // Unwrapping is safe: the BirthDate column check already validated.
-        birthdate.age_on(meet_date).unwrap()
+        Age::from_birthdate_and_meetdate(Birth::Date(birthdate), meet_date).unwrap()
     });
 
     let birthyear: Option<u32> = entry.birthyearrange.exact_birthyear();
@@ -1669,34 +1789,16 @@ fn check_division_age_consistency(
     }
 
     // Pairwise check Age and BirthDate.
-    match entry.age {
-        Age::Exact(age) => {
-            if let Some(Age::Exact(bd_age)) = age_from_birthdate {
-                if age != bd_age {
-                    let s = format!(
-                        "Age '{}' doesn't match BirthDate '{}', expected '{}'",
-                        entry.age,
-                        entry.birthdate.unwrap(),
-                        bd_age
-                    );
-                    report.error_on(line, s);
-                }
-            }
-        }
-        Age::Approximate(age) => {
-            if let Some(Age::Exact(bd_age)) = age_from_birthdate {
-                if age != bd_age && age != bd_age + 1 {
-                    let s = format!(
-                        "Age '{}' doesn't match BirthDate '{}, expected '{}'",
-                        entry.age,
-                        entry.birthdate.unwrap(),
-                        bd_age
-                    );
-                    report.error_on(line, s);
-                }
-            }
+    // An Approximate Age agrees with either of its two possible ages.
+    if let (Some(birthdate), Some(bd_age)) = (entry.birthdate, age_from_birthdate) {
+        if bd_age.is_definitely_less_than(entry.age) || bd_age.is_definitely_greater_than(entry.age)
+        {
+            let s = format!(
+                "Age '{}' doesn't match BirthDate '{}', expected '{}'",
+                entry.age, birthdate, bd_age
+            );
+            report.error_on(line, s);
         }
-        Age::None => (),
     }
 
     // Allow exemptions from division-specific checks.
@@ -1720,12 +1822,13 @@ fn check_division_age_consistency(
     }
 
     // Division string errors are already handled by check_column_division().
-    let (min_age, max_age) = match config.divisions.iter().find(|d| d.name == entry.division) {
-        Some(div) => (div.min, div.max),
+    let spec = match config.divisions.iter().find(|d| d.name == entry.division) {
+        Some(div) => div.spec(),
         None => {
             return (Age::None, Age::None);
         }
     };
+    let (min_age, max_age) = (spec.min_age, spec.max_age);
 
     // Use the various age-related columns to calculate a representative Age value.
     let age = entry.age_on(meet_date);
@@ -1808,20 +1911,15 @@ fn check_division_sex_consistency(
         }
     };
 
-    // Get the configured sex for the division, or return if not specified.
-    let sex = match config.divisions.iter().find(|d| d.name == entry.division) {
-        Some(div) => match div.sex {
-            Some(sex) => sex,
-            None => {
-                return;
-            }
-        },
+    // Division string errors are already handled by check_column_division().
+    let spec = match config.divisions.iter().find(|d| d.name == entry.division) {
+        Some(div) => div.spec(),
         None => {
             return;
         }
     };
 
-    if sex != entry.sex {
+    if let Some(sex) = spec.sex.filter(|_| !spec.accepts_sex(entry.sex)) {
         report.error_on(
             line,
             format!(
@@ -1832,6 +1930,53 @@ fn check_division_sex_consistency(
     }
 }
 
+/// Words in a Division name that imply the Sex of its lifters.
+///
+/// Words are matched case-insensitively against the whole words of the Division.
+const SEXED_DIVISION_WORDS: [(&str, Sex); 14] = [
+    ("boys", Sex::M),
+    ("female", Sex::F),
+    ("females", Sex::F),
+    ("girls", Sex::F),
+    ("ladies", Sex::F),
+    ("male", Sex::M),
+    ("males", Sex::M),
+    ("men", Sex::M),
+    ("men's", Sex::M),
+    ("mens", Sex::M),
+    ("woman", Sex::F),
+    ("women", Sex::F),
+    ("women's", Sex::F),
+    ("womens", Sex::F),
+];
+
+/// Checks that the Sex is consistent with any sexed words in the Division name.
+///
+/// Unlike the configured restrictions, this only warns, since the Division
+/// names are free-form.
+fn check_division_sex_words(entry: &Entry, line: u64, report: &mut Report) {
+    // Mx lifters may compete in any division.
+    if entry.sex == Sex::Mx {
+        return;
+    }
+
+    let division = entry.division.to_lowercase();
+    let implied = division
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .find_map(|word| SEXED_DIVISION_WORDS.iter().find(|(w, _)| *w == word))
+        .map(|&(_, sex)| sex);
+
+    if let Some(sex) = implied.filter(|&sex| sex != entry.sex) {
+        report.warning_on(
+            line,
+            format!(
+                "Division '{}' implies Sex '{:?}', found '{:?}'",
+                entry.division, sex, entry.sex
+            ),
+        );
+    }
+}
+
 /// Checks that a configured division is consistent with any Place restrictions.
 fn check_division_place_consistency(
     entry: &Entry,
@@ -1896,20 +2041,15 @@ fn check_division_equipment_consistency(
         }
     };
 
-    // Get the configured sex for the division, or return if not specified.
-    let eqlist = match config.divisions.iter().find(|d| d.name == entry.division) {
-        Some(div) => match &div.equipment {
-            Some(vec) => vec,
-            None => {
-                return;
-            }
-        },
+    // Division string errors are already handled by check_column_division().
+    let spec = match config.divisions.iter().find(|d| d.name == entry.division) {
+        Some(div) => div.spec(),
         None => {
             return;
         }
     };
 
-    if !eqlist.contains(&equipment) {
+    if !spec.accepts_equipment(equipment) {
         report.error_on(
             line,
             format!(
@@ -1951,6 +2091,437 @@ fn should_ignore_config(meet: Option<&Meet>, config: Option<&Config>) -> bool {
     false
 }
 
+/// Everything outside of a row that the per-row checks depend on.
+#[derive(Clone, Copy)]
+struct RowContext<'a> {
+    headers: &'a HeaderIndexMap,
+    meet: Option<&'a Meet>,
+    config: Option<&'a Config>,
+    lifterdata: Option<&'a LifterDataMap>,
+    default_date: Date,
+    report_disambiguations: bool,
+    fourths_may_lower: bool,
+    exempt_lift_order: bool,
+    exempt_division: bool,
+    exempt_weightclass_consistency: bool,
+    exempt_age: bool,
+}
+
+/// Checks a single row of an entries.csv, independently of all other rows.
+///
+/// Returns the parsed Entry, along with any messages about the row.
+fn check_row(record: &csv::StringRecord, context: &RowContext) -> (Entry, Vec<Message>) {
+    let RowContext {
+        headers,
+        meet,
+        config,
+        lifterdata,
+        default_date,
+        report_disambiguations,
+        fourths_may_lower,
+        exempt_lift_order,
+        exempt_division,
+        exempt_weightclass_consistency,
+        exempt_age,
+    } = *context;
+
+    // Messages are merged into the file's Report by the caller.
+    let mut report = Report::new(PathBuf::new());
+
+    let line = record.position().map_or(0, csv::Position::line);
+
+    // Check each field for whitespace errors.
+    for field in record {
+        if field.contains("  ") || field.starts_with(' ') || field.ends_with(' ') {
+            let msg = format!("Field '{}' contains extraneous spacing", field);
+            report.fixable_error_on(line, msg);
+        }
+    }
+
+    let mut entry = Entry {
+        entrydate: default_date, // Either a default, or sourced from the meet.csv.
+        ..Default::default()
+    };
+
+    // Check mandatory fields.
+    if let Some(idx) = headers.get(Header::Name) {
+        entry.name = check_column_name(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Sex) {
+        entry.sex = check_column_sex(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Equipment) {
+        entry.equipment = check_column_equipment(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::SquatEquipment) {
+        entry.squat_equipment = check_column_squatequipment(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::BenchEquipment) {
+        entry.bench_equipment = check_column_benchequipment(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::DeadliftEquipment) {
+        entry.deadlift_equipment = check_column_deadliftequipment(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Place) {
+        entry.place = check_column_place(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Age) {
+        entry.age = check_column_age(&record[idx], exempt_age, line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Event) {
+        entry.event = check_column_event(&record[idx], line, headers, &mut report);
+    }
+
+    // Check all the weight fields: they must contain non-zero values.
+    // Squat.
+    if let Some(idx) = headers.get(Header::Squat1Kg) {
+        entry.squat1kg = check_weight(&record[idx], line, Header::Squat1Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Squat2Kg) {
+        entry.squat2kg = check_weight(&record[idx], line, Header::Squat2Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Squat3Kg) {
+        entry.squat3kg = check_weight(&record[idx], line, Header::Squat3Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Squat4Kg) {
+        entry.squat4kg = check_weight(&record[idx], line, Header::Squat4Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Best3SquatKg) {
+        entry.best3squatkg = check_weight(&record[idx], line, Header::Best3SquatKg, &mut report);
+    }
+
+    // Bench.
+    if let Some(idx) = headers.get(Header::Bench1Kg) {
+        entry.bench1kg = check_weight(&record[idx], line, Header::Bench1Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Bench2Kg) {
+        entry.bench2kg = check_weight(&record[idx], line, Header::Bench2Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Bench3Kg) {
+        entry.bench3kg = check_weight(&record[idx], line, Header::Bench3Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Bench4Kg) {
+        entry.bench4kg = check_weight(&record[idx], line, Header::Bench4Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Best3BenchKg) {
+        entry.best3benchkg = check_weight(&record[idx], line, Header::Best3BenchKg, &mut report);
+    }
+
+    // Deadlift.
+    if let Some(idx) = headers.get(Header::Deadlift1Kg) {
+        entry.deadlift1kg = check_weight(&record[idx], line, Header::Deadlift1Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Deadlift2Kg) {
+        entry.deadlift2kg = check_weight(&record[idx], line, Header::Deadlift2Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Deadlift3Kg) {
+        entry.deadlift3kg = check_weight(&record[idx], line, Header::Deadlift3Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Deadlift4Kg) {
+        entry.deadlift4kg = check_weight(&record[idx], line, Header::Deadlift4Kg, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::Best3DeadliftKg) {
+        entry.best3deadliftkg =
+            check_weight(&record[idx], line, Header::Best3DeadliftKg, &mut report);
+    }
+
+    // TotalKg is a positive weight if present or 0 if missing.
+    if let Some(idx) = headers.get(Header::TotalKg) {
+        entry.totalkg = check_nonnegative_weight(&record[idx], line, Header::TotalKg, &mut report);
+    }
+
+    if let Some(idx) = headers.get(Header::BodyweightKg) {
+        entry.bodyweightkg = check_column_bodyweightkg(&record[idx], entry.sex, line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::WeightClassKg) {
+        entry.weightclasskg = check_column_weightclasskg(&record[idx], line, &mut report);
+    }
+
+    // If no bodyweight is given but there is a bounded weightclass,
+    // assume the pessimal case of the lifter at the top of the class.
+    if entry.bodyweightkg.is_zero() {
+        if let WeightClassKg::UnderOrEqual(w) = entry.weightclasskg {
+            entry.bodyweightkg = w;
+        }
+    }
+
+    // Set the Tested column early for federations that are fully-Tested.
+    // This allows check_column_tested() to override it later if needed.
+    if let Some(meet) = meet {
+        entry.tested = meet.federation.is_fully_tested(meet.date);
+    }
+
+    // Check optional fields.
+    if let Some(idx) = headers.get(Header::Division) {
+        check_column_division(&record[idx], config, exempt_division, line, &mut report);
+        entry.division = record[idx].to_string();
+    }
+
+    // Assign the Tested column if it's configured for the Division.
+    entry.tested = tested_from_division_config(&entry, config);
+
+    // Check the Country and State information.
+    if let Some(idx) = headers.get(Header::Country) {
+        entry.country = check_column_country(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::EntryDate) {
+        if let Some(date) = check_column_entrydate(&record[idx], line, &mut report) {
+            entry.entrydate = date;
+        }
+    }
+    if let Some(idx) = headers.get(Header::Federation) {
+        check_column_federation(&record[idx], meet, line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::State) {
+        let c = entry.country;
+        entry.state = check_column_state(&record[idx], c, meet, line, &mut report);
+
+        // If the Country was not explicitly specified, but the State was,
+        // the lifter's Country is inferrable from the MeetCountry.
+        if entry.country.is_none() {
+            entry.country = entry.state.map(|s| s.to_country());
+        }
+    }
+
+    if let Some(idx) = headers.get(Header::Tested) {
+        // Blank "Tested" columns default to the federation configuration.
+        if let Some(tested) = check_column_tested(&record[idx], line, &mut report) {
+            entry.tested = tested;
+        }
+    }
+    if let Some(idx) = headers.get(Header::CyrillicName) {
+        entry.cyrillicname = check_column_cyrillicname(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::JapaneseName) {
+        entry.japanesename = check_column_japanesename(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::GreekName) {
+        entry.greekname = check_column_greekname(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::KoreanName) {
+        entry.koreanname = check_column_koreanname(&record[idx], line, &mut report);
+    }
+    if let Some(idx) = headers.get(Header::BirthYear) {
+        if let Some(y) = check_column_birthyear(&record[idx], meet, line, &mut report) {
+            entry.birthyearrange = BirthYearRange::from_birthyear(y);
+        }
+    }
+    if let Some(idx) = headers.get(Header::BirthDate) {
+        entry.birthdate = check_column_birthdate(&record[idx], meet, line, &mut report);
+    }
+
+    // Check consistency across fields.
+    check_event_and_total_consistency(&entry, line, &mut report);
+    check_attempt_consistency(
+        &entry,
+        exempt_lift_order,
+        fourths_may_lower,
+        line,
+        &mut report,
+    );
+    check_equipment_year(&entry, meet, line, &mut report);
+    check_weightclass_consistency(
+        &entry,
+        meet,
+        config,
+        exempt_weightclass_consistency,
+        line,
+        &mut report,
+    );
+
+    let (division_age_min, division_age_max) =
+        check_division_age_consistency(&entry, meet, config, exempt_division, line, &mut report);
+
+    check_division_sex_words(&entry, line, &mut report);
+    if !exempt_division {
+        check_division_sex_consistency(&entry, config, line, &mut report);
+        check_division_place_consistency(&entry, config, line, &mut report);
+        check_division_equipment_consistency(&entry, config, line, &mut report);
+    }
+
+    // If the Age wasn't assigned yet, infer it from any surrounding information.
+    if let Some(meet) = meet {
+        if entry.age == Age::None {
+            if let Some(birthdate) = entry.birthdate {
+                entry.age = birthdate.age_on(meet.date).unwrap_or(Age::None);
+            }
+        }
+        if entry.age == Age::None {
+            if let Some(birthyear) = entry.birthyearrange.exact_birthyear() {
+                entry.age = Age::from_birthyear_on_date(birthyear, meet.date);
+            }
+        }
+    }
+
+    // Infer the AgeRange based on Age or Division.
+    let range_from_age = AgeRange::from(entry.age);
+    let inferred_agerange = if range_from_age.is_some() {
+        range_from_age
+    } else {
+        // Fall back to Division-based ranges if the exact Age isn't specified.
+        AgeRange::from((division_age_min, division_age_max))
+    };
+
+    // The AgeRange can also be specified explicitly in an optional column.
+    if let Some(idx) = headers.get(Header::AgeRange) {
+        entry.agerange = check_column_agerange(&record[idx], inferred_agerange, line, &mut report);
+    } else {
+        entry.agerange = inferred_agerange;
+    }
+
+    // Try narrowing the BirthYearRange based on surrounding information.
+    if let Some(birthdate) = entry.birthdate {
+        entry.birthyearrange = BirthYearRange::from_birthyear(birthdate.year());
+    } else if let Some(meet) = meet {
+        // Try using the AgeRange.
+        entry.birthyearrange = entry.birthyearrange.intersect(BirthYearRange::from_range(
+            entry.agerange.min,
+            entry.agerange.max,
+            meet.date,
+        ));
+
+        // Try using division information.
+        entry.birthyearrange = entry.birthyearrange.intersect(BirthYearRange::from_range(
+            division_age_min,
+            division_age_max,
+            meet.date,
+        ));
+    }
+
+    // Infer the BirthYearClass.
+    if let Some(meet) = meet {
+        entry.birthyearclass = BirthYearClass::from_range(entry.birthyearrange, meet.date.year());
+    }
+
+    // Calculate points (except for McCulloch, which is Age-dependent).
+    let bw = entry.bodyweightkg;
+    entry.wilks = wilks(entry.sex, bw, entry.totalkg);
+    entry.glossbrenner = glossbrenner(entry.sex, bw, entry.totalkg);
+
+    // If the Name isn't provided, but there is an international name,
+    // just use the international name.
+    if entry.name.is_empty() {
+        if let Some(idx) = headers.get(Header::JapaneseName) {
+            entry.name = record[idx].to_string();
+        }
+    }
+    if entry.name.is_empty() {
+        if let Some(idx) = headers.get(Header::ChineseName) {
+            entry.name = record[idx].to_string();
+        }
+    }
+    if entry.name.is_empty() {
+        if let Some(idx) = headers.get(Header::KoreanName) {
+            entry.name = record[idx].to_string();
+        }
+    }
+
+    // Create the username if applicable.
+    if !entry.name.is_empty() {
+        match Username::from_name(&entry.name) {
+            Ok(username) => entry.username = username,
+            Err(msg) => report.error_on(line, format!("Username error: {}", msg)),
+        }
+    }
+
+    // If requested, report if the username requires disambiguation.
+    if report_disambiguations && !entry.username.as_str().is_empty() {
+        if let Some(data) = lifterdata.and_then(|map| map.get(&entry.username)) {
+            if data.disambiguation_count > 0 {
+                let url = format!("https://www.openpowerlifting.org/u/{}", entry.username);
+                report.error_on(line, format!("Disambiguate {} ({})", entry.name, url));
+            }
+        }
+    }
+
+    if entry.name.is_empty() {
+        report.error_on(line, "No Name was given or could be inferred");
+    }
+
+    (entry, report.messages)
+}
+
+/// Checks for a lifter accidentally entered twice into the same division.
+///
+/// Names are compared by Username, so differences in accents or capitalization
+/// don't hide a duplicate. A lifter may legitimately appear more than once in a
+/// division if they competed in different events or equipment, so those rows
+/// are only flagged if the Event, Equipment, and TotalKg all match.
+/// Returns the indices of the duplicates.
+fn check_duplicate_entries(entries: &[Entry], lines: &[u64], report: &mut Report) -> Vec<usize> {
+    let mut by_division: FxHashMap<(&Username, &str), Vec<usize>> = FxHashMap::default();
+    for (i, entry) in entries.iter().enumerate() {
+        if !entry.username.as_str().is_empty() {
+            let key = (&entry.username, entry.division.as_str());
+            by_division.entry(key).or_default().push(i);
+        }
+    }
+
+    // Pairs of (duplicate, first occurrence).
+    let mut duplicates: Vec<(usize, usize)> = Vec::new();
+    for indices in by_division.values() {
+        for (n, &later) in indices.iter().enumerate() {
+            let a = &entries[later];
+            let first = indices[..n].iter().find(|&&i| {
+                let b = &entries[i];
+                a.event == b.event && a.equipment == b.equipment && a.totalkg == b.totalkg
+            });
+            if let Some(&first) = first {
+                duplicates.push((later, first));
+            }
+        }
+    }
+
+    // Report in file order.
+    duplicates.sort_unstable();
+    for &(later, first) in &duplicates {
+        report.warning_on(
+            lines[later],
+            format!(
+                "Lifter '{}' appears to duplicate line {}",
+                entries[later].name, lines[first]
+            ),
+        );
+    }
+    duplicates.into_iter().map(|(later, _)| later).collect()
+}
+
+/// Warns when different spellings of a Name produce the same Username.
+///
+/// These are usually the same lifter with a typo, like a missing accent.
+/// Different lifters may legitimately share a Username, so this only warns.
+///
+/// Rows already reported as duplicates are skipped.
+fn check_name_spellings(
+    entries: &[Entry],
+    lines: &[u64],
+    duplicates: &[usize],
+    report: &mut Report,
+) {
+    // The first row with each Username.
+    let mut first_row: FxHashMap<&Username, usize> = FxHashMap::default();
+    // Each alternate spelling is only reported once.
+    let mut reported: FxHashSet<(&Username, &str)> = FxHashSet::default();
+
+    for (i, entry) in entries.iter().enumerate() {
+        if entry.username.as_str().is_empty() || duplicates.contains(&i) {
+            continue;
+        }
+
+        let first = *first_row.entry(&entry.username).or_insert(i);
+        let original = &entries[first].name;
+        if entry.name != *original && reported.insert((&entry.username, &entry.name)) {
+            report.warning_on(
+                lines[i],
+                format!(
+                    "Name '{}' has the same username as '{}' on line {}: is one misspelled?",
+                    entry.name, original, lines[first]
+                ),
+            );
+        }
+    }
+}
+
 /// Checks a single entries.csv file from an open `csv::Reader`.
 ///
 /// Extracting this out into a `Reader`-specific function is useful
@@ -2006,330 +2577,61 @@ where
     }
     let default_date = meet.map_or_else(Date::default, |m| m.date);
 
-    let mut entries: Vec<Entry> = Vec::new();
-
-    // This allocation can be re-used for each row.
-    let mut record = csv::StringRecord::new();
-    while rdr.read_record(&mut record)? {
-        let line = record.position().map_or(0, csv::Position::line);
-
-        // Check each field for whitespace errors.
-        for field in &record {
-            if field.contains("  ") || field.starts_with(' ') || field.ends_with(' ') {
-                let msg = format!("Field '{}' contains extraneous spacing", field);
-                report.error_on(line, msg);
-            }
-        }
-
-        let mut entry = Entry {
-            entrydate: default_date, // Either a default, or sourced from the meet.csv.
-            ..Default::default()
-        };
-
-        // Check mandatory fields.
-        if let Some(idx) = headers.get(Header::Name) {
-            entry.name = check_column_name(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Sex) {
-            entry.sex = check_column_sex(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Equipment) {
-            entry.equipment = check_column_equipment(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::SquatEquipment) {
-            entry.squat_equipment = check_column_squatequipment(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::BenchEquipment) {
-            entry.bench_equipment = check_column_benchequipment(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::DeadliftEquipment) {
-            entry.deadlift_equipment =
-                check_column_deadliftequipment(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Place) {
-            entry.place = check_column_place(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Age) {
-            entry.age = check_column_age(&record[idx], exempt_age, line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Event) {
-            entry.event = check_column_event(&record[idx], line, &headers, &mut report);
-        }
-
-        // Check all the weight fields: they must contain non-zero values.
-        // Squat.
-        if let Some(idx) = headers.get(Header::Squat1Kg) {
-            entry.squat1kg = check_weight(&record[idx], line, Header::Squat1Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Squat2Kg) {
-            entry.squat2kg = check_weight(&record[idx], line, Header::Squat2Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Squat3Kg) {
-            entry.squat3kg = check_weight(&record[idx], line, Header::Squat3Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Squat4Kg) {
-            entry.squat4kg = check_weight(&record[idx], line, Header::Squat4Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Best3SquatKg) {
-            entry.best3squatkg =
-                check_weight(&record[idx], line, Header::Best3SquatKg, &mut report);
-        }
-
-        // Bench.
-        if let Some(idx) = headers.get(Header::Bench1Kg) {
-            entry.bench1kg = check_weight(&record[idx], line, Header::Bench1Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Bench2Kg) {
-            entry.bench2kg = check_weight(&record[idx], line, Header::Bench2Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Bench3Kg) {
-            entry.bench3kg = check_weight(&record[idx], line, Header::Bench3Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Bench4Kg) {
-            entry.bench4kg = check_weight(&record[idx], line, Header::Bench4Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Best3BenchKg) {
-            entry.best3benchkg =
-                check_weight(&record[idx], line, Header::Best3BenchKg, &mut report);
-        }
-
-        // Deadlift.
-        if let Some(idx) = headers.get(Header::Deadlift1Kg) {
-            entry.deadlift1kg = check_weight(&record[idx], line, Header::Deadlift1Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Deadlift2Kg) {
-            entry.deadlift2kg = check_weight(&record[idx], line, Header::Deadlift2Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Deadlift3Kg) {
-            entry.deadlift3kg = check_weight(&record[idx], line, Header::Deadlift3Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Deadlift4Kg) {
-            entry.deadlift4kg = check_weight(&record[idx], line, Header::Deadlift4Kg, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::Best3DeadliftKg) {
-            entry.best3deadliftkg =
-                check_weight(&record[idx], line, Header::Best3DeadliftKg, &mut report);
-        }
-
-        // TotalKg is a positive weight if present or 0 if missing.
-        if let Some(idx) = headers.get(Header::TotalKg) {
-            entry.totalkg =
-                check_nonnegative_weight(&record[idx], line, Header::TotalKg, &mut report);
-        }
-
-        if let Some(idx) = headers.get(Header::BodyweightKg) {
-            entry.bodyweightkg = check_column_bodyweightkg(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::WeightClassKg) {
-            entry.weightclasskg = check_column_weightclasskg(&record[idx], line, &mut report);
-        }
-
-        // If no bodyweight is given but there is a bounded weightclass,
-        // assume the pessimal case of the lifter at the top of the class.
-        if entry.bodyweightkg.is_zero() {
-            if let WeightClassKg::UnderOrEqual(w) = entry.weightclasskg {
-                entry.bodyweightkg = w;
-            }
-        }
-
-        // Set the Tested column early for federations that are fully-Tested.
-        // This allows check_column_tested() to override it later if needed.
-        if let Some(meet) = meet {
-            entry.tested = meet.federation.is_fully_tested(meet.date);
-        }
-
-        // Check optional fields.
-        if let Some(idx) = headers.get(Header::Division) {
-            check_column_division(&record[idx], config, exempt_division, line, &mut report);
-            entry.division = record[idx].to_string();
-        }
-
-        // Assign the Tested column if it's configured for the Division.
-        entry.tested = tested_from_division_config(&entry, config);
-
-        // Check the Country and State information.
-        if let Some(idx) = headers.get(Header::Country) {
-            entry.country = check_column_country(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::EntryDate) {
-            if let Some(date) = check_column_entrydate(&record[idx], line, &mut report) {
-                entry.entrydate = date;
-            }
-        }
-        if let Some(idx) = headers.get(Header::State) {
-            let c = entry.country;
-            entry.state = check_column_state(&record[idx], c, meet, line, &mut report);
-
-            // If the Country was not explicitly specified, but the State was,
-            // the lifter's Country is inferrable from the MeetCountry.
-            if entry.country.is_none() {
-                entry.country = entry.state.map(|s| s.to_country());
-            }
-        }
-
-        if let Some(idx) = headers.get(Header::Tested) {
-            // Blank "Tested" columns default to the federation configuration.
-            if let Some(tested) = check_column_tested(&record[idx], line, &mut report) {
-                entry.tested = tested;
-            }
-        }
-        if let Some(idx) = headers.get(Header::CyrillicName) {
-            entry.cyrillicname = check_column_cyrillicname(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::JapaneseName) {
-            entry.japanesename = check_column_japanesename(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::GreekName) {
-            entry.greekname = check_column_greekname(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::KoreanName) {
-            entry.koreanname = check_column_koreanname(&record[idx], line, &mut report);
-        }
-        if let Some(idx) = headers.get(Header::BirthYear) {
-            if let Some(y) = check_column_birthyear(&record[idx], meet, line, &mut report) {
-                entry.birthyearrange = BirthYearRange::from_birthyear(y);
-            }
-        }
-        if let Some(idx) = headers.get(Header::BirthDate) {
-            entry.birthdate = check_column_birthdate(&record[idx], meet, line, &mut report);
-        }
-
-        // Check consistency across fields.
-        check_event_and_total_consistency(&entry, line, &mut report);
-        check_attempt_consistency(
-            &entry,
-            exempt_lift_order,
-            fourths_may_lower,
-            line,
-            &mut report,
-        );
-        check_equipment_year(&entry, meet, line, &mut report);
-        check_weightclass_consistency(
-            &entry,
-            meet,
-            config,
-            exempt_weightclass_consistency,
-            line,
-            &mut report,
-        );
-
-        let (division_age_min, division_age_max) = check_division_age_consistency(
-            &entry,
-            meet,
-            config,
-            exempt_division,
-            line,
-            &mut report,
-        );
-
-        if !exempt_division {
-            check_division_sex_consistency(&entry, config, line, &mut report);
-            check_division_place_consistency(&entry, config, line, &mut report);
-            check_division_equipment_consistency(&entry, config, line, &mut report);
-        }
-
-        // If the Age wasn't assigned yet, infer it from any surrounding information.
-        if let Some(meet) = meet {
-            if entry.age == Age::None {
-                if let Some(birthdate) = entry.birthdate {
-                    entry.age = birthdate.age_on(meet.date).unwrap_or(Age::None);
-                }
-            }
-            if entry.age == Age::None {
-                if let Some(birthyear) = entry.birthyearrange.exact_birthyear() {
-                    entry.age = Age::from_birthyear_on_date(birthyear, meet.date);
-                }
-            }
-        }
-
-        // Infer the AgeRange based on Age or Division.
-        let range_from_age = AgeRange::from(entry.age);
-        let inferred_agerange = if range_from_age.is_some() {
-            range_from_age
-        } else {
-            // Fall back to Division-based ranges if the exact Age isn't specified.
-            AgeRange::from((division_age_min, division_age_max))
-        };
-
-        // The AgeRange can also be specified explicitly in an optional column.
-        if let Some(idx) = headers.get(Header::AgeRange) {
-            entry.agerange =
-                check_column_agerange(&record[idx], inferred_agerange, line, &mut report);
-        } else {
-            entry.agerange = inferred_agerange;
-        }
-
-        // Try narrowing the BirthYearRange based on surrounding information.
-        if let Some(birthdate) = entry.birthdate {
-            entry.birthyearrange = BirthYearRange::from_birthyear(birthdate.year());
-        } else if let Some(meet) = meet {
-            // Try using the AgeRange.
-            entry.birthyearrange = entry.birthyearrange.intersect(BirthYearRange::from_range(
-                entry.agerange.min,
-                entry.agerange.max,
-                meet.date,
-            ));
-
-            // Try using division information.
-            entry.birthyearrange = entry.birthyearrange.intersect(BirthYearRange::from_range(
-                division_age_min,
-                division_age_max,
-                meet.date,
-            ));
-        }
-
-        // Infer the BirthYearClass.
-        if let Some(meet) = meet {
-            entry.birthyearclass =
-                BirthYearClass::from_range(entry.birthyearrange, meet.date.year());
-        }
-
-        // Calculate points (except for McCulloch, which is Age-dependent).
-        let bw = entry.bodyweightkg;
-        entry.wilks = wilks(entry.sex, bw, entry.totalkg);
-        entry.glossbrenner = glossbrenner(entry.sex, bw, entry.totalkg);
+    let context = RowContext {
+        headers: &headers,
+        meet,
+        config,
+        lifterdata,
+        default_date,
+        report_disambiguations,
+        fourths_may_lower,
+        exempt_lift_order,
+        exempt_division,
+        exempt_weightclass_consistency,
+        exempt_age,
+    };
 
-        // If the Name isn't provided, but there is an international name,
-        // just use the international name.
-        if entry.name.is_empty() {
-            if let Some(idx) = headers.get(Header::JapaneseName) {
-                entry.name = record[idx].to_string();
-            }
-        }
-        if entry.name.is_empty() {
-            if let Some(idx) = headers.get(Header::ChineseName) {
-                entry.name = record[idx].to_string();
-            }
-        }
-        if entry.name.is_empty() {
-            if let Some(idx) = headers.get(Header::KoreanName) {
-                entry.name = record[idx].to_string();
-            }
+    // Rows are checked independently and in parallel, one chunk at a time,
+    // so only a chunk of raw records is held in memory at once.
+    // Collecting preserves the row order, so the Report is deterministic.
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut lines: Vec<u64> = Vec::new();
+    let mut chunk: Vec<csv::StringRecord> = Vec::with_capacity(ROW_CHUNK_SIZE);
+    let mut records = rdr.records();
+    loop {
+        chunk.clear();
+        for record in records.by_ref().take(ROW_CHUNK_SIZE) {
+            chunk.push(record?);
+        }
+        if chunk.is_empty() {
+            break;
         }
 
-        // Create the username if applicable.
-        if !entry.name.is_empty() {
-            match Username::from_name(&entry.name) {
-                Ok(username) => entry.username = username,
-                Err(msg) => report.error_on(line, format!("Username error: {}", msg)),
-            }
-        }
+        let rows: Vec<(Entry, Vec<Message>)> = chunk
+            .par_iter()
+            .map(|record| check_row(record, &context))
+            .collect();
 
-        // If requested, report if the username requires disambiguation.
-        if report_disambiguations && !entry.username.as_str().is_empty() {
-            if let Some(data) = lifterdata.and_then(|map| map.get(&entry.username)) {
-                if data.disambiguation_count > 0 {
-                    let url = format!("https://www.openpowerlifting.org/u/{}", entry.username);
-                    report.error_on(line, format!("Disambiguate {} ({})", entry.name, url));
-                }
-            }
+        for (record, (entry, messages)) in chunk.iter().zip(rows) {
+            report.messages.extend(messages);
+            entries.push(entry);
+            lines.push(record.position().map_or(0, csv::Position::line));
         }
+    }
 
-        if entry.name.is_empty() {
-            report.error_on(line, "No Name was given or could be inferred");
-        }
+    // Cross-row checks run after all the rows are known.
+    let duplicates = check_duplicate_entries(&entries, &lines, &mut report);
+    check_name_spellings(&entries, &lines, &duplicates, &mut report);
 
-        entries.push(entry);
+    // Catch multiple meets concatenated into a single file.
+    let max_entries = config.map_or(DEFAULT_MAX_ENTRIES, Config::max_entries);
+    if entries.len() > max_entries {
+        report.warning(format!(
+            "File has {} entries, more than the expected maximum of {}: \
+             are multiple meets combined?",
+            entries.len(),
+            max_entries
+        ));
     }
 
     Ok(EntriesCheckResult {
@@ -55,6 +55,9 @@ const REQUIRED_HEADERS: [&str; 6] = [
     "MeetName",
 ];
 
+/// The maximum length of the final component of a MeetPath.
+pub const MAX_MEETPATH_COMPONENT_LEN: usize = 32;
+
 /// Optional headers may appear after the required ones.
 const OPTIONAL_HEADERS: [&str; 1] = ["RuleSet"];
 
@@ -72,25 +75,78 @@ fn check_headers(headers: &csv::StringRecord, report: &mut Report) {
         return;
     }
 
+    // A byte-order mark gets read as part of the first header.
+    if headers[0].starts_with('\u{feff}') {
+        report.fixable_error("File must not begin with a byte-order mark");
+    }
+
     // Check required headers.
     for (i, header) in headers.iter().take(REQUIRED_HEADERS.len()).enumerate() {
         if header != REQUIRED_HEADERS[i] {
-            report.error(format!("Column {} must be '{}'", i, REQUIRED_HEADERS[i]));
+            let msg = format!("Column {} must be '{}'", i, REQUIRED_HEADERS[i]);
+            report.error(msg + &suggest_header(header));
         }
     }
 
     // Check optional headers.
     for header in headers.iter().skip(REQUIRED_HEADERS.len()) {
         if !OPTIONAL_HEADERS.contains(&header) {
-            report.error(format!("Unknown optional column '{}'", &header));
+            let msg = format!("Unknown optional column '{}'", &header);
+            report.error(msg + &suggest_header(header));
+        }
+    }
+}
+
+/// Headers within this edit distance of a known header are likely typos.
+const MAX_HEADER_TYPO_DISTANCE: usize = 2;
+
+/// Calculates the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    // Distances from the prefix of `a` seen so far to each prefix of `b`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
         }
     }
+    row[b.len()]
+}
+
+/// Suggests the known header that a mistyped header was probably meant to be.
+///
+/// Returns an empty string if no header is close.
+fn suggest_header(header: &str) -> String {
+    REQUIRED_HEADERS
+        .iter()
+        .chain(OPTIONAL_HEADERS.iter())
+        .map(|known| (edit_distance(header, known), known))
+        .filter(|&(distance, _)| distance > 0 && distance <= MAX_HEADER_TYPO_DISTANCE)
+        .min_by_key(|&(distance, _)| distance)
+        .map_or_else(String::new, |(_, known)| {
+            format!(": found '{}', did you mean '{}'?", header, known)
+        })
 }
 
 /// Checks that the MeetPath contains only characters valid in a URL.
 pub fn check_meetpath(report: &mut Report) -> Option<String> {
     match opltypes::file_to_meetpath(&report.path) {
-        Ok(s) => Some(s),
+        Ok(s) => {
+            // Overly-long directory names break downstream tooling.
+            let name = s.rsplit('/').next().unwrap_or_default();
+            if name.len() > MAX_MEETPATH_COMPONENT_LEN {
+                report.error(format!(
+                    "Meet folder '{}' is longer than {} characters",
+                    name, MAX_MEETPATH_COMPONENT_LEN
+                ));
+            }
+            Some(s)
+        }
         Err(MeetPathError::NonAsciiError) => {
             report.error("Path must only contain alphanumeric ASCII or '/-' characters");
             None
@@ -135,8 +191,9 @@ pub fn check_date(s: &str, report: &mut Report) -> Option<Date> {
     let date = date.unwrap();
 
     // The date should not be implausibly long ago.
-    if date.year() < 1945 {
-        report.error(format!("Implausible year in '{}'", s));
+    // Powerlifting wasn't codified until around 1950, so earlier years are suspect.
+    if date.year() < 1950 {
+        report.warning(format!("Meet in '{}' predates codified powerlifting", s));
     }
 
     // This is sufficiently fast to call that caching is of no practical benefit.
@@ -148,7 +205,7 @@ pub fn check_date(s: &str, report: &mut Report) -> Option<Date> {
         || (date.year() == y && date.month() > m)
         || (date.year() == y && date.month() == m && date.day() > d)
     {
-        report.error(format!("Meet occurs in the future in '{}'", s));
+        report.warning(format!("Meet occurs in the future in '{}'", s));
     }
 
     // The date should exist in the Gregorian calendar.
@@ -181,6 +238,14 @@ pub fn check_meetcountry(s: &str, report: &mut Report) -> Option<Country> {
     }
 }
 
+/// Federations that always record a MeetState for meets in their home country.
+const MEETSTATE_REQUIRED_AT_HOME: [Federation; 2] = [Federation::USAPL, Federation::USPA];
+
+/// Whether a federation must record a MeetState for a meet in the country.
+fn requires_meetstate(federation: Federation, country: Country) -> bool {
+    MEETSTATE_REQUIRED_AT_HOME.contains(&federation) && federation.home_country() == Some(country)
+}
+
 /// Checks the optional MeetState column.
 pub fn check_meetstate(s: &str, report: &mut Report, country: Option<Country>) -> Option<State> {
     if s.is_empty() {
@@ -198,6 +263,8 @@ pub fn check_meetstate(s: &str, report: &mut Report, country: Option<Country>) -
 
     match State::from_str_and_country(s, country) {
         Ok(s) => Some(s),
+        // Without State data for the Country, there's nothing to check against.
+        Err(StateParseError::CountryHasNoStates(_)) => None,
         Err(_) => {
             let cstr = country.to_string();
             report.error(format!("Unknown state '{}' for country '{}'", s, cstr));
@@ -219,7 +286,7 @@ pub fn check_meettown(s: &str, report: &mut Report) -> Option<String> {
 
     // Check for excessive spacing.
     if s.contains("  ") || s.starts_with(' ') || s.ends_with(' ') {
-        report.error(format!("Excessive whitespace in MeetTown '{}'", s));
+        report.fixable_error(format!("Excessive whitespace in MeetTown '{}'", s));
     }
 
     if s.is_empty() {
@@ -229,6 +296,12 @@ pub fn check_meettown(s: &str, report: &mut Report) -> Option<String> {
     }
 }
 
+/// Finds a standalone 4-digit year in a MeetName, like "2015 Nationals".
+fn year_in_name(name: &str) -> Option<&str> {
+    name.split(|c: char| !c.is_ascii_digit())
+        .find(|digits| digits.len() == 4 && (digits.starts_with("19") || digits.starts_with("20")))
+}
+
 /// Checks the mandatory MeetName column.
 pub fn check_meetname(s: &str, report: &mut Report, fedstr: &str, datestr: &str) -> Option<String> {
     if s.is_empty() {
@@ -246,7 +319,7 @@ pub fn check_meetname(s: &str, report: &mut Report, fedstr: &str, datestr: &str)
 
     // Check for excessive spacing.
     if s.contains("  ") || s.starts_with(' ') || s.ends_with(' ') {
-        report.error(format!("Excessive whitespace in MeetName '{}'", s));
+        report.fixable_error(format!("Excessive whitespace in MeetName '{}'", s));
     }
 
     // The federation shouldn't be part of the name.
@@ -257,8 +330,18 @@ pub fn check_meetname(s: &str, report: &mut Report, fedstr: &str, datestr: &str)
     // The year shouldn't be part of the name.
     if let Some(idx) = datestr.find('-') {
         let year = &datestr[0..idx];
-        if s.contains(year) {
+        if s.starts_with(year) {
+            // A leading year is redundant with the Date, and can just be dropped.
+            report.fixable_error(format!("MeetName '{}' must not begin with the year", s));
+        } else if s.contains(year) {
             report.error(format!("MeetName '{}' must not contain the year", s));
+        } else if let Some(other) = year_in_name(s) {
+            // Usually a copy-paste error, but some series are named after
+            // the year they qualify for.
+            report.warning(format!(
+                "MeetName '{}' mentions the year {}, but the Date is in {}",
+                s, other, year
+            ));
         }
     }
 
@@ -316,7 +399,7 @@ where
 
     // Verify column headers. Only continue if they're valid.
     check_headers(rdr.headers()?, &mut report);
-    if !report.messages.is_empty() {
+    if initial_errors != report.count_errors() {
         return Ok(MeetCheckResult { report, meet: None });
     }
 
@@ -330,9 +413,40 @@ where
     // Check the required columns.
     let federation = check_federation(record.get(0).unwrap(), &mut report);
     let date = check_date(record.get(1).unwrap(), &mut report);
-    let country = check_meetcountry(record.get(2).unwrap(), &mut report);
-    let state = check_meetstate(record.get(3).unwrap(), &mut report, country);
+
+    // Swapped MeetCountry and MeetState columns get a single targeted error.
+    let (countrystr, statestr) = (record.get(2).unwrap(), record.get(3).unwrap());
+    let (country, state) =
+        if countrystr.parse::<Country>().is_err() && looks_swapped(countrystr, statestr) {
+            report.fixable_error(format!(
+                "MeetCountry '{}' and MeetState '{}' appear swapped",
+                countrystr, statestr
+            ));
+            (None, None)
+        } else {
+            let country = check_meetcountry(countrystr, &mut report);
+            (country, check_meetstate(statestr, &mut report, country))
+        };
+
+    if let (Some(federation), Some(country)) = (federation, country) {
+        if statestr.is_empty() && requires_meetstate(federation, country) {
+            report.error(format!(
+                "{} meets in {} require a MeetState",
+                federation, countrystr
+            ));
+        }
+    }
+
     let town = check_meettown(record.get(4).unwrap(), &mut report);
+
+    // A state without a town usually means the row was cut short.
+    // Some meets, like online meets, legitimately have no town.
+    if country.is_some() && !statestr.is_empty() && town.is_none() {
+        report.warning(format!(
+            "MeetState '{}' is present, but MeetTown is missing",
+            statestr
+        ));
+    }
     let name = check_meetname(
         record.get(5).unwrap(),
         &mut report,
@@ -376,6 +490,66 @@ where
     }
 }
 
+/// Gets the column order that would restore the expected headers, if the
+/// headers are exactly the expected columns in a different order.
+fn reordered_columns(headers: &csv::StringRecord) -> Option<Vec<usize>> {
+    let optional = OPTIONAL_HEADERS
+        .iter()
+        .filter(|&&h| headers.iter().any(|header| header == h));
+    let expected: Vec<&str> = REQUIRED_HEADERS.iter().chain(optional).copied().collect();
+    if headers.len() != expected.len() {
+        return None;
+    }
+
+    let order: Vec<usize> = expected
+        .iter()
+        .map(|h| headers.iter().position(|header| header == *h))
+        .collect::<Option<_>>()?;
+
+    // Headers that are already in order need no help.
+    if order.iter().enumerate().all(|(i, &j)| i == j) {
+        None
+    } else {
+        Some(order)
+    }
+}
+
+/// Checks a meet.csv like `do_check()`, but tolerates reordered columns.
+///
+/// If the only problem with the headers is their order, the columns are
+/// reordered in memory and checked normally, with a single warning.
+pub fn do_check_lenient<R>(
+    rdr: &mut csv::Reader<R>,
+    config: Option<&Config>,
+    mut report: Report,
+    meetpath: String,
+) -> Result<MeetCheckResult, Box<dyn Error>>
+where
+    R: io::Read,
+{
+    let headers = rdr.headers()?.clone();
+    let order = match reordered_columns(&headers) {
+        Some(order) => order,
+        None => return do_check(rdr, config, report, meetpath),
+    };
+
+    report.warning(format!(
+        "Columns are out of order: expected '{}'",
+        REQUIRED_HEADERS.join(",")
+    ));
+
+    let mut wtr = csv::Writer::from_writer(vec![]);
+    wtr.write_record(order.iter().map(|&i| &headers[i]))?;
+    for record in rdr.records() {
+        let record = record?;
+        wtr.write_record(order.iter().map(|&i| record.get(i).unwrap_or_default()))?;
+    }
+    let data = wtr.into_inner()?;
+
+    let mut reordered = csv::Reader::from_reader(data.as_slice());
+    do_check(&mut reordered, config, report, meetpath)
+}
+
 /// Checks a single meet.csv string, used by the server.
 pub fn check_meet_from_string(
     reader: &csv::ReaderBuilder,
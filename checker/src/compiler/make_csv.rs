@@ -182,13 +182,15 @@ impl<'d> EntriesRow<'d> {
             wilks: entry.wilks,
             mcculloch,
             glossbrenner: entry.glossbrenner,
+            // Entries without Equipment have no GOODLIFT Points.
             goodlift: goodlift(
                 entry.sex,
                 entry.equipment,
                 entry.event,
                 entry.bodyweightkg,
                 entry.totalkg,
-            ),
+            )
+            .unwrap_or_default(),
             wilks2020: wilks2020(entry.sex, entry.bodyweightkg, entry.totalkg),
             dots: dots(entry.sex, entry.bodyweightkg, entry.totalkg),
             tested: if entry.tested { "Yes" } else { "" },
@@ -43,13 +43,15 @@ fn make_export_row<'a>(entry: &'a Entry, meet: &'a Meet) -> ExportRow<'a> {
         dots: dots(entry.sex, entry.bodyweightkg, entry.totalkg),
         wilks: entry.wilks,
         glossbrenner: entry.glossbrenner,
+        // Entries without Equipment have no GOODLIFT Points.
         goodlift: goodlift(
             entry.sex,
             entry.equipment,
             entry.event,
             entry.bodyweightkg,
             entry.totalkg,
-        ),
+        )
+        .unwrap_or_default(),
         tested: if entry.tested { "Yes" } else { "" },
         country: entry.country,
         state: entry.state.map(opltypes::states::State::to_state_string),
@@ -29,23 +29,189 @@ pub use meetdata::{AllMeetData, LifterMap, SingleMeetData};
 
 pub mod disambiguator;
 
+use serde::Serialize;
+
+use std::collections::HashMap;
 use std::error::Error;
 use std::path::{Path, PathBuf};
 
-/// A data error or warning message that should be reported.
+/// How seriously a Message should be taken.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum Severity {
+    /// A note that does not indicate a problem with the data.
+    Info,
+    /// A likely problem that allows checks to pass.
+    Warning,
+    /// A problem that causes checks to fail.
+    Error,
+}
+
+/// A data error, warning, or informational message that should be reported.
+///
+/// Messages are `fixable` if the issue can be corrected mechanically,
+/// for example by trimming whitespace, without any human judgment.
+///
+/// Messages about a specific line of a CSV file record that `line`.
+/// Lines are numbered from 1 as in a text editor, so the header is line 1
+/// and the first row is line 2. Blank lines are skipped by the CSV reader
+/// and do not count, so a row after one is numbered as if it weren't there.
+/// Messages merged in from another Report record that Report's `path`.
 #[derive(Debug, Serialize)]
 pub enum Message {
-    Error(String),
-    Warning(String),
+    Info {
+        text: String,
+        fixable: bool,
+        line: Option<u64>,
+        path: Option<PathBuf>,
+    },
+    Error {
+        text: String,
+        fixable: bool,
+        line: Option<u64>,
+        path: Option<PathBuf>,
+    },
+    Warning {
+        text: String,
+        fixable: bool,
+        line: Option<u64>,
+        path: Option<PathBuf>,
+    },
+}
+
+impl Message {
+    /// How seriously the message should be taken.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Message::Info { .. } => Severity::Info,
+            Message::Warning { .. } => Severity::Warning,
+            Message::Error { .. } => Severity::Error,
+        }
+    }
+
+    /// Borrows the human-readable text of the message.
+    pub fn text(&self) -> &str {
+        match self {
+            Message::Info { text, .. } => text,
+            Message::Error { text, .. } => text,
+            Message::Warning { text, .. } => text,
+        }
+    }
+
+    /// Whether the issue could be corrected automatically.
+    pub fn is_fixable(&self) -> bool {
+        match self {
+            Message::Info { fixable, .. } => *fixable,
+            Message::Error { fixable, .. } => *fixable,
+            Message::Warning { fixable, .. } => *fixable,
+        }
+    }
+
+    /// The line of the file that the message is about, if any.
+    pub fn line(&self) -> Option<u64> {
+        match self {
+            Message::Info { line, .. } => *line,
+            Message::Error { line, .. } => *line,
+            Message::Warning { line, .. } => *line,
+        }
+    }
+
+    /// The file that the message is about, if it differs from its Report's.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Message::Info { path, .. } => path.as_deref(),
+            Message::Error { path, .. } => path.as_deref(),
+            Message::Warning { path, .. } => path.as_deref(),
+        }
+    }
+
+    /// Mutably borrows the human-readable text of the message.
+    fn text_mut(&mut self) -> &mut String {
+        match self {
+            Message::Info { text, .. } => text,
+            Message::Error { text, .. } => text,
+            Message::Warning { text, .. } => text,
+        }
+    }
+
+    /// Attributes the message to a file, unless it already is.
+    fn attribute_to(&mut self, file: &Path) {
+        let path = match self {
+            Message::Info { path, .. } => path,
+            Message::Error { path, .. } => path,
+            Message::Warning { path, .. } => path,
+        };
+        if path.is_none() {
+            *path = Some(file.to_path_buf());
+        }
+    }
+}
+
+/// How many messages of each Severity a Report holds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MessageCounts {
+    pub info: usize,
+    pub warnings: usize,
+    pub errors: usize,
 }
 
 /// Accumulates messages that should be reported as a single batch.
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
 pub struct Report {
     pub path: PathBuf,
     pub messages: Vec<Message>,
 }
 
+/// A Message in the structured output of a Report.
+#[derive(Deserialize, Serialize)]
+pub struct MessageJson {
+    pub text: String,
+    pub fixable: bool,
+    pub line: Option<u64>,
+    pub path: Option<PathBuf>,
+}
+
+/// The structured output of a Report, with messages grouped by kind.
+#[derive(Deserialize, Serialize)]
+pub struct ReportJson {
+    pub path: PathBuf,
+    pub errors: Vec<MessageJson>,
+    pub warnings: Vec<MessageJson>,
+    pub info: Vec<MessageJson>,
+}
+
+impl From<&Report> for ReportJson {
+    fn from(report: &Report) -> ReportJson {
+        let mut json = ReportJson {
+            path: report.path.clone(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            info: Vec::new(),
+        };
+
+        for message in &report.messages {
+            let entry = MessageJson {
+                text: message.text().to_string(),
+                fixable: message.is_fixable(),
+                line: message.line(),
+                path: message.path().map(Path::to_path_buf),
+            };
+            match message.severity() {
+                Severity::Error => json.errors.push(entry),
+                Severity::Warning => json.warnings.push(entry),
+                Severity::Info => json.info.push(entry),
+            }
+        }
+
+        json
+    }
+}
+
+impl Serialize for Report {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ReportJson::from(self).serialize(serializer)
+    }
+}
+
 impl Report {
     /// Creates a new Report.
     pub fn new(path: PathBuf) -> Self {
@@ -57,24 +223,132 @@ impl Report {
 
     /// Reports an error, which causes checks to fail.
     pub fn error(&mut self, message: impl ToString) {
-        self.messages.push(Message::Error(message.to_string()));
+        self.messages.push(Message::Error {
+            text: message.to_string(),
+            fixable: false,
+            line: None,
+            path: None,
+        });
     }
 
     /// Reports an error on a specific line.
     pub fn error_on(&mut self, line: u64, message: impl ToString) {
         let msg = format!(" Line {}: {}", line, message.to_string());
-        self.messages.push(Message::Error(msg));
+        self.messages.push(Message::Error {
+            text: msg,
+            fixable: false,
+            line: Some(line),
+            path: None,
+        });
+    }
+
+    /// Reports an error that could be corrected automatically.
+    pub fn fixable_error(&mut self, message: impl ToString) {
+        self.messages.push(Message::Error {
+            text: message.to_string(),
+            fixable: true,
+            line: None,
+            path: None,
+        });
+    }
+
+    /// Reports an error on a specific line that could be corrected automatically.
+    pub fn fixable_error_on(&mut self, line: u64, message: impl ToString) {
+        let msg = format!(" Line {}: {}", line, message.to_string());
+        self.messages.push(Message::Error {
+            text: msg,
+            fixable: true,
+            line: Some(line),
+            path: None,
+        });
     }
 
     /// Reports a warning, which allows checks to pass with a note.
     pub fn warning(&mut self, message: impl ToString) {
-        self.messages.push(Message::Warning(message.to_string()));
+        self.messages.push(Message::Warning {
+            text: message.to_string(),
+            fixable: false,
+            line: None,
+            path: None,
+        });
     }
 
     /// Reports a warning on a specific line.
     pub fn warning_on(&mut self, line: u64, message: impl ToString) {
         let msg = format!(" Line {}: {}", line, message.to_string());
-        self.messages.push(Message::Warning(msg));
+        self.messages.push(Message::Warning {
+            text: msg,
+            fixable: false,
+            line: Some(line),
+            path: None,
+        });
+    }
+
+    /// Reports a note, which does not affect whether checks pass.
+    pub fn info(&mut self, message: impl ToString) {
+        self.messages.push(Message::Info {
+            text: message.to_string(),
+            fixable: false,
+            line: None,
+            path: None,
+        });
+    }
+
+    /// Reports a note on a specific line.
+    pub fn info_on(&mut self, line: u64, message: impl ToString) {
+        let msg = format!(" Line {}: {}", line, message.to_string());
+        self.messages.push(Message::Info {
+            text: msg,
+            fixable: false,
+            line: Some(line),
+            path: None,
+        });
+    }
+
+    /// Absorbs the messages of another Report.
+    ///
+    /// Each absorbed message remembers the path of the Report it came from.
+    pub fn merge(&mut self, other: Report) {
+        let Report { path, messages } = other;
+        for mut message in messages {
+            message.attribute_to(&path);
+            self.messages.push(message);
+        }
+    }
+
+    /// Collapses identical messages into the first of them.
+    ///
+    /// Messages are identical if they have the same Severity, text, and file.
+    /// A collapsed message is annotated with how many others it replaced,
+    /// like "(and 9 more)". Afterwards, [Report::count_messages] counts
+    /// the collapsed messages, not the original ones.
+    pub fn dedup(&mut self) {
+        let mut first_index: HashMap<(Severity, String, Option<PathBuf>), usize> = HashMap::new();
+        let mut kept: Vec<(Message, usize)> = Vec::with_capacity(self.messages.len());
+
+        for message in self.messages.drain(..) {
+            let key = (
+                message.severity(),
+                message.text().to_string(),
+                message.path().map(Path::to_path_buf),
+            );
+            match first_index.get(&key) {
+                Some(&i) => kept[i].1 += 1,
+                None => {
+                    first_index.insert(key, kept.len());
+                    kept.push((message, 0));
+                }
+            }
+        }
+
+        for (mut message, repeats) in kept {
+            if repeats > 0 {
+                message
+                    .text_mut()
+                    .push_str(&format!(" (and {} more)", repeats));
+            }
+            self.messages.push(message);
+        }
     }
 
     /// Whether a report has any messages.
@@ -82,31 +356,38 @@ impl Report {
         !self.messages.is_empty()
     }
 
-    /// Returns how many messages there are of (errors, warnings).
-    pub fn count_messages(&self) -> (usize, usize) {
-        let mut errors = 0;
-        let mut warnings = 0;
-
+    /// Returns how many messages there are of each Severity.
+    pub fn count_messages(&self) -> MessageCounts {
+        let mut counts = MessageCounts::default();
         for message in &self.messages {
-            match message {
-                Message::Error(_) => errors += 1,
-                Message::Warning(_) => warnings += 1,
+            match message.severity() {
+                Severity::Info => counts.info += 1,
+                Severity::Warning => counts.warnings += 1,
+                Severity::Error => counts.errors += 1,
             }
         }
+        counts
+    }
 
-        (errors, warnings)
+    /// Returns how many messages there are of (errors, warnings).
+    pub fn count_errors_and_warnings(&self) -> (usize, usize) {
+        let counts = self.count_messages();
+        (counts.errors, counts.warnings)
     }
 
     /// Returns how many errors there are.
     pub fn count_errors(&self) -> usize {
-        let (errors, _) = self.count_messages();
-        errors
+        self.count_messages().errors
     }
 
     /// Returns how many warnings there are.
     pub fn count_warnings(&self) -> usize {
-        let (_, warnings) = self.count_messages();
-        warnings
+        self.count_messages().warnings
+    }
+
+    /// Gets the structured form of the Report, for tooling.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Report serialization is infallible")
     }
 
     /// Returns the name of the parent folder of the given file.
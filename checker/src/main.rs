@@ -73,19 +73,26 @@ fn project_root() -> Result<PathBuf, Box<dyn Error>> {
 /// Outputs a report to stdout with the StdoutLock held for atomicity.
 ///
 /// Failure to write a report is not itself an error that needs reporting.
-fn write_report(handle: &mut io::StdoutLock, report: checker::Report) {
+fn write_report(handle: &mut io::StdoutLock, mut report: checker::Report) {
+    // Repeated messages are shown once. The summary counts are taken
+    // beforehand, so they still include every repeat.
+    report.dedup();
+
     // Output the full name of the file in default coloring.
     let _ = handle.write_fmt(format_args!("{}\n", report.path.to_str().unwrap()));
 
     // Output each message with some festive coloring.
     for message in report.messages {
         match message {
-            checker::Message::Error(s) => {
+            checker::Message::Error { text: s, .. } => {
                 let _ = handle.write_fmt(format_args!(" {}\n", s.bold().red()));
             }
-            checker::Message::Warning(s) => {
+            checker::Message::Warning { text: s, .. } => {
                 let _ = handle.write_fmt(format_args!(" {}\n", s.bold().yellow()));
             }
+            checker::Message::Info { text: s, .. } => {
+                let _ = handle.write_fmt(format_args!(" {}\n", s));
+            }
         }
     }
 }
@@ -178,7 +185,7 @@ fn configurations(meet_data_root: &Path) -> Result<ConfigMap, (usize, usize)> {
         match checker::check_config(configpath) {
             Ok(result) => {
                 // Tally up and output and errors and warnings.
-                let (errors, warnings) = result.report.count_messages();
+                let (errors, warnings) = result.report.count_errors_and_warnings();
                 if errors > 0 || warnings > 0 {
                     error_count += errors;
                     warning_count += warnings;
@@ -337,7 +344,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let timing = instant_if(args.debug_timing);
     let result = checker::check_lifterdata(&reader, &project_root.join("lifter-data"));
     for report in result.reports {
-        let (errors, warnings) = report.count_messages();
+        let (errors, warnings) = report.count_errors_and_warnings();
         if errors > 0 {
             error_count.fetch_add(errors, Ordering::SeqCst);
         }
@@ -380,7 +387,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     let mut local_errors = 0;
                     let mut local_warnings = 0;
                     for report in &reports {
-                        let (errors, warnings) = report.count_messages();
+                        let (errors, warnings) = report.count_errors_and_warnings();
                         local_errors += errors;
                         local_warnings += warnings;
                     }
@@ -440,7 +447,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Check for consistency errors for individual lifters.
     let timing = instant_if(args.debug_timing);
     for report in checker::consistency::check(&liftermap, &meetdata, &lifterdata, is_partial) {
-        let (errors, warnings) = report.count_messages();
+        let (errors, warnings) = report.count_errors_and_warnings();
         error_count += errors;
         warning_count += warnings;
 
@@ -3,20 +3,33 @@
 extern crate checker;
 extern crate csv;
 
-use checker::checklib::entries::do_check;
-use checker::Report;
+use checker::checklib::config::{DivisionConfig, OptionConfig, WeightClassConfig};
+use checker::checklib::entries::{do_check, EntriesCheckResult};
+use checker::{Config, Meet, MessageCounts, Report, ReportJson, Severity};
+use opltypes::*;
 
 use std::path::PathBuf;
 
 /// Executes checks against a string representation of a CSV,
-/// returning the number of errors.
-fn check(csv: &str) -> usize {
+/// returning the full result.
+fn check_result(csv: &str, meet: Option<&Meet>, config: Option<&Config>) -> EntriesCheckResult {
     let report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
     let mut rdr = csv::ReaderBuilder::new()
         .quoting(false)
         .from_reader(csv.as_bytes());
-    let checkresult = do_check(&mut rdr, None, None, None, report).unwrap();
-    checkresult.report.count_errors()
+    do_check(&mut rdr, meet, config, None, report).unwrap()
+}
+
+/// Executes checks against a string representation of a CSV,
+/// returning the report.
+fn check_report(csv: &str, meet: Option<&Meet>, config: Option<&Config>) -> Report {
+    check_result(csv, meet, config).report
+}
+
+/// Executes checks against a string representation of a CSV,
+/// returning the number of errors.
+fn check(csv: &str) -> usize {
+    check_report(csv, None, None).count_errors()
 }
 
 #[test]
@@ -135,6 +148,36 @@ fn test_column_event() {
     assert!(check(data) > 0);
 }
 
+#[test]
+fn test_event_lift_data() {
+    let errors = |csv: &str| -> Vec<String> {
+        let report = check_report(csv, None, None);
+        let messages = report.messages.iter();
+        messages
+            .filter(|m| m.severity() == Severity::Error)
+            .map(|m| m.text().to_string())
+            .collect()
+    };
+    let header = "Name,WeightClassKg,Sex,Squat1Kg,Best3SquatKg,Bench1Kg,Best3BenchKg,\
+                  Deadlift1Kg,Best3DeadliftKg,TotalKg,Equipment,Event,Place\n";
+
+    // Every lift of a full power meet.
+    let data = format!(
+        "{}Test User,90,M,200,200,100,100,250,250,550,Raw,SBD,1",
+        header
+    );
+    assert!(errors(&data).is_empty());
+
+    // A bench-only lifter with only bench data.
+    let data = format!("{}Test User,90,M,,,100,100,,,100,Raw,B,1", header);
+    assert!(errors(&data).is_empty());
+
+    // A bench-only lifter with a stray squat attempt.
+    let data = format!("{}Test User,90,M,200,,100,100,,,100,Raw,B,1", header);
+    let found = errors(&data);
+    assert!(found.contains(&" Line 2: Event 'B' cannot have squat data".to_string()));
+}
+
 #[test]
 fn test_event_consistency() {
     // Bench-only lifter with valid data, but marked SBD.
@@ -198,3 +241,607 @@ fn regression_tests() {
                 Aleksey Krasnoshchekov,M,86,,,,,,170,90,SBD,Raw,No,-135,-135,-135,,,160,165,170,170,,Pro Masters 40-44,42,1";
     assert_eq!(check(data), 1);
 }
+
+#[test]
+fn test_fixable_messages() {
+    // Extraneous whitespace can be trimmed automatically.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User ,90,M,100,100,Raw,B,1";
+    let report = check_report(data, None, None);
+    assert_eq!(report.count_errors(), 1);
+    assert!(report.messages[0].is_fixable());
+
+    // A bad weight needs human judgment.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,1000,1000,Raw,B,1";
+    let report = check_report(data, None, None);
+    assert!(report.count_errors() > 0);
+    assert!(report.messages.iter().all(|m| !m.is_fixable()));
+}
+
+#[test]
+fn test_message_line_numbers() {
+    // The header is line 1, so the second row is line 3.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,100,100,Raw,B,1\n\
+                Other User,90,X,100,100,Raw,B,2";
+    let report = check_report(data, None, None);
+    assert_eq!(report.count_errors(), 1);
+    assert_eq!(report.messages[0].line(), Some(3));
+
+    // Blank lines are skipped without counting toward the numbering.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,100,100,Raw,B,1\n\
+                \n\
+                Other User,90,X,100,100,Raw,B,2";
+    let report = check_report(data, None, None);
+    assert_eq!(report.count_errors(), 1);
+    assert_eq!(report.messages[0].line(), Some(3));
+}
+
+#[test]
+fn test_federation_matches_meet() {
+    let meet = Meet {
+        path: "wrpf/1901".to_string(),
+        federation: Federation::WRPF,
+        date: Date::from_parts(2019, 3, 1),
+        country: Country::USA,
+        state: None,
+        town: None,
+        name: "Test Meet".to_string(),
+        ruleset: RuleSet::default(),
+    };
+    let count_warnings = |csv: &str| -> usize {
+        let report = check_report(csv, Some(&meet), None);
+        assert_eq!(report.count_errors(), 0);
+        report.count_warnings()
+    };
+
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Federation\n\
+                Test User,90,M,100,100,Raw,B,1,WRPF";
+    assert_eq!(count_warnings(data), 0);
+
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Federation\n\
+                Test User,90,M,100,100,Raw,B,1,IPF";
+    assert_eq!(count_warnings(data), 1);
+}
+
+#[test]
+fn test_age_matches_birthdate() {
+    let meet = Meet {
+        path: "wrpf/1901".to_string(),
+        federation: Federation::WRPF,
+        date: Date::from_parts(2019, 3, 1),
+        country: Country::USA,
+        state: None,
+        town: None,
+        name: "Test Meet".to_string(),
+        ruleset: RuleSet::default(),
+    };
+    let check_age = |age: &str, birthdate: &str| -> usize {
+        let csv = format!(
+            "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Age,BirthDate\n\
+             Test User,90,M,100,100,Raw,B,1,{},{}",
+            age, birthdate
+        );
+        check_report(&csv, Some(&meet), None).count_errors()
+    };
+
+    // The lifter turned 30 the day before the meet.
+    assert_eq!(check_age("30", "1989-02-28"), 0);
+    assert_eq!(check_age("35", "1989-02-28"), 1);
+
+    // "29 or 30" and "30 or 31" both allow for 30.
+    assert_eq!(check_age("29.5", "1989-02-28"), 0);
+    assert_eq!(check_age("30.5", "1989-02-28"), 0);
+    assert_eq!(check_age("31.5", "1989-02-28"), 1);
+}
+
+#[test]
+fn test_total_with_failed_attempts() {
+    let check_counts = |csv: &str| -> (usize, usize) {
+        let report = check_report(csv, None, None);
+        (report.count_errors(), report.count_warnings())
+    };
+    let header = "Name,WeightClassKg,Sex,Equipment,Event,Place,TotalKg,\
+                  Bench1Kg,Bench2Kg,Bench3Kg,Best3BenchKg,\
+                  Deadlift1Kg,Deadlift2Kg,Deadlift3Kg,Best3DeadliftKg";
+
+    // A bombed lifter with a stray total.
+    let data = format!(
+        "{}\nTest User,90,M,Raw,BD,1,300,-100,-100,-100,,-200,-200,-200,",
+        header
+    );
+    assert_eq!(check_counts(&data), (1, 0));
+
+    // The same lifter correctly marked as bombed out.
+    let data = format!(
+        "{}\nTest User,90,M,Raw,BD,DQ,,-100,-100,-100,,-200,-200,-200,",
+        header
+    );
+    assert_eq!(check_counts(&data), (0, 0));
+
+    // A normal successful lifter.
+    let data = format!(
+        "{}\nTest User,90,M,Raw,BD,1,300,90,100,-105,100,180,200,-210,200",
+        header
+    );
+    assert_eq!(check_counts(&data), (0, 0));
+
+    // Only the bench bombed, but there's still a total.
+    let data = format!(
+        "{}\nTest User,90,M,Raw,BD,1,200,-100,-100,-100,,180,200,-210,200",
+        header
+    );
+    assert_eq!(check_counts(&data), (0, 1));
+}
+
+#[test]
+fn test_division_sex_words() {
+    let count_warnings = |csv: &str| -> usize {
+        let report = check_report(csv, None, None);
+        assert_eq!(report.count_errors(), 0);
+        report.count_warnings()
+    };
+
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n\
+                Test User,90,M,100,100,Raw,B,1,Open Men";
+    assert_eq!(count_warnings(data), 0);
+
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n\
+                Test User,90,M,100,100,Raw,B,1,Masters Women 40-44";
+    assert_eq!(count_warnings(data), 1);
+
+    // Substrings of other words are not sexed.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n\
+                Test User,90,F,100,100,Raw,B,1,Menominee Open";
+    assert_eq!(count_warnings(data), 0);
+}
+
+#[test]
+fn test_report_json() {
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n\
+                Test User,90,M,100,100,Raw,B,1,Masters Women\n\
+                Test User,90,X,100,100,Raw,B,1,Open";
+    let report = check_report(data, None, None);
+    let (errors, warnings) = report.count_errors_and_warnings();
+    assert!(errors > 0 && warnings > 0);
+
+    let json: ReportJson = serde_json::from_value(report.to_json()).unwrap();
+    assert_eq!(json.path, report.path);
+    assert_eq!(json.errors.len(), errors);
+    assert_eq!(json.warnings.len(), warnings);
+    assert!(json.warnings.iter().all(|m| m.line.is_some()));
+}
+
+#[test]
+fn test_parallel_rows_match_sequential() {
+    // A large meet, with errors and warnings scattered throughout.
+    let mut data = String::from(
+        "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n",
+    );
+    for i in 0..5000 {
+        let sex = if i % 7 == 0 { "X" } else { "M" };
+        let division = if i % 11 == 0 { "Women" } else { "Open" };
+        data += &format!("Test User {},90,{},100,100,Raw,B,1,{}\n", i, sex, division);
+    }
+
+    let run = || {
+        let result = check_result(&data, None, None);
+        let texts: Vec<String> = result
+            .report
+            .messages
+            .iter()
+            .map(|m| m.text().to_string())
+            .collect();
+        let names: Vec<String> = result
+            .entries
+            .unwrap()
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        (texts, names)
+    };
+
+    let sequential = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap()
+        .install(run);
+    let parallel = run();
+    assert!(!sequential.0.is_empty());
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn test_duplicate_entries() {
+    let count_warnings = |csv: &str| -> usize {
+        let report = check_report(csv, None, None);
+        assert_eq!(report.count_errors(), 0);
+        report.count_warnings()
+    };
+
+    // The same lifter entered twice, with different accents.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n\
+                José Smith,90,M,100,100,Raw,B,1,Open\n\
+                Jose Smith,90,M,100,100,Raw,B,1,Open";
+    assert_eq!(count_warnings(data), 1);
+
+    // One lifter competing in two divisions is legitimate.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n\
+                José Smith,90,M,100,100,Raw,B,1,Open\n\
+                José Smith,90,M,100,100,Raw,B,1,Masters 40-44";
+    assert_eq!(count_warnings(data), 0);
+
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place,Division\n\
+                José Smith,90,M,100,100,Raw,B,1,Open\n\
+                John Smith,90,M,100,100,Raw,B,2,Open\n\
+                Jane Smith,90,F,100,100,Raw,B,1,Open";
+    assert_eq!(count_warnings(data), 0);
+}
+
+#[test]
+fn test_report_merge() {
+    let mut entries = Report::new(PathBuf::from("meet-data/wrpf/1901/entries.csv"));
+    entries.error_on(2, "Unknown division 'Novice'");
+    entries.warning_on(3, "Lifter 'Test User' appears to duplicate line 2");
+
+    let mut meet = Report::new(PathBuf::from("meet-data/wrpf/1901/meet.csv"));
+    meet.error("Invalid date '2016-08-90'. Must be YYYY-MM-DD");
+    meet.error("Too many rows");
+
+    let mut merged = Report::new(PathBuf::from("meet-data/wrpf/1901"));
+    merged.merge(entries);
+    merged.merge(meet);
+    assert_eq!(merged.count_errors_and_warnings(), (3, 1));
+
+    let paths: Vec<&str> = merged
+        .messages
+        .iter()
+        .map(|m| m.path().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(paths[0], "meet-data/wrpf/1901/entries.csv");
+    assert_eq!(paths[1], "meet-data/wrpf/1901/entries.csv");
+    assert_eq!(paths[2], "meet-data/wrpf/1901/meet.csv");
+    assert_eq!(paths[3], "meet-data/wrpf/1901/meet.csv");
+}
+
+#[test]
+fn test_report_dedup() {
+    let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+    for _ in 0..10 {
+        report.warning("Division 'Open' is not configured");
+    }
+    report.error("Division 'Open' is not configured");
+    report.warning_on(2, "Division 'Open' is not configured");
+    report.dedup();
+
+    // Messages of a different Severity or line are kept apart.
+    let texts: Vec<&str> = report.messages.iter().map(|m| m.text()).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "Division 'Open' is not configured (and 9 more)",
+            "Division 'Open' is not configured",
+            " Line 2: Division 'Open' is not configured",
+        ]
+    );
+    assert_eq!(report.count_errors_and_warnings(), (1, 2));
+}
+
+#[test]
+fn test_severity_counts() {
+    let mut report = Report::new(PathBuf::from("[inline_parent]/[inline]"));
+    report.info("Meet has 3 lifters");
+    report.info_on(2, "Lifter 'Test User' has no bodyweight");
+    report.warning_on(3, "Lifter 'Test User' appears to duplicate line 2");
+    report.error("Too many rows");
+
+    let severities: Vec<Severity> = report.messages.iter().map(|m| m.severity()).collect();
+    assert_eq!(
+        severities,
+        vec![
+            Severity::Info,
+            Severity::Info,
+            Severity::Warning,
+            Severity::Error
+        ]
+    );
+    assert_eq!(
+        report.count_messages(),
+        MessageCounts {
+            info: 2,
+            warnings: 1,
+            errors: 1
+        }
+    );
+    assert_eq!(report.count_errors_and_warnings(), (1, 1));
+
+    let json: ReportJson = serde_json::from_value(report.to_json()).unwrap();
+    assert_eq!(json.info.len(), 2);
+    assert_eq!(json.info[1].line, Some(2));
+}
+
+#[test]
+fn test_max_entries() {
+    let config = Config {
+        options: Some(OptionConfig {
+            max_entries: Some(2),
+            ..OptionConfig::default()
+        }),
+        divisions: vec![],
+        weightclasses: vec![],
+        exemptions: vec![],
+        rulesets: vec![],
+    };
+    let count_warnings = |csv: &str| -> usize {
+        let report = check_report(csv, None, Some(&config));
+        report.count_warnings()
+    };
+
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,100,100,Raw,B,1\n\
+                Other User,90,M,90,90,Raw,B,2";
+    assert_eq!(count_warnings(data), 0);
+
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,100,100,Raw,B,1\n\
+                Other User,90,M,90,90,Raw,B,2\n\
+                Third User,90,M,80,80,Raw,B,3";
+    assert_eq!(count_warnings(data), 1);
+}
+
+/// A configuration with a single group of men's weightclasses.
+fn weightclass_config() -> Config {
+    Config {
+        options: None,
+        divisions: vec![DivisionConfig {
+            name: "Open".to_string(),
+            min: Age::Exact(0),
+            max: Age::Exact(255),
+            sex: None,
+            equipment: None,
+            tested: None,
+            place: None,
+        }],
+        weightclasses: vec![WeightClassConfig {
+            name: "default_M".to_string(),
+            classes: ["67.5", "75", "82.5", "82.5+"]
+                .iter()
+                .map(|s| s.parse::<WeightClassKg>().unwrap())
+                .collect(),
+            date_min: Date::from_parts(1900, 1, 1),
+            date_max: Date::from_parts(9999, 1, 1),
+            sex: Sex::M,
+            divisions: None,
+        }],
+        exemptions: vec![],
+        rulesets: vec![],
+    }
+}
+
+#[test]
+fn test_weightclass_system() {
+    let config = weightclass_config();
+    let count_errors = |csv: &str| -> usize {
+        let report = check_report(csv, None, Some(&config));
+        report.count_errors()
+    };
+
+    let data = "Name,Division,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,Open,75,M,100,100,Raw,B,1";
+    assert_eq!(count_errors(data), 0);
+
+    // The federation uses 75, not the IPF's 74.
+    let data = "Name,Division,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,Open,74,M,100,100,Raw,B,1";
+    assert_eq!(count_errors(data), 1);
+}
+
+#[test]
+fn test_bodyweight_below_class() {
+    let config = weightclass_config();
+    let count_errors = |csv: &str| -> usize {
+        let report = check_report(csv, None, Some(&config));
+        report.count_errors()
+    };
+
+    let data =
+        "Name,Division,BodyweightKg,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,Open,74.2,75,M,100,100,Raw,B,1";
+    assert_eq!(count_errors(data), 0);
+
+    // A bodyweight that fits a lighter configured class is already an error.
+    let data =
+        "Name,Division,BodyweightKg,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                Test User,Open,66.1,82.5,M,100,100,Raw,B,1";
+    assert_eq!(count_errors(data), 1);
+}
+
+#[test]
+fn test_total_matches_attempts() {
+    let header = "Name,WeightClassKg,Sex,Squat1Kg,Squat2Kg,Squat3Kg,Best3SquatKg,\
+                  Bench1Kg,Bench2Kg,Bench3Kg,Best3BenchKg,\
+                  Deadlift1Kg,Deadlift2Kg,Deadlift3Kg,Best3DeadliftKg,\
+                  TotalKg,Equipment,Event,Place\n";
+
+    // The total is the sum of the best successful attempts.
+    let data = format!(
+        "{}Test User,90,M,180,190,-200,190,100,-105,-105,100,220,240,250,250,540,Raw,SBD,1",
+        header
+    );
+    assert_eq!(check(&data), 0);
+
+    // A mistyped total is an error.
+    let data = format!(
+        "{}Test User,90,M,180,190,-200,190,100,-105,-105,100,220,240,250,250,550,Raw,SBD,1",
+        header
+    );
+    assert_eq!(check(&data), 1);
+
+    // Without attempt columns, only the Best3 columns are used.
+    let data = "Name,WeightClassKg,Sex,Best3SquatKg,Best3BenchKg,Best3DeadliftKg,\
+                TotalKg,Equipment,Event,Place\n\
+                Test User,90,M,190,100,250,540,Raw,SBD,1";
+    assert_eq!(check(data), 0);
+}
+
+#[test]
+fn test_implausible_attempts() {
+    let counts = |csv: &str| -> (usize, usize) {
+        let report = check_report(csv, None, None);
+        report.count_errors_and_warnings()
+    };
+    let header = "Name,BodyweightKg,Sex,Squat1Kg,Best3SquatKg,TotalKg,Equipment,Event,Place\n";
+
+    // A normal attempt is clean, and so is a failed one, which is negative.
+    assert_eq!(
+        counts(&format!("{}Test User,90,M,180,180,180,Raw,S,1", header)),
+        (0, 0)
+    );
+    assert_eq!(
+        counts(&format!("{}Test User,90,M,-180,,,Raw,S,DQ", header)),
+        (0, 0)
+    );
+
+    // Weights that cannot be negative are errors.
+    // The bodyweight is also reported as implausible.
+    let data = format!("{}Test User,-90,M,180,180,180,Raw,S,1", header);
+    assert_eq!(counts(&data), (2, 0));
+
+    // A squat above the plausibility ceiling is a warning.
+    let data = format!("{}Test User,90,M,520,520,520,Multi-ply,S,1", header);
+    assert_eq!(counts(&data), (0, 2));
+    let data = format!("{}Test User,90,M,-520,,,Multi-ply,S,DQ", header);
+    assert_eq!(counts(&data), (0, 1));
+
+    // A misplaced decimal beyond any possible lift is still an error,
+    // alongside the errors it causes in the Best3SquatKg and TotalKg.
+    let data = format!("{}Test User,90,M,1800,1800,1800,Raw,S,1", header);
+    assert_eq!(counts(&data), (3, 0));
+}
+
+#[test]
+fn test_best3_matches_attempts() {
+    let messages = |csv: &str| -> Vec<String> {
+        let report = check_report(csv, None, None);
+        report
+            .messages
+            .iter()
+            .map(|m| m.text().to_string())
+            .collect()
+    };
+    let header = "Name,WeightClassKg,Sex,Squat1Kg,Squat2Kg,Squat3Kg,Best3SquatKg,\
+                  Bench1Kg,Bench2Kg,Bench3Kg,Best3BenchKg,TotalKg,Equipment,Event,Place\n";
+
+    // The summaries agree with the attempts.
+    let data = format!(
+        "{}Test User,90,M,180,190,-200,190,100,105,-110,105,295,Raw,SB,1",
+        header
+    );
+    assert!(messages(&data).is_empty());
+
+    // The squat summary disagrees, and the error names the lift.
+    let data = format!(
+        "{}Test User,90,M,180,190,-200,200,100,105,-110,105,295,Raw,SB,1",
+        header
+    );
+    assert_eq!(
+        messages(&data),
+        vec![" Line 2: Best3SquatKg '200' does not match best attempt '190'"]
+    );
+
+    // If every bench failed, the summary is blank or the lightest failure.
+    let data = format!(
+        "{}Test User,90,M,180,190,-200,190,-100,-105,-105,,,Raw,SB,DQ",
+        header
+    );
+    assert!(messages(&data).is_empty());
+    let data = format!(
+        "{}Test User,90,M,180,190,-200,190,-100,-105,-105,-100,,Raw,SB,DQ",
+        header
+    );
+    assert!(messages(&data).is_empty());
+    let data = format!(
+        "{}Test User,90,M,180,190,-200,190,-100,-105,-105,-105,,Raw,SB,DQ",
+        header
+    );
+    assert_eq!(
+        messages(&data),
+        vec![" Line 2: Best3BenchKg '-105' does not match least failed attempt '-100'"]
+    );
+}
+
+#[test]
+fn test_large_file() {
+    // Names must be distinct, so spell the row number out in letters.
+    let name = |mut i: usize| -> String {
+        let mut letters = String::new();
+        loop {
+            letters.push((b'a' + (i % 26) as u8) as char);
+            i /= 26;
+            if i == 0 {
+                break;
+            }
+        }
+        format!("Test Lifter{}", letters)
+    };
+
+    // Every thousandth row has a mistyped total.
+    let rows = 5000;
+    let mut data =
+        String::from("Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n");
+    for i in 0..rows {
+        let total = if i % 1000 == 999 { 101 } else { 100 };
+        data.push_str(&format!("{},90,M,100,{},Raw,B,1\n", name(i), total));
+    }
+
+    let result = check_result(&data, None, None);
+    assert_eq!(result.entries.unwrap().len(), rows);
+
+    // Errors are reported in file order, across chunk boundaries.
+    let error_lines: Vec<u64> = result
+        .report
+        .messages
+        .iter()
+        .filter(|m| m.severity() == Severity::Error)
+        .filter_map(|m| m.line())
+        .collect();
+    assert_eq!(error_lines, vec![1001, 2001, 3001, 4001, 5001]);
+
+    // The only warning is for the file being unusually large.
+    assert_eq!(result.report.count_warnings(), 1);
+}
+
+#[test]
+fn test_name_spellings() {
+    let warnings = |csv: &str| -> Vec<String> {
+        let report = check_report(csv, None, None);
+        let messages = report.messages.iter();
+        messages
+            .filter(|m| m.severity() == Severity::Warning)
+            .map(|m| m.text().to_string())
+            .collect()
+    };
+
+    // A missing accent gives the same username.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                José Pérez,90,M,100,100,Raw,B,1\n\
+                Jose Perez,90,M,90,90,Raw,B,2\n\
+                Jose Perez,100,M,80,80,Raw,B,1";
+    assert_eq!(
+        warnings(data),
+        vec![
+            " Line 3: Name 'Jose Perez' has the same username as 'José Pérez' on line 2: \
+              is one misspelled?"
+        ]
+    );
+
+    // Different names and disambiguated lifters are fine.
+    let data = "Name,WeightClassKg,Sex,Best3BenchKg,TotalKg,Equipment,Event,Place\n\
+                John Smith,90,M,100,100,Raw,B,1\n\
+                Jon Smith,90,M,90,90,Raw,B,2\n\
+                John Doe #1,90,M,80,80,Raw,B,3\n\
+                John Doe #2,90,M,70,70,Raw,B,4";
+    assert!(warnings(data).is_empty());
+}
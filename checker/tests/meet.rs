@@ -3,20 +3,44 @@
 extern crate checker;
 extern crate csv;
 
-use checker::checklib::meet::do_check;
+use checker::checklib::meet::{check_meetpath, do_check, do_check_lenient, MeetCheckResult};
 use checker::Report;
 
 use std::path::PathBuf;
 
 /// Executes checks against a string representation of a CSV,
-/// returning the number of errors.
-fn check(csv: &str) -> usize {
+/// returning the full result.
+///
+/// The `lenient` flag selects `do_check_lenient()` instead of `do_check()`.
+fn check_result(csv: &str, lenient: bool) -> MeetCheckResult {
     let report = Report::new(PathBuf::from("[inline]"));
     let mut rdr = csv::ReaderBuilder::new()
         .quoting(false)
         .from_reader(csv.as_bytes());
-    let checkresult = do_check(&mut rdr, None, report, String::from("foo/bar")).unwrap();
-    checkresult.report.count_errors()
+    let meetpath = String::from("foo/bar");
+    if lenient {
+        do_check_lenient(&mut rdr, None, report, meetpath).unwrap()
+    } else {
+        do_check(&mut rdr, None, report, meetpath).unwrap()
+    }
+}
+
+/// Executes checks against a string representation of a CSV,
+/// returning the report.
+fn check_report(csv: &str) -> Report {
+    check_result(csv, false).report
+}
+
+/// Executes checks against a string representation of a CSV,
+/// returning the number of errors.
+fn check(csv: &str) -> usize {
+    check_report(csv).count_errors()
+}
+
+/// Executes checks against a string representation of a CSV,
+/// returning the number of warnings.
+fn count_warnings(csv: &str) -> usize {
+    check_report(csv).count_warnings()
 }
 
 #[test]
@@ -73,6 +97,24 @@ fn test_missing_headers() {
     assert!(check(data) > 0);
 }
 
+#[test]
+fn test_header_suggestions() {
+    let data = "Fedaration,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    let report = check_report(data);
+    assert_eq!(report.count_errors(), 1);
+    assert!(report.messages[0]
+        .text()
+        .contains("did you mean 'Federation'?"));
+
+    // Unrelated headers get no suggestion.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName,Sponsor\n\
+                WRPF,2016-08-19,USA,CA,Mountain View,Boss of Bosses 3,Nobody";
+    let report = check_report(data);
+    assert_eq!(report.count_errors(), 1);
+    assert!(!report.messages[0].text().contains("did you mean"));
+}
+
 #[test]
 fn test_header_typos() {
     // Typo Federation.
@@ -116,6 +158,23 @@ fn test_reordered_headers() {
     assert!(check(data) > 0);
 }
 
+#[test]
+fn test_reordered_headers_lenient() {
+    let data = "Federation,Date,MeetState,MeetCountry,MeetTown,MeetName\n\
+                WRPF,2016-08-19,CA,USA,Mountain View,Boss of Bosses 3";
+    let result = check_result(data, true);
+    assert_eq!(result.report.count_errors_and_warnings(), (0, 1));
+    let meet = result.meet.unwrap();
+    assert_eq!(meet.country, opltypes::Country::USA);
+    assert_eq!(meet.town.as_deref(), Some("Mountain View"));
+
+    // Other problems are still checked after reordering.
+    let data = "Federation,Date,MeetState,MeetCountry,MeetTown,MeetName\n\
+                WRPF,2016-08-90,CA,USA,Mountain View,Boss of Bosses 3";
+    let result = check_result(data, true);
+    assert_eq!(result.report.count_errors_and_warnings(), (1, 1));
+}
+
 #[test]
 fn test_invalid_rowcounts() {
     // Missing the data row.
@@ -161,13 +220,35 @@ fn test_date() {
     // Check for ridiculously early dates.
     let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
                 WRPF,1935-08-19,USA,CA,Mountain View,Boss of Bosses 3";
-    assert_eq!(check(data), 1);
+    assert_eq!(count_warnings(data), 1);
 
-    // Dates in the future should be rejected.
+    // Dates in the future should be flagged.
     // If this project is still around when this test fails -- hey :-)
     let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
                 WRPF,2999-08-19,USA,CA,Mountain View,Boss of Bosses 3";
-    assert_eq!(check(data), 1);
+    assert_eq!(count_warnings(data), 1);
+}
+
+#[test]
+fn test_date_warnings() {
+    let count_messages = |csv: &str| check_report(csv).count_errors_and_warnings();
+
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(count_messages(data), (0, 0));
+
+    // Years before codified powerlifting are suspicious, but allowed.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,1948-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(count_messages(data), (0, 1));
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,1900-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(count_messages(data), (0, 1));
+
+    // Future dates are likely typos, but also only warnings.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2999-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(count_messages(data), (0, 1));
 }
 
 #[test]
@@ -218,6 +299,43 @@ fn test_meetstate() {
     let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
                 WRPF,2016-08-19,USA,CAT,,Boss of Bosses 3";
     assert_eq!(check(data), 1);
+
+    // Ukrainian oblasts are known.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WPC,2016-08-19,Ukraine,KV,,Kyiv Cup";
+    assert_eq!(check(data), 0);
+}
+
+#[test]
+fn test_swapped_country_and_state() {
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(check(data), 0);
+
+    // Swapped columns produce one targeted error, not two parse errors.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,CA,USA,Mountain View,Boss of Bosses 3";
+    let report = check_report(data);
+    assert_eq!(report.messages.len(), 1);
+    assert!(report.messages[0].text().contains("appear swapped"));
+}
+
+#[test]
+fn test_meetstate_without_state_data() {
+    // Countries without State data skip the check.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,France,IDF,,Boss of Bosses 3";
+    assert_eq!(check(data), 0);
+}
+
+#[test]
+fn test_meetstate_wrong_country() {
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,QLD,,Boss of Bosses 3";
+    let report = check_report(data);
+    assert_eq!(report.count_errors(), 1);
+    assert!(report.messages[0].text().contains("'QLD'"));
+    assert!(report.messages[0].text().contains("'USA'"));
 }
 
 #[test]
@@ -244,6 +362,43 @@ fn test_meettown() {
     assert_eq!(check(data), 1);
 }
 
+#[test]
+fn test_meetstate_required() {
+    // USAPL always records the state of meets in the USA.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                USAPL,2019-03-02,USA,,,Spring Open";
+    assert_eq!(check(data), 1);
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                USAPL,2019-03-02,USA,TX,Austin,Spring Open";
+    assert_eq!(check(data), 0);
+
+    // But not outside the USA.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                USAPL,2019-03-02,Canada,,,Spring Open";
+    assert_eq!(check(data), 0);
+
+    // Federations without a policy may omit the state.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,,,Boss of Bosses 3";
+    assert_eq!(check(data), 0);
+}
+
+#[test]
+fn test_meetstate_without_meettown() {
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,CA,,Boss of Bosses 3";
+    assert_eq!(count_warnings(data), 1);
+    assert_eq!(check(data), 0);
+
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(count_warnings(data), 0);
+
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,,,Boss of Bosses 3";
+    assert_eq!(count_warnings(data), 0);
+}
+
 #[test]
 fn test_meetname() {
     // MeetName is mandatory.
@@ -281,4 +436,47 @@ fn test_meetname() {
     let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
                 WRPF,2016-08-19,USA,CA,Mountain View,2016 Boss of Bosses 3";
     assert_eq!(check(data), 1);
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2016-08-19,USA,CA,Mountain View,Boss of Bosses 2016";
+    assert_eq!(check(data), 1);
+
+    // Series named after a different year are fine.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2017-01-14,USA,CA,Mountain View,2016 Boss of Bosses Rematch";
+    assert_eq!(check(data), 0);
+}
+
+#[test]
+fn test_meetname_other_year() {
+    // A different year is usually a copy-paste error, so it gets a warning.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2017-08-19,USA,CA,Mountain View,2015 Nationals";
+    assert_eq!(check(data), 0);
+    assert_eq!(count_warnings(data), 1);
+
+    // Names without a year are clean.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2017-08-19,USA,CA,Mountain View,Nationals";
+    assert_eq!(count_warnings(data), 0);
+
+    // Other numbers aren't years.
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2017-08-19,USA,CA,Mountain View,Boss of Bosses 3";
+    assert_eq!(count_warnings(data), 0);
+    let data = "Federation,Date,MeetCountry,MeetState,MeetTown,MeetName\n\
+                WRPF,2017-08-19,USA,CA,Mountain View,Rumble 1500";
+    assert_eq!(count_warnings(data), 0);
+}
+
+#[test]
+fn test_meetpath_length() {
+    let errors = |name: &str| -> usize {
+        let path = format!("/opl-data/meet-data/wrpf/{}/meet.csv", name);
+        let mut report = Report::new(PathBuf::from(path));
+        assert!(check_meetpath(&mut report).is_some());
+        report.count_errors()
+    };
+
+    assert_eq!(errors(&"a".repeat(32)), 0);
+    assert_eq!(errors(&"a".repeat(40)), 1);
 }
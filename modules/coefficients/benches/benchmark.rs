@@ -69,7 +69,7 @@ pub fn coefficient_benchmarks(c: &mut Criterion) {
                 black_box(Event::sbd()),
                 black_box(BODYWEIGHT_MEN),
                 black_box(TOTAL_MEN),
-            );
+            )
         });
     });
 
@@ -89,7 +89,7 @@ pub fn coefficient_benchmarks(c: &mut Criterion) {
                 black_box(Event::sbd()),
                 black_box(BODYWEIGHT_MEN),
                 black_box(TOTAL_MEN),
-            );
+            )
         });
     });
 
@@ -32,7 +32,8 @@ pub fn dots_coefficient_men(bodyweightkg: f64) -> f64 {
     const E: f64 = -307.75076;
 
     // Bodyweight bounds are defined; bodyweights out of range match the boundaries.
-    let adjusted = bodyweightkg.clamp(40.0, 210.0);
+    let (min, max) = PointsSystem::Dots.valid_bodyweight_range(Sex::M).unwrap();
+    let adjusted = bodyweightkg.clamp(min, max);
     500.0 / poly4(A, B, C, D, E, adjusted)
 }
 
@@ -44,7 +45,8 @@ pub fn dots_coefficient_women(bodyweightkg: f64) -> f64 {
     const E: f64 = -57.96288;
 
     // Bodyweight bounds are defined; bodyweights out of range match the boundaries.
-    let adjusted = bodyweightkg.clamp(40.0, 150.0);
+    let (min, max) = PointsSystem::Dots.valid_bodyweight_range(Sex::F).unwrap();
+    let adjusted = bodyweightkg.clamp(min, max);
     500.0 / poly4(A, B, C, D, E, adjusted)
 }
 
@@ -62,7 +64,8 @@ pub fn dots_coefficient_women(bodyweightkg: f64) -> f64 {
 /// Tim says that Dots is an acronym for "Dynamic Objective Team Scoring,"
 /// but that they chose the acronym before figuring out the expansion.
 pub fn dots(sex: Sex, bodyweight: WeightKg, total: WeightKg) -> Points {
-    if bodyweight.is_zero() || total.is_zero() {
+    let zero = WeightKg::from_i32(0);
+    if bodyweight <= zero || total <= zero {
         return Points::from_i32(0);
     }
     let coefficient: f64 = match sex {
@@ -71,3 +74,48 @@ pub fn dots(sex: Sex, bodyweight: WeightKg, total: WeightKg) -> Points {
     };
     Points::from(coefficient * f64::from(total))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points() {
+        assert_eq!(
+            dots(Sex::M, WeightKg::from_i32(100), WeightKg::from_i32(700)),
+            Points::from(430.861)
+        );
+        assert_eq!(
+            dots(Sex::F, WeightKg::from_i32(60), WeightKg::from_i32(400)),
+            Points::from(443.418)
+        );
+    }
+
+    #[test]
+    fn non_positive_total() {
+        let bw = WeightKg::from_i32(90);
+        assert_eq!(dots(Sex::M, bw, WeightKg::from_i32(0)), Points::from_i32(0));
+        assert_eq!(
+            dots(Sex::M, bw, WeightKg::from_i32(-100)),
+            Points::from_i32(0)
+        );
+    }
+
+    #[test]
+    fn bodyweight_ceiling() {
+        let total = WeightKg::from_i32(700);
+        assert_eq!(
+            dots(Sex::M, WeightKg::from_i32(250), total),
+            dots(Sex::M, WeightKg::from_i32(210), total)
+        );
+    }
+
+    #[test]
+    fn bodyweight_floor() {
+        let total = WeightKg::from_i32(200);
+        assert_eq!(
+            dots(Sex::F, WeightKg::from_i32(30), total),
+            dots(Sex::F, WeightKg::from_i32(40), total)
+        );
+    }
+}
@@ -60,9 +60,13 @@ pub fn glossbrenner(sex: Sex, bodyweight: WeightKg, total: WeightKg) -> Points {
     if bodyweight.is_zero() || total.is_zero() {
         return Points::from_i32(0);
     }
+    let (min, max) = PointsSystem::Glossbrenner
+        .valid_bodyweight_range(sex)
+        .unwrap();
+    let adjusted = f64::from(bodyweight).clamp(min, max);
     let coefficient: f64 = match sex {
-        Sex::M | Sex::Mx => glossbrenner_coefficient_men(f64::from(bodyweight)),
-        Sex::F => glossbrenner_coefficient_women(f64::from(bodyweight)),
+        Sex::M | Sex::Mx => glossbrenner_coefficient_men(adjusted),
+        Sex::F => glossbrenner_coefficient_women(adjusted),
     };
     Points::from(coefficient * f64::from(total))
 }
@@ -78,6 +82,27 @@ mod tests {
         assert_eq!(glossbrenner_coefficient_women(100.0), 0.7152488066040259);
     }
 
+    #[test]
+    fn averaging() {
+        // In the normal range, Glossbrenner averages Schwartz-Malone and Wilks.
+        let men = (schwartz_coefficient(90.0) + wilks_coefficient_men(90.0)) / 2.0;
+        assert_eq!(glossbrenner_coefficient_men(90.0), men);
+        let women = (malone_coefficient(60.0) + wilks_coefficient_women(60.0)) / 2.0;
+        assert_eq!(glossbrenner_coefficient_women(60.0), women);
+
+        // At the extremes, Wilks is replaced by a linear fit.
+        let men = (schwartz_coefficient(160.0) + wilks_coefficient_men(160.0)) / 2.0;
+        assert!((glossbrenner_coefficient_men(160.0) - men).abs() > 0.0001);
+        let women = (malone_coefficient(110.0) + wilks_coefficient_women(110.0)) / 2.0;
+        assert!((glossbrenner_coefficient_women(110.0) - women).abs() > 0.0001);
+
+        // The pieces meet at the boundaries.
+        let gap = glossbrenner_coefficient_men(153.05) - glossbrenner_coefficient_men(153.04);
+        assert!(gap.abs() < 0.001);
+        let gap = glossbrenner_coefficient_women(106.3) - glossbrenner_coefficient_women(106.29);
+        assert!(gap.abs() < 0.001);
+    }
+
     #[test]
     fn points() {
         // Point values taken (rounded) from the old Python implementation.
@@ -96,4 +121,13 @@ mod tests {
             Points::from_i32(0)
         );
     }
+
+    #[test]
+    fn bodyweight_floor() {
+        let total = WeightKg::from_i32(200);
+        assert_eq!(
+            glossbrenner(Sex::F, WeightKg::from_i32(30), total),
+            glossbrenner(Sex::F, WeightKg::from_i32(40), total)
+        );
+    }
 }
@@ -23,16 +23,24 @@
 
 use opltypes::*;
 
+use crate::UnknownEquipmentError;
+
 /// Hardcoded formula parameters: `(A, B, C)`.
 type Parameters = (f64, f64, f64);
 
 /// Gets formula parameters from what is effectively a lookup table.
-fn parameters(sex: Sex, equipment: Equipment, event: Event) -> Parameters {
+fn parameters(
+    sex: Sex,
+    equipment: Equipment,
+    event: Event,
+) -> Result<Parameters, UnknownEquipmentError> {
     // Since the formula was made for the IPF, it only covers Raw and Single-ply.
     // We do our best and just reuse those for Wraps and Multi-ply, respectively.
     let equipment = match equipment {
         Equipment::Raw | Equipment::Wraps | Equipment::Straps => Equipment::Raw,
         Equipment::Single | Equipment::Multi | Equipment::Unlimited => Equipment::Single,
+        // Without the equipment, there's no way to choose parameters.
+        Equipment::Unknown => return Err(UnknownEquipmentError),
     };
 
     // Points are only specified for Sex::M and Sex::F.
@@ -44,7 +52,7 @@ fn parameters(sex: Sex, equipment: Equipment, event: Event) -> Parameters {
     const SBD: Event = Event::sbd();
     const B: Event = Event::b();
 
-    match (event, dichotomous_sex, equipment) {
+    Ok(match (event, dichotomous_sex, equipment) {
         (SBD, Sex::M, Equipment::Raw) => (1199.72839, 1025.18162, 0.009210),
         (SBD, Sex::M, Equipment::Single) => (1236.25115, 1449.21864, 0.01644),
         (SBD, Sex::F, Equipment::Raw) => (610.32796, 1045.59282, 0.03048),
@@ -56,23 +64,25 @@ fn parameters(sex: Sex, equipment: Equipment, event: Event) -> Parameters {
         (B, Sex::F, Equipment::Single) => (221.82209, 357.00377, 0.02937),
 
         _ => (0.0, 0.0, 0.0),
-    }
+    })
 }
 
 /// Calculates IPF GOODLIFT Points.
+///
+/// Fails if the `equipment` is `Equipment::Unknown`.
 pub fn goodlift(
     sex: Sex,
     equipment: Equipment,
     event: Event,
     bodyweight: WeightKg,
     total: WeightKg,
-) -> Points {
+) -> Result<Points, UnknownEquipmentError> {
     // Look up parameters.
-    let (a, b, c) = parameters(sex, equipment, event);
+    let (a, b, c) = parameters(sex, equipment, event)?;
 
     // Exit early for undefined cases.
     if a == 0.0 || bodyweight < WeightKg::from_i32(35) || total.is_zero() {
-        return Points::from_i32(0);
+        return Ok(Points::from_i32(0));
     }
 
     // A - B * e^(-C * Bwt).
@@ -81,13 +91,27 @@ pub fn goodlift(
 
     // Prevent division by zero.
     if denominator == 0.0 {
-        return Points::from_i32(0);
+        return Ok(Points::from_i32(0));
     }
 
     // Calculate GOODLIFT points.
     // We add the requirement that the value be non-negative.
     let points: f64 = f64::from(total) * (0.0_f64).max(100.0 / denominator);
-    Points::from(points)
+    Ok(Points::from(points))
+}
+
+/// Calculates IPF GL Points.
+///
+/// This is the IPF's official name for GOODLIFT Points, which replaced the
+/// older IPF Points formula in 2020.
+pub fn ipf_gl_points(
+    sex: Sex,
+    equipment: Equipment,
+    event: Event,
+    bodyweight: WeightKg,
+    total: WeightKg,
+) -> Result<Points, UnknownEquipmentError> {
+    goodlift(sex, equipment, event, bodyweight, total)
 }
 
 #[cfg(test)]
@@ -101,7 +125,7 @@ mod tests {
         let total = WeightKg::from_f32(1035.0);
         assert_eq!(
             goodlift(Sex::M, Equipment::Single, Event::sbd(), weight, total),
-            Points::from(112.85)
+            Ok(Points::from(112.85))
         );
 
         // Susanna Torronen from 2019 World Open Classic Bench Press Championships.
@@ -109,7 +133,55 @@ mod tests {
         let total = WeightKg::from_f32(122.5);
         assert_eq!(
             goodlift(Sex::F, Equipment::Raw, Event::b(), weight, total),
-            Points::from(96.78)
+            Ok(Points::from(96.78))
+        );
+    }
+
+    #[test]
+    fn coefficient_tables() {
+        // One case for each full-power table, computed from the published coefficients.
+        let points = |sex, equipment, bw, total| {
+            let bw = WeightKg::from_f32(bw);
+            let total = WeightKg::from_f32(total);
+            ipf_gl_points(sex, equipment, Event::sbd(), bw, total)
+        };
+        assert_eq!(
+            points(Sex::M, Equipment::Raw, 93.0, 800.0),
+            Ok(Points::from(104.66))
+        );
+        assert_eq!(
+            points(Sex::M, Equipment::Single, 92.04, 1035.0),
+            Ok(Points::from(112.85))
+        );
+        assert_eq!(
+            points(Sex::F, Equipment::Raw, 57.0, 450.0),
+            Ok(Points::from(105.55))
+        );
+        assert_eq!(
+            points(Sex::F, Equipment::Single, 63.0, 550.0),
+            Ok(Points::from(99.29))
+        );
+
+        // Bench-only lifts use their own tables.
+        let bw = WeightKg::from_f32(120.0);
+        let total = WeightKg::from_f32(300.0);
+        assert_eq!(
+            ipf_gl_points(Sex::M, Equipment::Raw, Event::b(), bw, total),
+            Ok(Points::from(126.56))
+        );
+    }
+
+    #[test]
+    fn unknown_equipment() {
+        let weight = WeightKg::from_f32(92.04);
+        let total = WeightKg::from_f32(1035.0);
+        assert_eq!(
+            goodlift(Sex::M, Equipment::Unknown, Event::sbd(), weight, total),
+            Err(UnknownEquipmentError)
+        );
+        assert_eq!(
+            ipf_gl_points(Sex::F, Equipment::Unknown, Event::b(), weight, total),
+            Err(UnknownEquipmentError)
         );
     }
 }
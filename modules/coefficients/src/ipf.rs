@@ -23,16 +23,24 @@
 
 use opltypes::*;
 
+use crate::UnknownEquipmentError;
+
 /// Hardcoded formula parameters: `(mean_1, mean_2, deviation_1, deviation_2)`.
 type Parameters = (f64, f64, f64, f64);
 
 /// Gets formula parameters from what is effectively a lookup table.
-fn parameters(sex: Sex, equipment: Equipment, event: Event) -> Parameters {
+fn parameters(
+    sex: Sex,
+    equipment: Equipment,
+    event: Event,
+) -> Result<Parameters, UnknownEquipmentError> {
     // Since the formula was made for the IPF, it only covers Raw and Single-ply.
     // We do our best and just reuse those for Wraps and Multi-ply, respectively.
     let equipment = match equipment {
         Equipment::Raw | Equipment::Wraps | Equipment::Straps => Equipment::Raw,
         Equipment::Single | Equipment::Multi | Equipment::Unlimited => Equipment::Single,
+        // Without the equipment, there's no way to choose parameters.
+        Equipment::Unknown => return Err(UnknownEquipmentError),
     };
 
     // IPF Points are only specified for Sex::M and Sex::F.
@@ -46,7 +54,7 @@ fn parameters(sex: Sex, equipment: Equipment, event: Event) -> Parameters {
     const B: Event = Event::b();
     const D: Event = Event::d();
 
-    match (event, dichotomous_sex, equipment) {
+    Ok(match (event, dichotomous_sex, equipment) {
         (SBD, Sex::M, Equipment::Raw) => (310.67, 857.785, 53.216, 147.0835),
         (SBD, Sex::M, Equipment::Single) => (387.265, 1121.28, 80.6324, 222.4896),
         (SBD, Sex::F, Equipment::Raw) => (125.1435, 228.03, 34.5246, 86.8301),
@@ -68,26 +76,28 @@ fn parameters(sex: Sex, equipment: Equipment, event: Event) -> Parameters {
         (D, Sex::F, Equipment::Single) => (51.0020, 69.8265, 8.5802, 5.7258),
 
         _ => (0.0, 0.0, 0.0, 0.0),
-    }
+    })
 }
 
 /// Calculates IPF Points.
 ///
 /// The IPF formula is a normal distribution with a mean of 500 and a standard
 /// deviation of 100.
+///
+/// Fails if the `equipment` is `Equipment::Unknown`.
 pub fn ipf(
     sex: Sex,
     equipment: Equipment,
     event: Event,
     bodyweight: WeightKg,
     total: WeightKg,
-) -> Points {
+) -> Result<Points, UnknownEquipmentError> {
     // Look up parameters.
-    let (mean1, mean2, dev1, dev2) = parameters(sex, equipment, event);
+    let (mean1, mean2, dev1, dev2) = parameters(sex, equipment, event)?;
 
     // Exit early for undefined cases.
     if mean1 == 0.0 || bodyweight < WeightKg::from_i32(40) || total.is_zero() {
-        return Points::from_i32(0);
+        return Ok(Points::from_i32(0));
     }
 
     // Calculate the properties of the normal distribution.
@@ -97,7 +107,7 @@ pub fn ipf(
 
     // Prevent division by zero.
     if dev == 0.0 {
-        return Points::from_i32(0);
+        return Ok(Points::from_i32(0));
     }
 
     // Calculate IPF points.
@@ -105,7 +115,7 @@ pub fn ipf(
     // Although this breaks from the formal definition of the formula,
     // it looks to have been the IPF's intention.
     let points: f64 = (0.0_f64).max(500.0 + 100.0 * (f64::from(total) - mean) / dev);
-    Points::from(points)
+    Ok(Points::from(points))
 }
 
 #[cfg(test)]
@@ -114,7 +124,7 @@ mod tests {
 
     /// A simple helper to pass some defaults, so the testcases aren't too long.
     fn test_helper(bodyweight: WeightKg, total: WeightKg) -> Points {
-        ipf(Sex::M, Equipment::Raw, Event::sbd(), bodyweight, total)
+        ipf(Sex::M, Equipment::Raw, Event::sbd(), bodyweight, total).unwrap()
     }
 
     #[test]
@@ -128,7 +138,7 @@ mod tests {
         let total = WeightKg::from_f32(337.5);
         assert_eq!(
             ipf(Sex::F, Equipment::Raw, Event::sbd(), weight, total),
-            Points::from(546.67)
+            Ok(Points::from(546.67))
         );
     }
 
@@ -157,5 +167,12 @@ mod tests {
             test_helper(WeightKg::from_f32(100.0), WeightKg::from_f32(-100.0)),
             Points::from(0.0)
         );
+
+        // Unknown equipment has no parameters.
+        let (bw, total) = (WeightKg::from_f32(100.0), WeightKg::from_f32(700.0));
+        assert_eq!(
+            ipf(Sex::M, Equipment::Unknown, Event::sbd(), bw, total),
+            Err(UnknownEquipmentError)
+        );
     }
 }
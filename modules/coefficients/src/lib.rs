@@ -24,6 +24,9 @@
 
 extern crate opltypes;
 
+use std::error::Error;
+use std::fmt;
+
 mod ah;
 pub use crate::ah::ah;
 
@@ -34,7 +37,7 @@ mod glossbrenner;
 pub use crate::glossbrenner::glossbrenner;
 
 mod goodlift;
-pub use crate::goodlift::goodlift;
+pub use crate::goodlift::{goodlift, ipf_gl_points};
 
 mod hoffman;
 pub use crate::hoffman::hoffman;
@@ -43,16 +46,19 @@ mod ipf;
 pub use crate::ipf::ipf;
 
 mod mcculloch;
-pub use crate::mcculloch::mcculloch;
+pub use crate::mcculloch::{mcculloch, mcculloch_coefficient, mcculloch_points};
 
 mod nasa;
 pub use crate::nasa::nasa;
 
+mod points_for;
+pub use crate::points_for::points_for;
+
 mod reshel;
 pub use crate::reshel::reshel;
 
 mod schwartzmalone;
-pub use crate::schwartzmalone::schwartzmalone;
+pub use crate::schwartzmalone::{malone_coefficient, schwartz_coefficient, schwartzmalone};
 
 mod wilks;
 pub use crate::wilks::wilks;
@@ -60,6 +66,22 @@ pub use crate::wilks::wilks;
 mod wilks2020;
 pub use crate::wilks2020::wilks2020;
 
+/// An error for coefficients that depend on the Equipment, when it is
+/// `Equipment::Unknown`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnknownEquipmentError;
+
+impl fmt::Display for UnknownEquipmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the coefficients depend on the Equipment, which is unknown"
+        )
+    }
+}
+
+impl Error for UnknownEquipmentError {}
+
 /// Multiply and add. On many CPUs, this is a single instruction.
 #[inline(always)]
 fn madd(a: f64, b: f64, c: f64) -> f64 {
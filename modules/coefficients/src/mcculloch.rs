@@ -147,6 +147,30 @@ const AGE_COEFFICIENTS: [f64; 101] = [
     3.154, // 100
 ];
 
+/// Calculates the McCulloch/Foster age coefficient.
+///
+/// Lifters aged 23 through 40 receive no adjustment. Juniors are adjusted by the
+/// Foster coefficients, and Masters by the McCulloch coefficients.
+///
+/// An approximate age could be either of two adjacent ages, so it receives the
+/// midpoint of their two coefficients. This differs from `mcculloch()`, which
+/// keeps the less generous of the two for rankings.
+pub fn mcculloch_coefficient(age: Age) -> f64 {
+    match age {
+        Age::Approximate(age) => {
+            let younger = age_coeff(Age::Exact(age));
+            let older = age_coeff(Age::Exact(age.saturating_add(1)));
+            (younger + older) / 2.0
+        }
+        _ => age_coeff(age),
+    }
+}
+
+/// Applies the McCulloch/Foster age coefficient to existing points.
+pub fn mcculloch_points(points: Points, age: Age) -> Points {
+    Points::from(f64::from(points) * mcculloch_coefficient(age))
+}
+
 /// Calculates an appropriate age coefficient.
 fn age_coeff(age: Age) -> f64 {
     match age {
@@ -205,6 +229,43 @@ pub fn mcculloch(sex: Sex, bodyweight: WeightKg, total: WeightKg, age: Age) -> P
 mod tests {
     use super::*;
 
+    #[test]
+    fn masters_boundary() {
+        assert_eq!(mcculloch_coefficient(Age::Exact(40)), 1.0);
+        assert_eq!(mcculloch_coefficient(Age::Exact(41)), 1.01);
+        assert_eq!(mcculloch_coefficient(Age::Exact(50)), 1.13);
+        assert_eq!(mcculloch_coefficient(Age::Exact(60)), 1.34);
+
+        assert_eq!(mcculloch_coefficient(Age::None), 1.0);
+    }
+
+    #[test]
+    fn approximate_midpoint() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        // Approximate ages take the midpoint of the two possible ages.
+        assert!(close(mcculloch_coefficient(Age::Approximate(40)), 1.005));
+        assert!(close(mcculloch_coefficient(Age::Approximate(59)), 1.3275));
+        assert!(close(mcculloch_coefficient(Age::Approximate(30)), 1.0));
+
+        // Rankings points still use the less generous age.
+        assert_eq!(age_coeff(Age::Approximate(59)), 1.315);
+
+        // The oldest ages don't overflow.
+        let oldest = mcculloch_coefficient(Age::Exact(u8::MAX));
+        assert_eq!(mcculloch_coefficient(Age::Approximate(u8::MAX)), oldest);
+    }
+
+    #[test]
+    fn points() {
+        let points = Points::from_i32(400);
+        assert_eq!(mcculloch_points(points, Age::Exact(30)), points);
+        assert_eq!(
+            mcculloch_points(points, Age::Exact(50)),
+            Points::from(452.0)
+        );
+    }
+
     #[test]
     fn bounds_check() {
         // Don't panic.
@@ -0,0 +1,96 @@
+//! Dispatch to the formula for a points system.
+//
+// Copyright (c) 2020 The OpenPowerlifting Project
+//
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and/or associated documentation files (the
+// "Materials"), to deal in the Materials without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Materials, and to
+// permit persons to whom the Materials are furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Materials.
+//
+// THE MATERIALS ARE PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+// CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,
+// TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
+// MATERIALS OR THE USE OR OTHER DEALINGS IN THE MATERIALS.
+
+use opltypes::*;
+
+use crate::*;
+
+/// Calculates points under any points system.
+///
+/// Arguments that a system does not use are ignored. Systems that depend on
+/// the equipment score zero points for `Equipment::Unknown`.
+pub fn points_for(
+    system: PointsSystem,
+    sex: Sex,
+    equipment: Equipment,
+    event: Event,
+    bodyweight: WeightKg,
+    total: WeightKg,
+    age: Age,
+) -> Points {
+    match system {
+        PointsSystem::AH => ah(sex, bodyweight, total),
+        PointsSystem::Dots => dots(sex, bodyweight, total),
+        PointsSystem::Glossbrenner => glossbrenner(sex, bodyweight, total),
+        PointsSystem::Goodlift => {
+            goodlift(sex, equipment, event, bodyweight, total).unwrap_or_default()
+        }
+        PointsSystem::IPFPoints => {
+            ipf(sex, equipment, event, bodyweight, total).unwrap_or_default()
+        }
+        PointsSystem::McCulloch => mcculloch(sex, bodyweight, total, age),
+        PointsSystem::NASA => nasa(bodyweight, total),
+        PointsSystem::Reshel => reshel(sex, bodyweight, total),
+        PointsSystem::SchwartzMalone => schwartzmalone(sex, bodyweight, total),
+        PointsSystem::Total => total.as_kg().as_points(),
+        PointsSystem::Wilks => wilks(sex, bodyweight, total),
+        PointsSystem::Wilks2020 => wilks2020(sex, bodyweight, total),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn every_system() {
+        let bw = WeightKg::from_i32(90);
+        let total = WeightKg::from_i32(600);
+        for system in PointsSystem::iter() {
+            let points = points_for(
+                system,
+                Sex::M,
+                Equipment::Raw,
+                Event::sbd(),
+                bw,
+                total,
+                Age::Exact(30),
+            );
+            assert!(points > Points::from_i32(0), "{:?}", system);
+        }
+
+        // Total reinterprets the weight, matching how the database ranks by Total.
+        let points = points_for(
+            PointsSystem::Total,
+            Sex::F,
+            Equipment::Raw,
+            Event::sbd(),
+            bw,
+            total,
+            Age::None,
+        );
+        assert_eq!(points, total.as_kg().as_points());
+    }
+}
@@ -165,6 +165,14 @@ mod tests {
         assert!(matches_table(schwartz_coefficient(348.0 / kg), 0.4854));
     }
 
+    #[test]
+    fn bodyweight_bounds() {
+        // Bodyweights outside the tables match the nearest endpoint.
+        assert_eq!(schwartz_coefficient(30.0), schwartz_coefficient(40.0));
+        assert_eq!(schwartz_coefficient(200.0), schwartz_coefficient(166.0));
+        assert_eq!(malone_coefficient(20.0), malone_coefficient(29.24));
+    }
+
     #[test]
     fn points() {
         // Points taken verbatim from the old Python implementation.
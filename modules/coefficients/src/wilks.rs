@@ -34,9 +34,8 @@ pub fn wilks_coefficient_men(bodyweightkg: f64) -> f64 {
     const E: f64 = 7.01863E-06;
     const F: f64 = -1.291E-08;
 
-    // Upper bound avoids asymptote.
-    // Lower bound avoids children with huge coefficients.
-    let adjusted = bodyweightkg.clamp(40.0, 201.9);
+    let (min, max) = PointsSystem::Wilks.valid_bodyweight_range(Sex::M).unwrap();
+    let adjusted = bodyweightkg.clamp(min, max);
 
     500.0 / poly5(F, E, D, C, B, A, adjusted)
 }
@@ -49,14 +48,16 @@ pub fn wilks_coefficient_women(bodyweightkg: f64) -> f64 {
     const E: f64 = 0.00004731582;
     const F: f64 = -0.00000009054;
 
-    // Upper bound avoids asymptote.
-    // Lower bound avoids children with huge coefficients.
-    let adjusted = bodyweightkg.clamp(26.51, 154.53);
+    let (min, max) = PointsSystem::Wilks.valid_bodyweight_range(Sex::F).unwrap();
+    let adjusted = bodyweightkg.clamp(min, max);
 
     500.0 / poly5(F, E, D, C, B, A, adjusted)
 }
 
 /// Calculates Wilks points.
+///
+/// The fixed-point inputs are converted to `f64` exactly, and the result is
+/// rounded only once, when converted to `Points`.
 pub fn wilks(sex: Sex, bodyweight: WeightKg, total: WeightKg) -> Points {
     if bodyweight.is_zero() || total.is_zero() {
         return Points::from_i32(0);
@@ -91,4 +92,25 @@ mod tests {
             Points::from(557.4434)
         );
     }
+
+    #[test]
+    fn fixed_point_inputs() {
+        // A 182lb lifter, whose bodyweight was converted to kilograms.
+        let bw = "82.55".parse::<WeightKg>().unwrap();
+        let total = "612.5".parse::<WeightKg>().unwrap();
+        assert_eq!(wilks(Sex::M, bw, total), Points::from(410.17));
+        assert_eq!(
+            wilks(Sex::M, WeightKg::from_f32(82.55), WeightKg::from_f32(612.5)),
+            wilks(Sex::M, bw, total)
+        );
+    }
+
+    #[test]
+    fn bodyweight_floor() {
+        let total = WeightKg::from_i32(200);
+        assert_eq!(
+            wilks(Sex::F, WeightKg::from_i32(30), total),
+            wilks(Sex::F, WeightKg::from_i32(40), total)
+        );
+    }
 }
@@ -38,8 +38,13 @@ pub fn query_benchmarks(c: &mut Criterion) {
                 year: YearFilter::AllYears,
                 event: EventFilter::FullPower,
                 state: None,
+                tested: TestedFilter::AllEntries,
+                min_bodyweight_kg: None,
             },
             order_by: OrderBy::Goodlift,
+            tie_breaks: None,
+            offset: 0,
+            limit: 0,
         };
 
         b.iter(|| {
@@ -84,6 +84,142 @@ pub fn filter_dots(entry: &Entry) -> bool {
     entry.points(PointsSystem::Dots, WeightUnits::Kg) > Points::from_i32(0)
 }
 
+/// Whether an `Entry` satisfies the minimum bodyweight of the selection.
+#[inline]
+pub fn filter_min_bodyweight(selection: &EntryFilter, entry: &Entry) -> bool {
+    match selection.min_bodyweight_kg {
+        Some(min) => entry.bodyweightkg >= min,
+        None => true,
+    }
+}
+
+/// Whether an `Entry` satisfies the drug-testing filter of the selection.
+#[inline]
+pub fn filter_tested(selection: &EntryFilter, entry: &Entry) -> bool {
+    match selection.tested {
+        TestedFilter::AllEntries => true,
+        TestedFilter::TestedOnly => entry.tested,
+        TestedFilter::UntestedOnly => !entry.tested,
+    }
+}
+
+/// Whether an `Entry` should be part of the rankings for a sort order.
+pub fn filter_for(order_by: OrderBy) -> fn(&Entry) -> bool {
+    match order_by {
+        OrderBy::Squat => filter_squat,
+        OrderBy::Bench => filter_bench,
+        OrderBy::Deadlift => filter_deadlift,
+        OrderBy::Total => filter_total,
+        OrderBy::Dots => filter_dots,
+        OrderBy::Glossbrenner => filter_glossbrenner,
+        OrderBy::Goodlift => filter_goodlift,
+        OrderBy::McCulloch => filter_mcculloch,
+        OrderBy::Wilks => filter_wilks,
+        OrderBy::Wilks2020 => filter_wilks2020,
+    }
+}
+
+/// A way of ordering Entries that tie in the rankings sort.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum TieBreak {
+    /// The Entry from the earlier meet comes first.
+    EarlierDate,
+    /// The Entry with the lower bodyweight comes first.
+    LowerBodyweight,
+    /// The Entry with the higher total comes first.
+    HigherTotal,
+    /// The Entry of the lifter with the lower LifterID comes first.
+    ///
+    /// LifterIDs are unique, so this decides any tie between two lifters.
+    LowerLifterId,
+}
+
+impl TieBreak {
+    /// Orders two Entries by this criterion alone.
+    #[inline]
+    pub fn cmp(self, meets: &[Meet], a: &Entry, b: &Entry) -> cmp::Ordering {
+        match self {
+            TieBreak::EarlierDate => meets[a.meet_id as usize]
+                .date
+                .cmp(&meets[b.meet_id as usize].date),
+            TieBreak::LowerBodyweight => a.bodyweightkg.cmp(&b.bodyweightkg),
+            TieBreak::HigherTotal => a.totalkg.cmp(&b.totalkg).reverse(),
+            TieBreak::LowerLifterId => a.lifter_id.cmp(&b.lifter_id),
+        }
+    }
+}
+
+/// The tie-breaks used by the rankings by weight, in order.
+///
+/// Whoever achieved a result first ranks higher, then whoever was lighter.
+/// For the same lifter on the same day, the Entry with the largest Total wins.
+/// The final LifterID comparison keeps the order stable between rebuilds.
+pub const DEFAULT_TIE_BREAKS: [TieBreak; 4] = [
+    TieBreak::EarlierDate,
+    TieBreak::LowerBodyweight,
+    TieBreak::HigherTotal,
+    TieBreak::LowerLifterId,
+];
+
+/// The tie-breaks used by the rankings by points, in order.
+///
+/// Bodyweight is already part of the points, so it isn't compared again.
+pub const POINTS_TIE_BREAKS: [TieBreak; 3] = [
+    TieBreak::EarlierDate,
+    TieBreak::HigherTotal,
+    TieBreak::LowerLifterId,
+];
+
+/// Gets the tie-breaks that the rankings use for a sort order.
+pub fn default_tie_breaks(order_by: OrderBy) -> &'static [TieBreak] {
+    if order_by.is_by_points() {
+        &POINTS_TIE_BREAKS
+    } else {
+        &DEFAULT_TIE_BREAKS
+    }
+}
+
+/// Orders two Entries by a chain of tie-breaks, using each in turn.
+#[inline]
+pub fn cmp_tie_breaks(
+    tie_breaks: &[TieBreak],
+    meets: &[Meet],
+    a: &Entry,
+    b: &Entry,
+) -> cmp::Ordering {
+    tie_breaks.iter().fold(cmp::Ordering::Equal, |ord, tb| {
+        ord.then_with(|| tb.cmp(meets, a, b))
+    })
+}
+
+/// Defines an `Ordering` of Entries for a rankings sort, with custom tie-breaks.
+///
+/// Entries are first ordered by the sort, best first, then by the tie-breaks.
+pub fn cmp_with_tie_breaks(
+    order_by: OrderBy,
+    tie_breaks: &[TieBreak],
+    meets: &[Meet],
+    a: &Entry,
+    b: &Entry,
+) -> cmp::Ordering {
+    let primary = match order_by {
+        OrderBy::Squat => a.highest_squatkg().cmp(&b.highest_squatkg()),
+        OrderBy::Bench => a.highest_benchkg().cmp(&b.highest_benchkg()),
+        OrderBy::Deadlift => a.highest_deadliftkg().cmp(&b.highest_deadliftkg()),
+        OrderBy::Total => a.totalkg.cmp(&b.totalkg),
+        _ => {
+            let system = PointsSystem::from(order_by);
+            let a_points = a.points(system, WeightUnits::Kg);
+            a_points.cmp(&b.points(system, WeightUnits::Kg))
+        }
+    };
+
+    // Higher results first.
+    primary
+        .reverse()
+        .then_with(|| cmp_tie_breaks(tie_breaks, meets, a, b))
+}
+
 /// Defines an `Ordering` of Entries by Squat.
 #[inline]
 pub fn cmp_squat(meets: &[Meet], a: &Entry, b: &Entry) -> cmp::Ordering {
@@ -91,16 +227,8 @@ pub fn cmp_squat(meets: &[Meet], a: &Entry, b: &Entry) -> cmp::Ordering {
     a.highest_squatkg()
         .cmp(&b.highest_squatkg())
         .reverse()
-        // If equal, sort by Date, earlier first.
-        .then_with(|| {
-            meets[a.meet_id as usize]
-                .date
-                .cmp(&meets[b.meet_id as usize].date)
-        })
-        // If equal, sort by Bodyweight, lower first.
-        .then_with(|| a.bodyweightkg.cmp(&b.bodyweightkg))
-        // If for the same lifter on the same day, prefer Entry with largest Total.
-        .then_with(|| a.totalkg.cmp(&b.totalkg).reverse())
+        // If equal, break the tie deterministically.
+        .then_with(|| cmp_tie_breaks(&DEFAULT_TIE_BREAKS, meets, a, b))
 }
 
 /// Defines an `Ordering` of Entries by Bench.
@@ -110,16 +238,8 @@ pub fn cmp_bench(meets: &[Meet], a: &Entry, b: &Entry) -> cmp::Ordering {
     a.highest_benchkg()
         .cmp(&b.highest_benchkg())
         .reverse()
-        // If equal, sort by Date, earlier first.
-        .then_with(|| {
-            meets[a.meet_id as usize]
-                .date
-                .cmp(&meets[b.meet_id as usize].date)
-        })
-        // If equal, sort by Bodyweight, lower first.
-        .then_with(|| a.bodyweightkg.cmp(&b.bodyweightkg))
-        // If for the same lifter on the same day, prefer Entry with largest Total.
-        .then_with(|| a.totalkg.cmp(&b.totalkg).reverse())
+        // If equal, break the tie deterministically.
+        .then_with(|| cmp_tie_breaks(&DEFAULT_TIE_BREAKS, meets, a, b))
 }
 
 /// Defines an `Ordering` of Entries by Deadlift.
@@ -129,16 +249,8 @@ pub fn cmp_deadlift(meets: &[Meet], a: &Entry, b: &Entry) -> cmp::Ordering {
     a.highest_deadliftkg()
         .cmp(&b.highest_deadliftkg())
         .reverse()
-        // If equal, sort by Date, earlier first.
-        .then_with(|| {
-            meets[a.meet_id as usize]
-                .date
-                .cmp(&meets[b.meet_id as usize].date)
-        })
-        // If equal, sort by Bodyweight, lower first.
-        .then_with(|| a.bodyweightkg.cmp(&b.bodyweightkg))
-        // If for the same lifter on the same day, prefer Entry with largest Total.
-        .then_with(|| a.totalkg.cmp(&b.totalkg).reverse())
+        // If equal, break the tie deterministically.
+        .then_with(|| cmp_tie_breaks(&DEFAULT_TIE_BREAKS, meets, a, b))
 }
 
 /// Defines an `Ordering` of Entries by Total.
@@ -148,14 +260,8 @@ pub fn cmp_total(meets: &[Meet], a: &Entry, b: &Entry) -> cmp::Ordering {
     a.totalkg
         .cmp(&b.totalkg)
         .reverse()
-        // If equal, sort by Date, earlier first.
-        .then_with(|| {
-            meets[a.meet_id as usize]
-                .date
-                .cmp(&meets[b.meet_id as usize].date)
-        })
-        // If equal, sort by Bodyweight, lower first.
-        .then_with(|| a.bodyweightkg.cmp(&b.bodyweightkg))
+        // If equal, break the tie deterministically.
+        .then_with(|| cmp_tie_breaks(&DEFAULT_TIE_BREAKS, meets, a, b))
 }
 
 /// Defines a generic `Ordering` of Entries by some points.
@@ -168,14 +274,8 @@ fn cmp_generic_points(meets: &[Meet], a: &Entry, b: &Entry, system: PointsSystem
     a_points
         .cmp(&b_points)
         .reverse()
-        // If equal, sort by Date, earlier first.
-        .then_with(|| {
-            meets[a.meet_id as usize]
-                .date
-                .cmp(&meets[b.meet_id as usize].date)
-        })
-        // If that's equal too, sort by Total, highest first.
-        .then_with(|| a.totalkg.cmp(&b.totalkg).reverse())
+        // If equal, break the tie deterministically.
+        .then_with(|| cmp_tie_breaks(&POINTS_TIE_BREAKS, meets, a, b))
 }
 
 /// Defines an `Ordering` of Entries by McCulloch points.
@@ -458,6 +558,36 @@ pub fn entry_indices_for<'db>(
         cur = PossiblyOwnedNonSortedNonUnique::Owned(filter);
     }
 
+    // Filter by minimum bodyweight manually.
+    if selection.min_bodyweight_kg.is_some() {
+        let filter = NonSortedNonUnique(
+            cur.0
+                .iter()
+                .filter_map(
+                    |&i| match filter_min_bodyweight(selection, opldb.entry(i)) {
+                        true => Some(i),
+                        false => None,
+                    },
+                )
+                .collect(),
+        );
+        cur = PossiblyOwnedNonSortedNonUnique::Owned(filter);
+    }
+
+    // Filter by drug-testing status manually.
+    if selection.tested != TestedFilter::AllEntries {
+        let filter = NonSortedNonUnique(
+            cur.0
+                .iter()
+                .filter_map(|&i| match filter_tested(selection, opldb.entry(i)) {
+                    true => Some(i),
+                    false => None,
+                })
+                .collect(),
+        );
+        cur = PossiblyOwnedNonSortedNonUnique::Owned(filter);
+    }
+
     cur
 }
 
@@ -471,6 +601,23 @@ pub fn full_sorted_uniqued<'db>(
 ) -> PossiblyOwnedSortedUnique<'db> {
     let cache = opldb.cache();
 
+    // A custom tie-break chain reorders the cached rankings, so sort manually.
+    if let Some(tie_breaks) = query.tie_breaks {
+        if tie_breaks != default_tie_breaks(query.order_by) {
+            let cur = entry_indices_for(&query.filter, opldb);
+            let compare = |meets: &[Meet], a: &Entry, b: &Entry| {
+                cmp_with_tie_breaks(query.order_by, tie_breaks, meets, a, b)
+            };
+            let belongs = filter_for(query.order_by);
+            return PossiblyOwnedSortedUnique::Owned(cur.sort_and_unique_by(
+                opldb.entries(),
+                opldb.meets(),
+                compare,
+                belongs,
+            ));
+        }
+    }
+
     // First, try to use the constant-time cache.
     if query.filter.federation == FederationFilter::AllFederations
         && query.filter.weightclasses == WeightClassFilter::AllClasses
@@ -478,6 +625,8 @@ pub fn full_sorted_uniqued<'db>(
         && query.filter.ageclass == AgeClassFilter::AllAges
         && query.filter.event == EventFilter::AllEvents
         && query.filter.state.is_none()
+        && query.filter.tested == TestedFilter::AllEntries
+        && query.filter.min_bodyweight_kg.is_none()
     {
         let by_sort = match query.order_by {
             OrderBy::Squat => &cache.constant_time.squat,
@@ -550,3 +699,110 @@ pub fn full_sorted_uniqued<'db>(
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tie_breaks() {
+        let meet = |date| Meet {
+            path: "test/1".into(),
+            federation: Federation::WRPF,
+            date,
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 0,
+        };
+        let meets = [
+            meet(Date::from_parts(2020, 1, 1)),
+            meet(Date::from_parts(2021, 1, 1)),
+        ];
+
+        // Entries tied on total.
+        let entry = |lifter_id, meet_id, bodyweight| Entry {
+            lifter_id,
+            meet_id,
+            totalkg: WeightKg::from_i32(600),
+            bodyweightkg: WeightKg::from_i32(bodyweight),
+            ..Entry::default()
+        };
+
+        // At the same meet, the lighter lifter wins.
+        let heavy = entry(1, 0, 100);
+        let light = entry(2, 0, 90);
+        assert_eq!(cmp_total(&meets, &light, &heavy), cmp::Ordering::Less);
+        assert_eq!(cmp_total(&meets, &heavy, &light), cmp::Ordering::Greater);
+
+        // The heavier lifter totalled first, which wins by default.
+        let later_light = entry(2, 1, 90);
+        assert_eq!(cmp_total(&meets, &heavy, &later_light), cmp::Ordering::Less);
+
+        // A custom chain can prefer bodyweight over date.
+        let by_bodyweight = [TieBreak::LowerBodyweight, TieBreak::EarlierDate];
+        let cmp = |a, b| cmp_with_tie_breaks(OrderBy::Total, &by_bodyweight, &meets, a, b);
+        assert_eq!(cmp(&later_light, &heavy), cmp::Ordering::Less);
+
+        // With everything else equal, the LifterID decides.
+        let twin = entry(0, 0, 100);
+        assert_eq!(cmp_total(&meets, &twin, &heavy), cmp::Ordering::Less);
+        assert_eq!(
+            cmp_with_tie_breaks(OrderBy::Total, &DEFAULT_TIE_BREAKS, &meets, &twin, &heavy),
+            cmp_total(&meets, &twin, &heavy)
+        );
+    }
+
+    #[test]
+    fn test_filter_min_bodyweight() {
+        let light = Entry {
+            bodyweightkg: WeightKg::from_f32(0.5),
+            ..Entry::default()
+        };
+        let normal = Entry {
+            bodyweightkg: WeightKg::from_f32(82.5),
+            ..Entry::default()
+        };
+
+        // By default, no entries are excluded.
+        let selection = EntryFilter::default();
+        assert!(filter_min_bodyweight(&selection, &light));
+        assert!(filter_min_bodyweight(&selection, &normal));
+
+        let selection = EntryFilter {
+            min_bodyweight_kg: Some(WeightKg::from_i32(20)),
+            ..EntryFilter::default()
+        };
+        assert!(!filter_min_bodyweight(&selection, &light));
+        assert!(filter_min_bodyweight(&selection, &normal));
+    }
+
+    #[test]
+    fn test_filter_tested() {
+        let tested = Entry {
+            tested: true,
+            ..Entry::default()
+        };
+        // Entries without a Tested value are untested.
+        let untested = Entry::default();
+
+        let with = |filter| EntryFilter {
+            tested: filter,
+            ..EntryFilter::default()
+        };
+
+        let all = with(TestedFilter::AllEntries);
+        assert!(filter_tested(&all, &tested));
+        assert!(filter_tested(&all, &untested));
+
+        let tested_only = with(TestedFilter::TestedOnly);
+        assert!(filter_tested(&tested_only, &tested));
+        assert!(!filter_tested(&tested_only, &untested));
+
+        let untested_only = with(TestedFilter::UntestedOnly);
+        assert!(!filter_tested(&untested_only, &tested));
+        assert!(filter_tested(&untested_only, &untested));
+    }
+}
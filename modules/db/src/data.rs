@@ -72,7 +72,7 @@ pub struct Meet {
 ///
 /// Entries are stored in a `Vec<Entry>` such that all entries for a given `lifter_id`
 /// are contiguous. This allows for very quickly determining a lifter's best Entry.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Entry {
     #[serde(rename(deserialize = "MeetID"))]
     pub meet_id: u32,
@@ -192,7 +192,10 @@ impl Entry {
             PointsSystem::Dots => self.dots,
             PointsSystem::Glossbrenner => self.glossbrenner,
             PointsSystem::Goodlift => self.goodlift,
-            PointsSystem::IPFPoints => coefficients::ipf(sex, eqp, evt, bw, total),
+            // Entries without Equipment are left out of IPF Points rankings.
+            PointsSystem::IPFPoints => {
+                coefficients::ipf(sex, eqp, evt, bw, total).unwrap_or_default()
+            }
             PointsSystem::McCulloch => self.mcculloch,
             PointsSystem::NASA => coefficients::nasa(bw, total),
             PointsSystem::Reshel => coefficients::reshel(sex, bw, total),
@@ -84,12 +84,7 @@ fn import_meets_csv(file: &str) -> Result<Vec<Meet>, Box<dyn Error>> {
 }
 
 /// Reads the `entries.csv` file into a Vec<Entry>.
-///
-/// Also fills in metadata about each Meet.
-fn import_entries_csv(
-    file: &str,
-    meets: &mut Vec<Meet>,
-) -> Result<(Vec<Entry>, MetaFederationCache), Box<dyn Error>> {
+fn import_entries_csv(file: &str) -> Result<Vec<Entry>, Box<dyn Error>> {
     let mut vec = Vec::with_capacity(3_000_000);
 
     let mut rdr = csv::ReaderBuilder::new().quoting(false).from_path(file)?;
@@ -98,28 +93,14 @@ fn import_entries_csv(
         vec.push(entry);
     }
 
-    // Initially, the entries CSV is sorted by meet_id.
-    // This ordering can be used to efficiently calculate meet metadata.
-    let metafed_cache = MetaFederationCache::make(meets, &vec);
-
-    // Calculate num_unique_lifters.
-    for (meet_id, meet) in meets.iter_mut().enumerate() {
-        meet.num_unique_lifters = precompute_num_unique_lifters(&vec, meet_id as u32);
-    }
-
-    // Sort the entries database by lifter_id.
-    // This invariant allows for extremely efficient lifter-uniqueness
-    // filtering without constructing additional data structures.
-    vec.sort_unstable_by_key(|e| e.lifter_id);
-
     vec.shrink_to_fit();
-    Ok((vec, metafed_cache))
+    Ok(vec)
 }
 
 /// Counts how many unique LifterIDs competed in a given meet.
 ///
 /// Assumes that the entries vector is sorted by meet_id --
-/// so this is only callable from within `import_entries_csv()`.
+/// so this is only callable from within `OplDb::from_parts()`.
 fn precompute_num_unique_lifters(entries: &[Entry], meet_id: u32) -> u32 {
     let found_index = entries
         .binary_search_by_key(&meet_id, |e| e.meet_id)
@@ -164,18 +145,44 @@ impl OplDb {
         entries_csv: &str,
     ) -> Result<OplDb, Box<dyn Error>> {
         let lifters = import_lifters_csv(lifters_csv)?;
-        let mut meets = import_meets_csv(meets_csv)?;
-        let (entries, metafed_cache) = import_entries_csv(entries_csv, &mut meets)?;
+        let meets = import_meets_csv(meets_csv)?;
+        let entries = import_entries_csv(entries_csv)?;
+        Ok(OplDb::from_parts(lifters, meets, entries))
+    }
+
+    /// Constructs the `OplDb` from already-parsed data, filling in metadata
+    /// about each Meet.
+    ///
+    /// As in the CSV files, the entries must be sorted by meet_id,
+    /// and every meet must have at least one entry.
+    pub fn from_parts(
+        lifters: Vec<Lifter>,
+        mut meets: Vec<Meet>,
+        mut entries: Vec<Entry>,
+    ) -> OplDb {
+        // Initially, the entries are sorted by meet_id.
+        // This ordering can be used to efficiently calculate meet metadata.
+        let metafed_cache = MetaFederationCache::make(&meets, &entries);
+
+        // Calculate num_unique_lifters.
+        for (meet_id, meet) in meets.iter_mut().enumerate() {
+            meet.num_unique_lifters = precompute_num_unique_lifters(&entries, meet_id as u32);
+        }
+
+        // Sort the entries database by lifter_id.
+        // This invariant allows for extremely efficient lifter-uniqueness
+        // filtering without constructing additional data structures.
+        entries.sort_unstable_by_key(|e| e.lifter_id);
 
         let cache = StaticCache::new(&lifters, &meets, &entries);
 
-        Ok(OplDb {
+        OplDb {
             lifters,
             meets,
             entries,
             cache,
             metafed_cache,
-        })
+        }
     }
 
     /// Returns the size of owned data structures.
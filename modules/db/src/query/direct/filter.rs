@@ -21,6 +21,10 @@ pub struct EntryFilter {
     pub year: YearFilter,
     pub event: EventFilter,
     pub state: Option<State>,
+    pub tested: TestedFilter,
+
+    /// Excludes entries with a lower bodyweight, such as placeholder zeroes.
+    pub min_bodyweight_kg: Option<WeightKg>,
 }
 
 impl Default for EntryFilter {
@@ -34,6 +38,8 @@ impl Default for EntryFilter {
             year: YearFilter::AllYears,
             event: EventFilter::AllEvents,
             state: None,
+            tested: TestedFilter::AllEntries,
+            min_bodyweight_kg: None,
         }
     }
 }
@@ -683,6 +689,34 @@ impl FromStr for EventFilter {
     }
 }
 
+/// Limits a query by drug-testing status.
+///
+/// Entries without a Tested value are treated as untested:
+/// they are included by `AllEntries` and `UntestedOnly`,
+/// but excluded by `TestedOnly`.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+pub enum TestedFilter {
+    /// Any entry, tested or not.
+    AllEntries,
+    /// Only entries marked as drug-tested.
+    TestedOnly,
+    /// Only entries not marked as drug-tested.
+    UntestedOnly,
+}
+
+impl FromStr for TestedFilter {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tested-and-untested" => Ok(TestedFilter::AllEntries),
+            "tested" => Ok(TestedFilter::TestedOnly),
+            "untested" => Ok(TestedFilter::UntestedOnly),
+            _ => Err(()),
+        }
+    }
+}
+
 /// The sort selector widget.
 #[derive(Copy, Clone, Debug, PartialEq, Serialize)]
 pub enum OrderBy {
@@ -1,15 +1,48 @@
 //! Interface for efficiently querying rankings.
 
 use std::ffi::OsStr;
+use std::ops::Range;
 use std::path::Path;
 
+use crate::algorithms::TieBreak;
 use crate::query::direct::*;
 
+/// The most rows that can be requested at once, and the default page size.
+pub const ROW_LIMIT: usize = 100;
+
 /// A query for rankings information.
+///
+/// The parts of the query apply in a fixed order: the `filter` selects
+/// entries, `order_by` and then `tie_breaks` sort them and keep each lifter's
+/// best entry, and only then do `offset` and `limit` select a page of that ranking.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Serialize)]
 pub struct RankingsQuery {
     pub filter: EntryFilter,
     pub order_by: OrderBy,
+
+    /// The tie-breaks for equal results, or `None` for the sort's defaults.
+    /// See `algorithms::default_tie_breaks()`.
+    pub tie_breaks: Option<&'static [TieBreak]>,
+
+    /// The index of the first row of the page, counting from zero.
+    pub offset: usize,
+    /// How many rows are in the page. See `page_range()`.
+    pub limit: usize,
+}
+
+/// Gets the rows of a page of a ranking, given its total length.
+///
+/// A `limit` of zero requests the default page size, and larger limits are
+/// capped at `ROW_LIMIT`. Offsets past the end produce an empty page.
+pub fn page_range(total_length: usize, offset: usize, limit: usize) -> Range<usize> {
+    let limit = if limit == 0 {
+        ROW_LIMIT
+    } else {
+        limit.min(ROW_LIMIT)
+    };
+    let start = offset.min(total_length);
+    let end = offset.saturating_add(limit).min(total_length);
+    start..end
 }
 
 impl RankingsQuery {
@@ -41,6 +74,7 @@ impl RankingsQuery {
         let mut parsed_sort: bool = false;
         let mut parsed_event: bool = false;
         let mut parsed_state: bool = false;
+        let mut parsed_tested: bool = false;
 
         // Iterate over each path component, attempting to determine
         // what kind of data it is.
@@ -106,6 +140,13 @@ impl RankingsQuery {
                 }
                 ret.filter.event = e;
                 parsed_event = true;
+            // Check whether this is drug-testing information.
+            } else if let Ok(t) = segment.parse::<TestedFilter>() {
+                if parsed_tested {
+                    return Err(());
+                }
+                ret.filter.tested = t;
+                parsed_tested = true;
             // Check whether this is a Country-State code.
             } else if let Ok(s) = opltypes::states::State::from_full_code(segment) {
                 if parsed_state {
@@ -121,15 +162,139 @@ impl RankingsQuery {
 
         Ok(ret)
     }
+
+    /// Sets the equipment filter.
+    pub fn with_equipment(mut self, equipment: EquipmentFilter) -> Self {
+        self.filter.equipment = equipment;
+        self
+    }
+
+    /// Sets the federation filter.
+    pub fn with_federation(mut self, federation: FederationFilter) -> Self {
+        self.filter.federation = federation;
+        self
+    }
+
+    /// Sets the weightclass filter.
+    pub fn with_weightclasses(mut self, weightclasses: WeightClassFilter) -> Self {
+        self.filter.weightclasses = weightclasses;
+        self
+    }
+
+    /// Sets the sex filter.
+    pub fn with_sex(mut self, sex: SexFilter) -> Self {
+        self.filter.sex = sex;
+        self
+    }
+
+    /// Sets the age class filter.
+    pub fn with_ageclass(mut self, ageclass: AgeClassFilter) -> Self {
+        self.filter.ageclass = ageclass;
+        self
+    }
+
+    /// Sets the year filter.
+    pub fn with_year(mut self, year: YearFilter) -> Self {
+        self.filter.year = year;
+        self
+    }
+
+    /// Sets the event filter.
+    pub fn with_event(mut self, event: EventFilter) -> Self {
+        self.filter.event = event;
+        self
+    }
+
+    /// Limits the query to meets in a single state.
+    pub fn with_state(mut self, state: opltypes::states::State) -> Self {
+        self.filter.state = Some(state);
+        self
+    }
+
+    /// Sets the drug-testing filter.
+    pub fn with_tested(mut self, tested: TestedFilter) -> Self {
+        self.filter.tested = tested;
+        self
+    }
+
+    /// Excludes entries below a bodyweight.
+    pub fn with_min_bodyweight_kg(mut self, bodyweight: opltypes::WeightKg) -> Self {
+        self.filter.min_bodyweight_kg = Some(bodyweight);
+        self
+    }
+
+    /// Sets the sort order.
+    pub fn with_order_by(mut self, order_by: OrderBy) -> Self {
+        self.order_by = order_by;
+        self
+    }
+
+    /// Sets the tie-breaks for equal results.
+    pub fn with_tie_breaks(mut self, tie_breaks: &'static [TieBreak]) -> Self {
+        self.tie_breaks = Some(tie_breaks);
+        self
+    }
+
+    /// Selects a page of the ranking. See `page_range()`.
+    pub fn with_page(mut self, offset: usize, limit: usize) -> Self {
+        self.offset = offset;
+        self.limit = limit;
+        self
+    }
+
+    /// Gets the rows of the query's page, given the total length of its ranking.
+    pub fn page_range(&self, total_length: usize) -> Range<usize> {
+        page_range(total_length, self.offset, self.limit)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::MetaFederation;
+    use crate::{algorithms, Entry, Lifter, Meet, MetaFederation, OplDb};
+    use opltypes::states::{State, USAState};
+    use opltypes::*;
 
     use std::path::Path;
 
+    /// Builds a `Meet` in the given year.
+    fn meet(year: u32) -> Meet {
+        Meet {
+            path: "test/1".into(),
+            federation: Federation::USAPL,
+            date: Date::from_parts(year, 6, 1),
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 0,
+        }
+    }
+
+    /// Builds a small `OplDb` around the given meets and entries.
+    ///
+    /// The entries must be sorted by meet_id, as for `OplDb::from_parts()`.
+    fn fixture(meets: Vec<Meet>, entries: Vec<Entry>) -> OplDb {
+        let num_lifters = entries.iter().map(|e| e.lifter_id + 1).max().unwrap_or(0);
+        let lifters: Vec<Lifter> = (0..num_lifters)
+            .map(|i| Lifter {
+                name: format!("Lifter {}", i).as_str().into(),
+                cyrillic_name: None,
+                greek_name: None,
+                japanese_name: None,
+                korean_name: None,
+                username: Username::from_name(&format!("lifter{}", i)).unwrap(),
+                instagram: None,
+                vkontakte: None,
+                color: None,
+                flair: None,
+            })
+            .collect();
+
+        OplDb::from_parts(lifters, meets, entries)
+    }
+
     #[test]
     fn test_rankings_query_from_path() {
         let d = RankingsQuery::default();
@@ -150,6 +315,136 @@ mod tests {
         assert_eq!(s.filter.equipment, EquipmentFilter::Raw);
     }
 
+    #[test]
+    fn test_rankings_query_builder() {
+        use opltypes::states::{State, USAState};
+
+        // Each builder call overrides only its own field.
+        let q = RankingsQuery::default()
+            .with_equipment(EquipmentFilter::Raw)
+            .with_sex(SexFilter::Women)
+            .with_year(YearFilter::OneYear(2019))
+            .with_state(State::InUSA(USAState::CA))
+            .with_order_by(OrderBy::Dots);
+
+        assert_eq!(q.filter.equipment, EquipmentFilter::Raw);
+        assert_eq!(q.filter.sex, SexFilter::Women);
+        assert_eq!(q.filter.year, YearFilter::OneYear(2019));
+        assert_eq!(q.filter.state, Some(State::InUSA(USAState::CA)));
+        assert_eq!(q.order_by, OrderBy::Dots);
+        assert_eq!(q.filter.federation, FederationFilter::AllFederations);
+
+        // The builder agrees with URL parsing.
+        let path = Path::new("/raw/women/2019/USA-CA/by-dots");
+        let d = RankingsQuery::default();
+        assert_eq!(RankingsQuery::from_url_path(path, &d).unwrap(), q);
+    }
+
+    #[test]
+    fn test_page_range() {
+        // First, middle, and last pages.
+        assert_eq!(page_range(52340, 0, 100), 0..100);
+        assert_eq!(page_range(52340, 500, 100), 500..600);
+        assert_eq!(page_range(52340, 52300, 100), 52300..52340);
+
+        // Past the end.
+        assert_eq!(page_range(52340, 60000, 100), 52340..52340);
+        assert!(page_range(0, 0, 100).is_empty());
+
+        // Zero and oversized limits.
+        assert_eq!(page_range(52340, 0, 0), 0..ROW_LIMIT);
+        assert_eq!(page_range(52340, 0, 5000), 0..ROW_LIMIT);
+        assert_eq!(page_range(52340, usize::MAX, 100), 52340..52340);
+    }
+
+    #[test]
+    fn test_rankings_query_combined() {
+        let meets = vec![meet(2019), meet(2020), meet(2020), meet(2020)];
+
+        let entry = |lifter_id, meet_id, dots, state, tested| Entry {
+            lifter_id,
+            meet_id,
+            equipment: Equipment::Raw,
+            dots: Points::from_i32(dots),
+            lifter_state: Some(State::InUSA(state)),
+            tested,
+            ..Entry::default()
+        };
+
+        let entries = vec![
+            // Excluded by the year, so lifter 0 ranks by the 2020 entry.
+            entry(0, 0, 500, USAState::CA, true),
+            entry(0, 1, 400, USAState::CA, true),
+            entry(1, 1, 450, USAState::CA, true),
+            // Excluded by the drug-testing status.
+            entry(2, 1, 480, USAState::CA, false),
+            // Excluded by the state.
+            entry(3, 2, 300, USAState::NY, true),
+            // Only the better entry of lifter 4 is ranked.
+            entry(4, 2, 350, USAState::CA, true),
+            entry(4, 3, 420, USAState::CA, true),
+            entry(5, 3, 200, USAState::CA, true),
+        ];
+
+        let db = fixture(meets, entries);
+
+        let query = RankingsQuery::default()
+            .with_year(YearFilter::OneYear(2020))
+            .with_state(State::InUSA(USAState::CA))
+            .with_tested(TestedFilter::TestedOnly)
+            .with_order_by(OrderBy::Dots)
+            .with_page(1, 2);
+
+        // Filtering, sorting, and uniqueness happen before pagination.
+        let list = algorithms::full_sorted_uniqued(&query, &db);
+        let ranked: Vec<(u32, u32)> = list
+            .0
+            .iter()
+            .map(|&i| (db.entry(i).lifter_id, db.entry(i).meet_id))
+            .collect();
+        assert_eq!(ranked, vec![(1, 1), (4, 3), (0, 1), (5, 3)]);
+
+        // The page is a slice of the full ranking.
+        let range = query.page_range(list.0.len());
+        assert_eq!(range, 1..3);
+        assert_eq!(ranked[range], [(4, 3), (0, 1)]);
+    }
+
+    #[test]
+    fn test_rankings_query_tie_breaks() {
+        use crate::algorithms::TieBreak;
+
+        // Two lifters tie on Dots: lifter 0 was first, but lifter 1 was lighter.
+        let entry = |lifter_id, meet_id, bodyweight| Entry {
+            lifter_id,
+            meet_id,
+            equipment: Equipment::Raw,
+            bodyweightkg: WeightKg::from_i32(bodyweight),
+            totalkg: WeightKg::from_i32(500),
+            dots: Points::from_i32(400),
+            ..Entry::default()
+        };
+        let db = fixture(
+            vec![meet(2019), meet(2020)],
+            vec![entry(0, 0, 90), entry(1, 1, 80)],
+        );
+        let lifter_ids = |query: &RankingsQuery| -> Vec<u32> {
+            let list = algorithms::full_sorted_uniqued(query, &db);
+            list.0.iter().map(|&i| db.entry(i).lifter_id).collect()
+        };
+
+        // By default, the earlier result ranks higher.
+        let query = RankingsQuery::default().with_order_by(OrderBy::Dots);
+        assert_eq!(lifter_ids(&query), vec![0, 1]);
+        let query = query.with_tie_breaks(algorithms::default_tie_breaks(OrderBy::Dots));
+        assert_eq!(lifter_ids(&query), vec![0, 1]);
+
+        // A custom chain can prefer the lighter lifter.
+        const LIGHTER_FIRST: [TieBreak; 2] = [TieBreak::LowerBodyweight, TieBreak::LowerLifterId];
+        let query = query.with_tie_breaks(&LIGHTER_FIRST);
+        assert_eq!(lifter_ids(&query), vec![1, 0]);
+    }
+
     #[test]
     fn test_rankings_query_from_path_errors() {
         let d = RankingsQuery::default();
@@ -165,6 +460,14 @@ mod tests {
         assert!(RankingsQuery::from_url_path(Path::new("////raw////"), &d).is_err());
         assert!(RankingsQuery::from_url_path(Path::new("////////raw"), &d).is_err());
 
+        // Drug-testing status.
+        let s = RankingsQuery::from_url_path(Path::new("/raw/tested"), &d).unwrap();
+        assert_eq!(s.filter.tested, TestedFilter::TestedOnly);
+        let s = RankingsQuery::from_url_path(Path::new("/untested"), &d).unwrap();
+        assert_eq!(s.filter.tested, TestedFilter::UntestedOnly);
+        assert_eq!(d.filter.tested, TestedFilter::AllEntries);
+        assert!(RankingsQuery::from_url_path(Path::new("/tested/untested"), &d).is_err());
+
         // Disallow nonsense.
         assert!(RankingsQuery::from_url_path(Path::new("912h3h123h12ch39"), &d).is_err());
         assert!(RankingsQuery::from_url_path(Path::new("......."), &d).is_err());
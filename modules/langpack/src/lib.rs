@@ -11,12 +11,17 @@ use opltypes::*;
 use serde::ser::Serialize;
 use strum::IntoEnumIterator;
 
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// List of languages accepted by the project, in ISO 639-1 code.
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, Debug, EnumIter, EnumString, PartialEq, Serialize, Deserialize)]
 pub enum Language {
+    /// Arabic, which is written right-to-left.
+    ///
+    /// The translations are currently stubbed to English.
+    ar,
     /// Czech.
     cz,
     /// German, without regional variance.
@@ -80,6 +85,7 @@ pub enum Language {
 impl fmt::Display for Language {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match self {
+            Language::ar => "ar",
             Language::cz => "cz",
             Language::de => "de",
             Language::el => "el",
@@ -125,6 +131,40 @@ impl Language {
     }
 }
 
+/// The direction in which text is laid out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum TextDirection {
+    /// Left-to-right, serialized as "ltr" for HTML's `dir` attribute.
+    #[serde(rename = "ltr")]
+    LeftToRight,
+    /// Right-to-left, serialized as "rtl" for HTML's `dir` attribute.
+    #[serde(rename = "rtl")]
+    RightToLeft,
+}
+
+impl Language {
+    /// Gets the direction in which the language's text is laid out.
+    pub fn text_direction(self) -> TextDirection {
+        match self {
+            Language::ar => TextDirection::RightToLeft,
+            _ => TextDirection::LeftToRight,
+        }
+    }
+
+    /// Gets the direction in which numbers and dates are laid out.
+    ///
+    /// Digits run left-to-right even within right-to-left text,
+    /// so weights, totals, and dates should be isolated as "ltr".
+    pub fn numeric_direction(self) -> TextDirection {
+        TextDirection::LeftToRight
+    }
+
+    /// Whether the language's text is laid out right-to-left.
+    pub fn is_rtl(self) -> bool {
+        self.text_direction() == TextDirection::RightToLeft
+    }
+}
+
 /// Helper struct to pass around language information.
 pub struct Locale<'a> {
     pub langinfo: &'a LangInfo,
@@ -132,6 +172,8 @@ pub struct Locale<'a> {
     pub strings: &'a Translations,
     pub number_format: NumberFormat,
     pub units: WeightUnits,
+    /// Languages consulted, in order, for strings the language lacks.
+    pub fallbacks: Vec<Language>,
 }
 
 impl<'a> Locale<'a> {
@@ -142,9 +184,21 @@ impl<'a> Locale<'a> {
             strings: langinfo.translations(language),
             number_format: language.number_format(),
             units,
+            fallbacks: vec![Language::en],
         }
     }
 
+    /// Whether text in the locale is laid out right-to-left.
+    pub fn is_rtl(&self) -> bool {
+        self.language.is_rtl()
+    }
+
+    /// Replaces the languages consulted for strings the language lacks.
+    pub fn with_fallbacks(mut self, fallbacks: Vec<Language>) -> Locale<'a> {
+        self.fallbacks = fallbacks;
+        self
+    }
+
     /// Localizes an arbitrary ordinal number.
     ///
     /// The Sex refers to either the sex of the lifter or the grammatical
@@ -682,10 +736,42 @@ pub struct Translations {
     pub labels: LabelTranslations,
     pub selectors: SelectorTranslations,
     pub lifter_page: LifterPageTranslations,
+    /// Strings that vary with a count, by key.
+    #[serde(default)]
+    pub plurals: BTreeMap<String, PluralForms>,
+}
+
+/// The forms of a string that varies with a count.
+///
+/// Forms for plural categories that a translation omits use the `other` form.
+#[derive(Serialize, Deserialize)]
+pub struct PluralForms {
+    pub zero: Option<String>,
+    pub one: Option<String>,
+    pub two: Option<String>,
+    pub few: Option<String>,
+    pub many: Option<String>,
+    pub other: String,
+}
+
+impl PluralForms {
+    /// Gets the form used for a plural category.
+    pub fn form(&self, category: PluralCategory) -> &str {
+        let form = match category {
+            PluralCategory::Zero => &self.zero,
+            PluralCategory::One => &self.one,
+            PluralCategory::Two => &self.two,
+            PluralCategory::Few => &self.few,
+            PluralCategory::Many => &self.many,
+            PluralCategory::Other => return &self.other,
+        };
+        form.as_deref().unwrap_or(&self.other)
+    }
 }
 
 /// Owner struct of all translation state.
 pub struct LangInfo {
+    ar: Translations,
     cz: Translations,
     de: Translations,
     el: Translations,
@@ -724,6 +810,7 @@ impl Default for LangInfo {
     /// TODO: Use a build.rs to also parse at compile time.
     fn default() -> LangInfo {
         LangInfo {
+            ar: json::from_str(include_str!("../translations/ar.json")).expect("ar"),
             cz: json::from_str(include_str!("../translations/cz.json")).expect("cz"),
             de: json::from_str(include_str!("../translations/de.json")).expect("de"),
             el: json::from_str(include_str!("../translations/el.json")).expect("el"),
@@ -758,6 +845,7 @@ impl Default for LangInfo {
 impl LangInfo {
     pub fn translations(&self, language: Language) -> &Translations {
         match language {
+            Language::ar => &self.ar,
             Language::cz => &self.cz,
             Language::de => &self.de,
             Language::el => &self.el,
@@ -798,6 +886,7 @@ impl Translations {
             Equipment::Multi => &self.equipment.multi,
             Equipment::Unlimited => &self.equipment.unlimited,
             Equipment::Straps => &self.equipment.straps,
+            Equipment::Unknown => "",
         }
     }
 
@@ -1024,6 +1113,7 @@ impl Language {
         // Taken from the following list:
         // https://en.wikipedia.org/wiki/Decimal_separator
         match self {
+            Language::ar => NumberFormat::ArabicPeriod,
             Language::cz => NumberFormat::ArabicComma,
             Language::de => NumberFormat::ArabicComma,
             Language::el => NumberFormat::ArabicComma,
@@ -1055,6 +1145,345 @@ impl Language {
     }
 }
 
+impl NumberFormat {
+    /// Gets the character separating the integer and fraction.
+    pub fn decimal_separator(self) -> char {
+        match self {
+            NumberFormat::ArabicPeriod => '.',
+            NumberFormat::ArabicComma => ',',
+        }
+    }
+
+    /// Gets the character conventionally grouping thousands.
+    ///
+    /// This is whichever of the period and comma isn't the decimal separator.
+    pub fn thousands_separator(self) -> char {
+        match self {
+            NumberFormat::ArabicPeriod => ',',
+            NumberFormat::ArabicComma => '.',
+        }
+    }
+}
+
+impl Language {
+    /// Gets the character grouping thousands for the given language.
+    ///
+    /// Defaults to the convention of the language's `NumberFormat`,
+    /// except for languages that group thousands with a space.
+    pub fn thousands_separator(self) -> char {
+        match self {
+            Language::cz
+            | Language::fi
+            | Language::fr
+            | Language::hu
+            | Language::lt
+            | Language::pl
+            | Language::ru
+            | Language::sk
+            | Language::sv
+            | Language::uk => '\u{a0}', // No-break space.
+            _ => self.number_format().thousands_separator(),
+        }
+    }
+}
+
+/// How a language writes ordinal numbers, like placings.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum OrdinalStyle {
+    /// Renders `1` as `1`.
+    NumberOnly,
+    /// Renders `1` as `1st`, `2` as `2nd`, `3` as `3rd`, and `4` as `4th`.
+    English,
+    /// Renders `1` as `1er`, and `2` as `2e`.
+    French,
+    /// Renders `1` as `1:a`, `2` as `2:a`, and `3` as `3:e`.
+    Swedish,
+    /// Renders `1` as `1º` for men, and `1ª` for women.
+    Romance,
+    /// Surrounds the number with a fixed prefix and suffix, like `1.`.
+    Affixes(&'static str, &'static str),
+}
+
+impl Language {
+    /// Gets how the language writes ordinal numbers.
+    fn ordinal_style(self) -> OrdinalStyle {
+        // No wildcard: a new Language must choose before it compiles.
+        match self {
+            Language::ar | Language::vi => OrdinalStyle::NumberOnly,
+            Language::en => OrdinalStyle::English,
+            Language::fr => OrdinalStyle::French,
+            Language::sv => OrdinalStyle::Swedish,
+            Language::cz
+            | Language::de
+            | Language::fi
+            | Language::hr
+            | Language::hu
+            | Language::lt
+            | Language::pl
+            | Language::sk
+            | Language::sl
+            | Language::sr
+            | Language::tr => OrdinalStyle::Affixes("", "."),
+            Language::el => OrdinalStyle::Affixes("", "ος"),
+            Language::eo => OrdinalStyle::Affixes("", "-a"),
+            Language::es | Language::it | Language::pt => OrdinalStyle::Romance,
+            Language::ja => OrdinalStyle::Affixes("", "位"),
+            Language::ko => OrdinalStyle::Affixes("", "위"),
+            Language::nl => OrdinalStyle::Affixes("", "e"),
+            Language::ru | Language::uk => OrdinalStyle::Affixes("", "-й"),
+            Language::zh_hant | Language::zh_hans => OrdinalStyle::Affixes("第", "名"),
+        }
+    }
+}
+
+/// Formats an ordinal number, like a placing, for display in the locale.
+///
+/// Gendered languages use the masculine form. Placings that should agree
+/// with the lifter's `Sex` are made by `Locale::ordinal()`, which follows
+/// the same rules.
+pub fn format_ordinal(locale: &Locale, n: u32) -> String {
+    locale.ordinal(n, Sex::M).to_string()
+}
+
+impl fmt::Display for LocalizedOrdinal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let n = self.n;
+        match self.style {
+            OrdinalStyle::NumberOnly => write!(f, "{}", n),
+            OrdinalStyle::English => {
+                let suffix = match (n % 10, n % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                write!(f, "{}{}", n, suffix)
+            }
+            OrdinalStyle::French => match n {
+                1 => write!(f, "1er"),
+                _ => write!(f, "{}e", n),
+            },
+            OrdinalStyle::Swedish => {
+                let suffix = match (n % 10, n % 100) {
+                    (_, 11..=12) => ":e",
+                    (1..=2, _) => ":a",
+                    _ => ":e",
+                };
+                write!(f, "{}{}", n, suffix)
+            }
+            OrdinalStyle::Romance => match self.sex {
+                Sex::M | Sex::Mx => write!(f, "{}º", n),
+                Sex::F => write!(f, "{}ª", n),
+            },
+            OrdinalStyle::Affixes(prefix, suffix) => write!(f, "{}{}{}", prefix, n, suffix),
+        }
+    }
+}
+
+/// Formats a number for display in the given language.
+///
+/// The number is rounded to `decimals` places after the decimal separator,
+/// and thousands in the integer part are grouped.
+pub fn format_number(language: Language, value: f64, decimals: usize) -> String {
+    let rounded = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match rounded.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (rounded.as_str(), None),
+    };
+
+    let mut s = String::with_capacity(rounded.len() + integer.len() / 3 + 1);
+    if value.is_sign_negative() && rounded.bytes().any(|b| (b'1'..=b'9').contains(&b)) {
+        s.push('-');
+    }
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            s.push(language.thousands_separator());
+        }
+        s.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        s.push(language.number_format().decimal_separator());
+        s.push_str(fraction);
+    }
+    s
+}
+
+/// The CLDR plural categories, which select the form of a counted word.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl Language {
+    /// Gets the plural category of a whole-number count.
+    ///
+    /// Taken from the CLDR cardinal plural rules:
+    /// https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html
+    pub fn plural_category(self, n: u64) -> PluralCategory {
+        let (n10, n100) = (n % 10, n % 100);
+        match self {
+            // No plural distinction.
+            Language::ja | Language::ko | Language::vi | Language::zh_hant | Language::zh_hans => {
+                PluralCategory::Other
+            }
+
+            Language::ar => match n100 {
+                _ if n == 0 => PluralCategory::Zero,
+                _ if n == 1 => PluralCategory::One,
+                _ if n == 2 => PluralCategory::Two,
+                3..=10 => PluralCategory::Few,
+                11..=99 => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+
+            // Zero or one.
+            Language::fr | Language::pt => match n {
+                0 | 1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+
+            // East Slavic: one, few, many.
+            Language::ru | Language::uk => {
+                if n10 == 1 && n100 != 11 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+
+            Language::pl => {
+                if n == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Many
+                }
+            }
+
+            // South Slavic, without a separate form for many.
+            Language::hr | Language::sr => {
+                if n10 == 1 && n100 != 11 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Other
+                }
+            }
+
+            Language::cz | Language::sk => match n {
+                1 => PluralCategory::One,
+                2..=4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+
+            Language::sl => match n100 {
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                3 | 4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+
+            Language::lt => {
+                if (11..=19).contains(&n100) {
+                    PluralCategory::Other
+                } else if n10 == 1 {
+                    PluralCategory::One
+                } else if n10 >= 2 {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Other
+                }
+            }
+
+            // One or other, like English.
+            Language::de
+            | Language::el
+            | Language::en
+            | Language::eo
+            | Language::es
+            | Language::fi
+            | Language::hu
+            | Language::it
+            | Language::nl
+            | Language::sv
+            | Language::tr => match n {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+        }
+    }
+}
+
+/// Gets the form of a counted string, like "meet" or "meets".
+///
+/// If the locale has no translation for the key, its fallback languages are
+/// consulted in order. If none has a translation, the key itself is used.
+pub fn pluralize<'a>(locale: &Locale<'a>, key: &'a str, count: u64) -> &'a str {
+    if let Some(forms) = locale.strings.plurals.get(key) {
+        return forms.form(locale.language.plural_category(count));
+    }
+
+    for &language in &locale.fallbacks {
+        if let Some(forms) = locale.langinfo.translations(language).plurals.get(key) {
+            return forms.form(language.plural_category(count));
+        }
+    }
+    key
+}
+
+/// Collects the keys of every string in a set of translations.
+///
+/// Keys are paths through the translations, like "columns.squat".
+/// The forms of a plural are counted together under its key,
+/// since languages differ in which forms they need.
+fn translation_keys(translations: &Translations) -> Vec<String> {
+    fn collect(value: &json::Value, path: &str, keys: &mut Vec<String>) {
+        match value {
+            json::Value::Object(map) if path != "plurals" || map.is_empty() => {
+                for (name, child) in map {
+                    let child_path = if path.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{}.{}", path, name)
+                    };
+                    collect(child, &child_path, keys);
+                }
+            }
+            json::Value::Object(map) => {
+                keys.extend(map.keys().map(|name| format!("{}.{}", path, name)));
+            }
+            json::Value::Null => (),
+            _ => keys.push(path.to_string()),
+        }
+    }
+
+    let mut keys = Vec::new();
+    let value = json::to_value(translations).expect("Translations serialization is infallible");
+    collect(&value, "", &mut keys);
+    keys
+}
+
+/// Lists the keys translated into English but not into the locale's language.
+///
+/// Lets translators find what remains to be done.
+pub fn missing_keys(locale: &Locale) -> Vec<String> {
+    let present = translation_keys(locale.strings);
+    translation_keys(locale.langinfo.translations(Language::en))
+        .into_iter()
+        .filter(|key| !present.contains(key))
+        .collect()
+}
+
 /// Type that gets serialized into a localized `WeightAny`.
 ///
 /// This is the final weight type that should be stored in the `Context`
@@ -1100,28 +1529,21 @@ impl Serialize for LocalizedPoints {
 
 /// Type that gets serialized into a localized ordinal.
 ///
-/// This is useful for Spanish and Portuguese, which use a special
-/// notation for ordinal numbers (dependent on the lifter's `Sex`).
+/// Follows the language's ordinal style, which in Spanish, Italian,
+/// and Portuguese depends on the lifter's `Sex`.
 #[derive(Copy, Clone)]
-pub enum LocalizedOrdinal {
-    /// Renders `1` as `1`.
-    NumberOnly(u32),
-
-    /// Renders `1` as `1º`, for men.
-    RomanceMasculine(u32),
-
-    /// Renders `1` as `1ª`, for women.
-    RomanceFeminine(u32),
+pub struct LocalizedOrdinal {
+    n: u32,
+    style: OrdinalStyle,
+    sex: Sex,
 }
 
 impl LocalizedOrdinal {
     pub fn from(n: u32, language: Language, sex: Sex) -> LocalizedOrdinal {
-        match language {
-            Language::es | Language::it | Language::pt => match sex {
-                Sex::M | Sex::Mx => LocalizedOrdinal::RomanceMasculine(n),
-                Sex::F => LocalizedOrdinal::RomanceFeminine(n),
-            },
-            _ => LocalizedOrdinal::NumberOnly(n),
+        LocalizedOrdinal {
+            n,
+            style: language.ordinal_style(),
+            sex,
         }
     }
 }
@@ -1131,16 +1553,9 @@ impl Serialize for LocalizedOrdinal {
     where
         S: serde::Serializer,
     {
-        match *self {
-            Self::NumberOnly(n) => serializer.serialize_u32(n),
-            Self::RomanceMasculine(n) => {
-                let s = format!("{}º", n);
-                serializer.serialize_str(&s)
-            }
-            Self::RomanceFeminine(n) => {
-                let s = format!("{}ª", n);
-                serializer.serialize_str(&s)
-            }
+        match self.style {
+            OrdinalStyle::NumberOnly => serializer.serialize_u32(self.n),
+            _ => serializer.collect_str(self),
         }
     }
 }
@@ -1276,4 +1691,127 @@ mod tests {
         // This will panic if the translation files fail parsing.
         LangInfo::default();
     }
+
+    #[test]
+    fn test_pluralize() {
+        let langinfo = LangInfo::default();
+
+        let en = Locale::new(&langinfo, Language::en, WeightUnits::Kg);
+        assert_eq!(pluralize(&en, "meets", 1), "meet");
+        assert_eq!(pluralize(&en, "meets", 0), "meets");
+        assert_eq!(pluralize(&en, "meets", 3), "meets");
+
+        let ru = Locale::new(&langinfo, Language::ru, WeightUnits::Kg);
+        assert_eq!(pluralize(&ru, "meets", 1), "соревнование");
+        assert_eq!(pluralize(&ru, "meets", 21), "соревнование");
+        assert_eq!(pluralize(&ru, "meets", 3), "соревнования");
+        assert_eq!(pluralize(&ru, "meets", 22), "соревнования");
+        assert_eq!(pluralize(&ru, "meets", 5), "соревнований");
+        assert_eq!(pluralize(&ru, "meets", 12), "соревнований");
+
+        // Russian has no translation of "lifters", so English is used.
+        assert_eq!(pluralize(&ru, "lifters", 1), "lifter");
+        assert_eq!(pluralize(&ru, "lifters", 5), "lifters");
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let langinfo = LangInfo::default();
+
+        // A deliberately incomplete locale, lacking the "lifters" plural.
+        let mut incomplete: Translations =
+            json::from_str(include_str!("../translations/en.json")).unwrap();
+        incomplete.plurals.remove("lifters");
+        let incomplete = Locale {
+            strings: &incomplete,
+            ..Locale::new(&langinfo, Language::de, WeightUnits::Kg)
+        };
+
+        assert_eq!(pluralize(&incomplete, "lifters", 1), "lifter");
+        assert_eq!(pluralize(&incomplete, "nonexistent", 2), "nonexistent");
+        assert_eq!(missing_keys(&incomplete), vec!["plurals.lifters"]);
+
+        // Without fallbacks, the raw key is used.
+        let incomplete = incomplete.with_fallbacks(vec![]);
+        assert_eq!(pluralize(&incomplete, "lifters", 1), "lifters");
+        assert_eq!(pluralize(&incomplete, "meets", 1), "meet");
+
+        let en = Locale::new(&langinfo, Language::en, WeightUnits::Kg);
+        assert!(missing_keys(&en).is_empty());
+    }
+
+    #[test]
+    fn test_rtl() {
+        let langinfo = LangInfo::default();
+        assert!(Locale::new(&langinfo, Language::ar, WeightUnits::Kg).is_rtl());
+        assert!(!Locale::new(&langinfo, Language::en, WeightUnits::Kg).is_rtl());
+
+        assert_eq!(Language::ar.text_direction(), TextDirection::RightToLeft);
+        assert_eq!(Language::ar.numeric_direction(), TextDirection::LeftToRight);
+        assert_eq!(Language::en.numeric_direction(), TextDirection::LeftToRight);
+
+        // The stubbed Arabic translations fall back to English.
+        let ar = Locale::new(&langinfo, Language::ar, WeightUnits::Kg);
+        assert_eq!(pluralize(&ar, "meets", 1), "meet");
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(Language::en, 1234.5, 1), "1,234.5");
+        assert_eq!(format_number(Language::de, 1234.5, 1), "1.234,5");
+        assert_eq!(format_number(Language::fr, 1234.5, 1), "1\u{a0}234,5");
+
+        assert_eq!(format_number(Language::en, 1234567.0, 0), "1,234,567");
+        assert_eq!(format_number(Language::en, 999.96, 1), "1,000.0");
+        assert_eq!(format_number(Language::de, -1234.5, 2), "-1.234,50");
+        assert_eq!(format_number(Language::en, -0.01, 1), "0.0");
+    }
+
+    #[test]
+    fn test_format_ordinal() {
+        let langinfo = LangInfo::default();
+
+        let en = Locale::new(&langinfo, Language::en, WeightUnits::Kg);
+        let ordinals: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 111, 112]
+            .iter()
+            .map(|&n| format_ordinal(&en, n))
+            .collect();
+        assert_eq!(
+            ordinals,
+            vec![
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "111th",
+                "112th"
+            ]
+        );
+
+        let de = Locale::new(&langinfo, Language::de, WeightUnits::Kg);
+        assert_eq!(format_ordinal(&de, 1), "1.");
+        assert_eq!(format_ordinal(&de, 21), "21.");
+
+        let fr = Locale::new(&langinfo, Language::fr, WeightUnits::Kg);
+        assert_eq!(format_ordinal(&fr, 1), "1er");
+        assert_eq!(format_ordinal(&fr, 2), "2e");
+
+        let sv = Locale::new(&langinfo, Language::sv, WeightUnits::Kg);
+        assert_eq!(format_ordinal(&sv, 2), "2:a");
+        assert_eq!(format_ordinal(&sv, 3), "3:e");
+        assert_eq!(format_ordinal(&sv, 11), "11:e");
+
+        let zh = Locale::new(&langinfo, Language::zh_hans, WeightUnits::Kg);
+        assert_eq!(format_ordinal(&zh, 3), "第3名");
+
+        // Placings follow the same rules, agreeing with the lifter's Sex.
+        let es = Locale::new(&langinfo, Language::es, WeightUnits::Kg);
+        assert_eq!(format_ordinal(&es, 1), "1º");
+        let place = |locale: &Locale, sex| {
+            let place = Place::P(std::num::NonZeroU8::new(2).unwrap());
+            json::to_string(&locale.place(place, sex)).unwrap()
+        };
+        assert_eq!(place(&en, Sex::M), "\"2nd\"");
+        assert_eq!(place(&es, Sex::F), "\"2ª\"");
+
+        // Languages without ordinal notation keep serializing numbers.
+        let vi = Locale::new(&langinfo, Language::vi, WeightUnits::Kg);
+        assert_eq!(place(&vi, Sex::M), "2");
+    }
 }
@@ -30,6 +30,15 @@ pub enum Age {
     None,
 }
 
+/// What is known about a lifter's birth, for calculating their Age.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Birth {
+    /// The full BirthDate is known.
+    Date(Date),
+    /// Only the year of birth is known.
+    Year(u32),
+}
+
 /// An opaque wrapper for `Age` that serializes to "23~" instead of "23.5".
 #[derive(Copy, Clone, Debug)]
 pub struct PrettyAge(Age);
@@ -96,6 +105,37 @@ impl Age {
         }
     }
 
+    /// Calculates the age of a lifter at a meet from their birth.
+    ///
+    /// The result is exact if the full BirthDate is known,
+    /// and approximate if only the birth year is known.
+    ///
+    /// A February 29 birthday is reached on March 1 in common years.
+    ///
+    /// # Failures
+    ///
+    /// Fails if the lifter was not yet born by the meet date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::{Age, Birth, Date};
+    /// let meet = Date::from_parts(2019, 02, 16);
+    ///
+    /// let birth = Birth::Date(Date::from_parts(1988, 06, 01));
+    /// assert_eq!(Age::from_birthdate_and_meetdate(birth, meet), Ok(Age::Exact(30)));
+    ///
+    /// let birth = Birth::Year(1988);
+    /// assert_eq!(Age::from_birthdate_and_meetdate(birth, meet), Ok(Age::Approximate(30)));
+    /// ```
+    pub fn from_birthdate_and_meetdate(birth: Birth, meet: Date) -> Result<Self, &'static str> {
+        match birth {
+            Birth::Date(birthdate) => birthdate.age_on(meet),
+            Birth::Year(year) if meet.year() < year => Err("Lifter was not born yet"),
+            Birth::Year(year) => Ok(Age::from_birthyear_on_date(year, meet)),
+        }
+    }
+
     /// Converts to an `Option<u8>`.
     ///
     /// # Examples
@@ -369,6 +409,38 @@ mod tests {
         assert_eq!(format!("{}", a), "");
     }
 
+    #[test]
+    fn from_birthdate_and_meetdate() {
+        let age = |birth, meet| Age::from_birthdate_and_meetdate(birth, meet);
+        let birth = Birth::Date(Date::from_parts(1990, 6, 15));
+
+        // Birthday passed, on the birthday, and not yet reached.
+        assert_eq!(age(birth, Date::from_parts(2020, 7, 1)), Ok(Age::Exact(30)));
+        assert_eq!(
+            age(birth, Date::from_parts(2020, 6, 15)),
+            Ok(Age::Exact(30))
+        );
+        assert_eq!(
+            age(birth, Date::from_parts(2020, 6, 14)),
+            Ok(Age::Exact(29))
+        );
+
+        // Only the birth year is known.
+        let birth_year = Birth::Year(1990);
+        let meet = Date::from_parts(2020, 6, 14);
+        assert_eq!(age(birth_year, meet), Ok(Age::Approximate(29)));
+
+        // The meet must not be before the lifter was born.
+        assert!(age(birth, Date::from_parts(1990, 6, 14)).is_err());
+        assert!(age(birth_year, Date::from_parts(1989, 12, 31)).is_err());
+
+        // Leap day birthdays are reached on March 1 in common years.
+        let leap = Birth::Date(Date::from_parts(2000, 2, 29));
+        assert_eq!(age(leap, Date::from_parts(2019, 2, 28)), Ok(Age::Exact(18)));
+        assert_eq!(age(leap, Date::from_parts(2019, 3, 1)), Ok(Age::Exact(19)));
+        assert_eq!(age(leap, Date::from_parts(2020, 2, 29)), Ok(Age::Exact(20)));
+    }
+
     #[test]
     fn is_definitely_less_than() {
         let approx_17 = Age::Approximate(17); // "17 or 18"
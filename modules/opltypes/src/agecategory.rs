@@ -0,0 +1,115 @@
+//! Defines the standard IPF age divisions.
+
+use crate::Age;
+
+/// The standard IPF age division that an Age falls into.
+///
+/// Unlike a `BirthYearClass`, which only considers the year of birth,
+/// the AgeCategory is based off the lifter's age on the day of the meet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum AgeCategory {
+    /// Ages 14 through 18.
+    SubJunior,
+    /// Ages 19 through 23.
+    Junior,
+    /// Ages 24 through 39.
+    Open,
+    /// Ages 40 through 49.
+    Masters1,
+    /// Ages 50 through 59.
+    Masters2,
+    /// Ages 60 through 69.
+    Masters3,
+    /// Ages 70 and up.
+    Masters4,
+
+    /// An Approximate Age that straddles two categories.
+    Ambiguous,
+
+    /// No age specified, or too young for any category.
+    None,
+}
+
+impl AgeCategory {
+    /// Gets the category of an exact age.
+    fn from_exact(age: u8) -> AgeCategory {
+        match age {
+            0..=13 => AgeCategory::None,
+            14..=18 => AgeCategory::SubJunior,
+            19..=23 => AgeCategory::Junior,
+            24..=39 => AgeCategory::Open,
+            40..=49 => AgeCategory::Masters1,
+            50..=59 => AgeCategory::Masters2,
+            60..=69 => AgeCategory::Masters3,
+            70..=u8::MAX => AgeCategory::Masters4,
+        }
+    }
+}
+
+impl Age {
+    /// Classifies the Age into its standard IPF age division.
+    ///
+    /// An Approximate Age is only classified if both of its possible ages
+    /// fall in the same category, and otherwise is `AgeCategory::Ambiguous`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::{Age, AgeCategory};
+    /// assert_eq!(Age::Exact(23).category(), AgeCategory::Junior);
+    /// assert_eq!(Age::Approximate(30).category(), AgeCategory::Open);
+    /// assert_eq!(Age::Approximate(39).category(), AgeCategory::Ambiguous);
+    /// ```
+    pub fn category(self) -> AgeCategory {
+        match self {
+            Age::Exact(age) => AgeCategory::from_exact(age),
+            Age::Approximate(age) => {
+                let lower = AgeCategory::from_exact(age);
+                let upper = AgeCategory::from_exact(age.saturating_add(1));
+                if lower == upper {
+                    lower
+                } else {
+                    AgeCategory::Ambiguous
+                }
+            }
+            Age::None => AgeCategory::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_boundaries() {
+        let boundaries = [
+            (13, AgeCategory::None, 14, AgeCategory::SubJunior),
+            (18, AgeCategory::SubJunior, 19, AgeCategory::Junior),
+            (23, AgeCategory::Junior, 24, AgeCategory::Open),
+            (39, AgeCategory::Open, 40, AgeCategory::Masters1),
+            (49, AgeCategory::Masters1, 50, AgeCategory::Masters2),
+            (59, AgeCategory::Masters2, 60, AgeCategory::Masters3),
+            (69, AgeCategory::Masters3, 70, AgeCategory::Masters4),
+        ];
+
+        for &(below, below_category, above, above_category) in &boundaries {
+            assert_eq!(Age::Exact(below).category(), below_category);
+            assert_eq!(Age::Exact(above).category(), above_category);
+        }
+
+        assert_eq!(Age::Exact(u8::MAX).category(), AgeCategory::Masters4);
+        assert_eq!(Age::None.category(), AgeCategory::None);
+    }
+
+    #[test]
+    fn approximate_ranges() {
+        // "40 or 41" is within Masters 1.
+        assert_eq!(Age::Approximate(40).category(), AgeCategory::Masters1);
+
+        // "39 or 40" could be either Open or Masters 1.
+        assert_eq!(Age::Approximate(39).category(), AgeCategory::Ambiguous);
+        assert_eq!(Age::Approximate(18).category(), AgeCategory::Ambiguous);
+        assert_eq!(Age::Approximate(13).category(), AgeCategory::Ambiguous);
+    }
+}
@@ -3,7 +3,7 @@
 use crate::{Age, AgeRange};
 
 /// The AgeClass used by the server for partitioning into age categories.
-#[derive(Copy, Clone, Debug, Deserialize, EnumString, Serialize, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Deserialize, EnumString, Serialize, PartialEq)]
 pub enum AgeClass {
     #[serde(rename = "5-12")]
     #[strum(serialize = "5-12")]
@@ -53,6 +53,7 @@ pub enum AgeClass {
     #[serde(rename = "80-999")]
     #[strum(serialize = "80-999")]
     Class80_999,
+    #[default]
     #[serde(rename = "")]
     #[strum(serialize = "")]
     None,
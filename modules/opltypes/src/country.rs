@@ -1,7 +1,54 @@
 //! Defines the `Country` field for the `meets` table.
 
+use strum::IntoEnumIterator;
+
+use crate::{PointsSystem, WeightUnits};
+
+/// A continental regional body of the International Powerlifting Federation.
+#[derive(Copy, Clone, Debug, Deserialize, Display, Serialize, PartialEq, EnumString)]
+pub enum IpfRegion {
+    /// African Powerlifting Federation.
+    AfricanPF,
+    /// Asian Powerlifting Federation.
+    AsianPF,
+    /// European Powerlifting Federation.
+    EPF,
+    /// Federacion Sudamericana de Powerlifting.
+    FESUPO,
+    /// North American Powerlifting Federation, including the Caribbean.
+    NAPF,
+    /// Oceania Regional Powerlifting Federation.
+    ORPF,
+}
+
+/// A continent, for grouping countries geographically.
+#[derive(Copy, Clone, Debug, Deserialize, Display, Serialize, PartialEq, EnumIter, EnumString)]
+pub enum Continent {
+    Africa,
+    Asia,
+    Europe,
+    /// Including Central America and the Caribbean.
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
 /// The Country column.
-#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, EnumString, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    EnumIter,
+    EnumString,
+    ToString,
+)]
 pub enum Country {
     Abkhazia,
     Afghanistan,
@@ -286,7 +333,301 @@ pub enum Country {
     Zimbabwe,
 }
 
+/// Common alternative names for countries, used by `Country::from_name_relaxed`.
+///
+/// Historical names map to the country's present-day variant, unless the
+/// former country has its own variant, like the USSR.
+const COUNTRY_ALIASES: &[(&str, Country)] = &[
+    // The United Kingdom and its constituent countries.
+    ("Britain", Country::UK),
+    ("GB", Country::UK),
+    ("Great Britain", Country::UK),
+    ("United Kingdom", Country::UK),
+    ("Northern Ireland", Country::NorthernIreland),
+    // The United States.
+    ("America", Country::USA),
+    ("US", Country::USA),
+    ("U.S.A.", Country::USA),
+    ("United States", Country::USA),
+    ("United States of America", Country::USA),
+    // Full and alternative names.
+    ("Czech Republic", Country::Czechia),
+    ("Côte d'Ivoire", Country::IvoryCoast),
+    ("Holland", Country::Netherlands),
+    ("Korea", Country::SouthKorea),
+    ("Republic of Korea", Country::SouthKorea),
+    ("Russian Federation", Country::Russia),
+    ("Timor-Leste", Country::EastTimor),
+    ("United Arab Emirates", Country::UAE),
+    // Historical names.
+    ("Burma", Country::Myanmar),
+    ("Cape Verde", Country::CaboVerde),
+    ("Macedonia", Country::NorthMacedonia),
+    ("Soviet Union", Country::USSR),
+    ("Swaziland", Country::Eswatini),
+];
+
 impl Country {
+    /// The ISO 3166-1 alpha-2 and alpha-3 codes of the country.
+    ///
+    /// Tahiti uses the codes of French Polynesia, and Congo is the
+    /// Republic of the Congo.
+    fn iso_codes(self) -> Option<(&'static str, &'static str)> {
+        use Country::*;
+        match self {
+            Afghanistan => Some(("AF", "AFG")),
+            Albania => Some(("AL", "ALB")),
+            Algeria => Some(("DZ", "DZA")),
+            AmericanSamoa => Some(("AS", "ASM")),
+            Angola => Some(("AO", "AGO")),
+            Argentina => Some(("AR", "ARG")),
+            Armenia => Some(("AM", "ARM")),
+            Aruba => Some(("AW", "ABW")),
+            Australia => Some(("AU", "AUS")),
+            Azerbaijan => Some(("AZ", "AZE")),
+            Austria => Some(("AT", "AUT")),
+            Bahamas => Some(("BS", "BHS")),
+            Bahrain => Some(("BH", "BHR")),
+            Bangladesh => Some(("BD", "BGD")),
+            Belarus => Some(("BY", "BLR")),
+            Belgium => Some(("BE", "BEL")),
+            Belize => Some(("BZ", "BLZ")),
+            Benin => Some(("BJ", "BEN")),
+            Bolivia => Some(("BO", "BOL")),
+            BosniaAndHerzegovina => Some(("BA", "BIH")),
+            Botswana => Some(("BW", "BWA")),
+            Brazil => Some(("BR", "BRA")),
+            BritishVirginIslands => Some(("VG", "VGB")),
+            Brunei => Some(("BN", "BRN")),
+            Bulgaria => Some(("BG", "BGR")),
+            BurkinaFaso => Some(("BF", "BFA")),
+            CaboVerde => Some(("CV", "CPV")),
+            Cambodia => Some(("KH", "KHM")),
+            Cameroon => Some(("CM", "CMR")),
+            Canada => Some(("CA", "CAN")),
+            CaymanIslands => Some(("KY", "CYM")),
+            CentralAfricanRepublic => Some(("CF", "CAF")),
+            Chile => Some(("CL", "CHL")),
+            China => Some(("CN", "CHN")),
+            Colombia => Some(("CO", "COL")),
+            Comoros => Some(("KM", "COM")),
+            Congo => Some(("CG", "COG")),
+            CookIslands => Some(("CK", "COK")),
+            CostaRica => Some(("CR", "CRI")),
+            Croatia => Some(("HR", "HRV")),
+            Cuba => Some(("CU", "CUB")),
+            Cyprus => Some(("CY", "CYP")),
+            Czechia => Some(("CZ", "CZE")),
+            Denmark => Some(("DK", "DNK")),
+            Djibouti => Some(("DJ", "DJI")),
+            DominicanRepublic => Some(("DO", "DOM")),
+            EastTimor => Some(("TL", "TLS")),
+            Ecuador => Some(("EC", "ECU")),
+            Egypt => Some(("EG", "EGY")),
+            ElSalvador => Some(("SV", "SLV")),
+            Estonia => Some(("EE", "EST")),
+            Eswatini => Some(("SZ", "SWZ")),
+            Ethiopia => Some(("ET", "ETH")),
+            Fiji => Some(("FJ", "FJI")),
+            Finland => Some(("FI", "FIN")),
+            France => Some(("FR", "FRA")),
+            Gabon => Some(("GA", "GAB")),
+            Georgia => Some(("GE", "GEO")),
+            Germany => Some(("DE", "DEU")),
+            Ghana => Some(("GH", "GHA")),
+            Gibraltar => Some(("GI", "GIB")),
+            Greece => Some(("GR", "GRC")),
+            Guatemala => Some(("GT", "GTM")),
+            Guinea => Some(("GN", "GIN")),
+            GuineaBissau => Some(("GW", "GNB")),
+            Guyana => Some(("GY", "GUY")),
+            Haiti => Some(("HT", "HTI")),
+            Honduras => Some(("HN", "HND")),
+            HongKong => Some(("HK", "HKG")),
+            Hungary => Some(("HU", "HUN")),
+            Iceland => Some(("IS", "ISL")),
+            India => Some(("IN", "IND")),
+            Indonesia => Some(("ID", "IDN")),
+            Ireland => Some(("IE", "IRL")),
+            Israel => Some(("IL", "ISR")),
+            Italy => Some(("IT", "ITA")),
+            Iran => Some(("IR", "IRN")),
+            Iraq => Some(("IQ", "IRQ")),
+            IvoryCoast => Some(("CI", "CIV")),
+            Jamaica => Some(("JM", "JAM")),
+            Japan => Some(("JP", "JPN")),
+            Jordan => Some(("JO", "JOR")),
+            Kazakhstan => Some(("KZ", "KAZ")),
+            Kenya => Some(("KE", "KEN")),
+            Kiribati => Some(("KI", "KIR")),
+            Kuwait => Some(("KW", "KWT")),
+            Kyrgyzstan => Some(("KG", "KGZ")),
+            Laos => Some(("LA", "LAO")),
+            Latvia => Some(("LV", "LVA")),
+            Lebanon => Some(("LB", "LBN")),
+            Lesotho => Some(("LS", "LSO")),
+            Liberia => Some(("LR", "LBR")),
+            Libya => Some(("LY", "LBY")),
+            Lithuania => Some(("LT", "LTU")),
+            Luxembourg => Some(("LU", "LUX")),
+            Malaysia => Some(("MY", "MYS")),
+            Mali => Some(("ML", "MLI")),
+            Malta => Some(("MT", "MLT")),
+            MarshallIslands => Some(("MH", "MHL")),
+            Mauritania => Some(("MR", "MRT")),
+            Mauritius => Some(("MU", "MUS")),
+            Mexico => Some(("MX", "MEX")),
+            Moldova => Some(("MD", "MDA")),
+            Monaco => Some(("MC", "MCO")),
+            Mongolia => Some(("MN", "MNG")),
+            Montenegro => Some(("ME", "MNE")),
+            Morocco => Some(("MA", "MAR")),
+            Myanmar => Some(("MM", "MMR")),
+            Namibia => Some(("NA", "NAM")),
+            Nauru => Some(("NR", "NRU")),
+            Nepal => Some(("NP", "NPL")),
+            Netherlands => Some(("NL", "NLD")),
+            NewCaledonia => Some(("NC", "NCL")),
+            NewZealand => Some(("NZ", "NZL")),
+            Nicaragua => Some(("NI", "NIC")),
+            Niger => Some(("NE", "NER")),
+            Nigeria => Some(("NG", "NGA")),
+            Niue => Some(("NU", "NIU")),
+            Norway => Some(("NO", "NOR")),
+            NorthMacedonia => Some(("MK", "MKD")),
+            Oman => Some(("OM", "OMN")),
+            Pakistan => Some(("PK", "PAK")),
+            Palestine => Some(("PS", "PSE")),
+            Panama => Some(("PA", "PAN")),
+            PapuaNewGuinea => Some(("PG", "PNG")),
+            Paraguay => Some(("PY", "PRY")),
+            Peru => Some(("PE", "PER")),
+            Philippines => Some(("PH", "PHL")),
+            Poland => Some(("PL", "POL")),
+            Portugal => Some(("PT", "PRT")),
+            PuertoRico => Some(("PR", "PRI")),
+            Qatar => Some(("QA", "QAT")),
+            Romania => Some(("RO", "ROU")),
+            Russia => Some(("RU", "RUS")),
+            Rwanda => Some(("RW", "RWA")),
+            Samoa => Some(("WS", "WSM")),
+            SaudiArabia => Some(("SA", "SAU")),
+            Senegal => Some(("SN", "SEN")),
+            Serbia => Some(("RS", "SRB")),
+            SierraLeone => Some(("SL", "SLE")),
+            Singapore => Some(("SG", "SGP")),
+            Slovakia => Some(("SK", "SVK")),
+            Slovenia => Some(("SI", "SVN")),
+            SolomonIslands => Some(("SB", "SLB")),
+            SouthAfrica => Some(("ZA", "ZAF")),
+            SouthKorea => Some(("KR", "KOR")),
+            Spain => Some(("ES", "ESP")),
+            SriLanka => Some(("LK", "LKA")),
+            Sudan => Some(("SD", "SDN")),
+            Sweden => Some(("SE", "SWE")),
+            Syria => Some(("SY", "SYR")),
+            Switzerland => Some(("CH", "CHE")),
+            Tahiti => Some(("PF", "PYF")),
+            Taiwan => Some(("TW", "TWN")),
+            Tajikistan => Some(("TJ", "TJK")),
+            Tanzania => Some(("TZ", "TZA")),
+            Thailand => Some(("TH", "THA")),
+            TheGambia => Some(("GM", "GMB")),
+            Togo => Some(("TG", "TGO")),
+            Tonga => Some(("TO", "TON")),
+            TrinidadAndTobago => Some(("TT", "TTO")),
+            Tunisia => Some(("TN", "TUN")),
+            Turkey => Some(("TR", "TUR")),
+            Turkmenistan => Some(("TM", "TKM")),
+            Tuvalu => Some(("TV", "TUV")),
+            UAE => Some(("AE", "ARE")),
+            Uganda => Some(("UG", "UGA")),
+            UK => Some(("GB", "GBR")),
+            Ukraine => Some(("UA", "UKR")),
+            Uruguay => Some(("UY", "URY")),
+            USA => Some(("US", "USA")),
+            USVirginIslands => Some(("VI", "VIR")),
+            Uzbekistan => Some(("UZ", "UZB")),
+            Vanuatu => Some(("VU", "VUT")),
+            Venezuela => Some(("VE", "VEN")),
+            Vietnam => Some(("VN", "VNM")),
+            WallisAndFutuna => Some(("WF", "WLF")),
+            Yemen => Some(("YE", "YEM")),
+            Zambia => Some(("ZM", "ZMB")),
+            Zimbabwe => Some(("ZW", "ZWE")),
+
+            // Regions without their own ISO 3166-1 code, and countries that no
+            // longer exist.
+            Abkhazia | Czechoslovakia | EastGermany | England | NetherlandsAntilles
+            | NorthernIreland | Rhodesia | Scotland | SerbiaAndMontenegro | Transnistria | USSR
+            | Wales | WestGermany | Yugoslavia => None,
+        }
+    }
+
+    /// Looks up a country by its ISO 3166-1 alpha-2 code, like "US".
+    pub fn from_iso2(code: &str) -> Option<Country> {
+        Country::iter().find(|c| {
+            c.to_iso2()
+                .is_some_and(|iso| iso.eq_ignore_ascii_case(code))
+        })
+    }
+
+    /// Looks up a country by its ISO 3166-1 alpha-3 code, like "USA".
+    pub fn from_iso3(code: &str) -> Option<Country> {
+        Country::iter().find(|c| {
+            c.to_iso3()
+                .is_some_and(|iso| iso.eq_ignore_ascii_case(code))
+        })
+    }
+
+    /// Parses a country from its canonical name, a common alias,
+    /// or its ISO 3166-1 alpha-3 code.
+    ///
+    /// Aliases are compared without regard to ASCII case, and are listed in
+    /// `COUNTRY_ALIASES`. The strict `FromStr` should still be used for data
+    /// that is already canonical.
+    ///
+    /// Names for the whole of Britain, like "Great Britain", map to
+    /// `Country::UK`, never to one of its constituent countries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Country;
+    /// assert_eq!(Country::from_name_relaxed("United States"), Ok(Country::USA));
+    /// assert_eq!(Country::from_name_relaxed("Great Britain"), Ok(Country::UK));
+    /// assert!(Country::from_name_relaxed("Atlantis").is_err());
+    /// ```
+    pub fn from_name_relaxed(s: &str) -> Result<Country, strum::ParseError> {
+        let s = s.trim();
+        if let Ok(country) = s.parse::<Country>() {
+            return Ok(country);
+        }
+
+        COUNTRY_ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(s))
+            .map(|&(_, country)| country)
+            .or_else(|| Country::from_iso3(s))
+            .ok_or(strum::ParseError::VariantNotFound)
+    }
+
+    /// The ISO 3166-1 alpha-2 code of the country, like "US".
+    ///
+    /// Returns `None` for regions without their own code, like England,
+    /// and for countries that no longer exist, like the USSR.
+    pub fn to_iso2(self) -> Option<&'static str> {
+        self.iso_codes().map(|(iso2, _)| iso2)
+    }
+
+    /// The ISO 3166-1 alpha-3 code of the country, like "USA".
+    ///
+    /// Returns `None` for regions without their own code, like England,
+    /// and for countries that no longer exist, like the USSR.
+    pub fn to_iso3(self) -> Option<&'static str> {
+        self.iso_codes().map(|(_, iso3)| iso3)
+    }
+
     /// Whether this Country contains the other. Countries contain themselves.
     #[inline]
     pub fn contains(self, other: Country) -> bool {
@@ -309,4 +650,512 @@ impl Country {
                 | Country::Wales
         )
     }
+
+    /// The continent the country is in.
+    ///
+    /// Transcontinental countries are assigned to a single continent:
+    /// Russia, Turkey, and the USSR to Europe, following their sporting bodies;
+    /// the Caucasus states, Kazakhstan, and Egypt by their capitals.
+    pub fn continent(self) -> Continent {
+        use Country::*;
+        // No wildcard: a new Country must be classified before it compiles.
+        match self {
+            Algeria
+            | Angola
+            | Benin
+            | Botswana
+            | BurkinaFaso
+            | CaboVerde
+            | Cameroon
+            | CentralAfricanRepublic
+            | Comoros
+            | Congo
+            | Djibouti
+            | Egypt
+            | Eswatini
+            | Ethiopia
+            | Gabon
+            | Ghana
+            | Guinea
+            | GuineaBissau
+            | IvoryCoast
+            | Kenya
+            | Lesotho
+            | Liberia
+            | Libya
+            | Mali
+            | Mauritania
+            | Mauritius
+            | Morocco
+            | Namibia
+            | Niger
+            | Nigeria
+            | Rhodesia
+            | Rwanda
+            | Senegal
+            | SierraLeone
+            | SouthAfrica
+            | Sudan
+            | Tanzania
+            | TheGambia
+            | Togo
+            | Tunisia
+            | Uganda
+            | Zambia
+            | Zimbabwe => Continent::Africa,
+
+            Abkhazia | Afghanistan | Armenia | Azerbaijan | Bahrain | Bangladesh | Brunei
+            | Cambodia | China | EastTimor | Georgia | HongKong | India | Indonesia | Iran
+            | Iraq | Israel | Japan | Jordan | Kazakhstan | Kuwait | Kyrgyzstan | Laos
+            | Lebanon | Malaysia | Mongolia | Myanmar | Nepal | Oman | Pakistan | Palestine
+            | Philippines | Qatar | SaudiArabia | Singapore | SouthKorea | SriLanka | Syria
+            | Taiwan | Tajikistan | Thailand | Turkmenistan | UAE | Uzbekistan | Vietnam
+            | Yemen => Continent::Asia,
+
+            Albania | Austria | Belarus | Belgium | BosniaAndHerzegovina | Bulgaria | Croatia
+            | Cyprus | Czechia | Czechoslovakia | Denmark | EastGermany | England | Estonia
+            | Finland | France | Germany | Gibraltar | Greece | Hungary | Iceland | Ireland
+            | Italy | Latvia | Lithuania | Luxembourg | Malta | Moldova | Monaco | Montenegro
+            | Netherlands | NorthernIreland | NorthMacedonia | Norway | Poland | Portugal
+            | Romania | Russia | Scotland | Serbia | SerbiaAndMontenegro | Slovakia | Slovenia
+            | Spain | Sweden | Switzerland | Transnistria | Turkey | UK | Ukraine | USSR
+            | Wales | WestGermany | Yugoslavia => Continent::Europe,
+
+            Aruba | Bahamas | Belize | BritishVirginIslands | Canada | CaymanIslands
+            | CostaRica | Cuba | DominicanRepublic | ElSalvador | Guatemala | Haiti | Honduras
+            | Jamaica | Mexico | NetherlandsAntilles | Nicaragua | Panama | PuertoRico
+            | TrinidadAndTobago | USA | USVirginIslands => Continent::NorthAmerica,
+
+            AmericanSamoa | Australia | CookIslands | Fiji | Kiribati | MarshallIslands | Nauru
+            | NewCaledonia | NewZealand | Niue | PapuaNewGuinea | Samoa | SolomonIslands
+            | Tahiti | Tonga | Tuvalu | Vanuatu | WallisAndFutuna => Continent::Oceania,
+
+            Argentina | Bolivia | Brazil | Chile | Colombia | Ecuador | Guyana | Paraguay
+            | Peru | Uruguay | Venezuela => Continent::SouthAmerica,
+        }
+    }
+
+    /// The IPF regional body the country's IPF affiliate belongs to.
+    ///
+    /// Returns `None` for countries without an IPF affiliate,
+    /// including countries that no longer exist.
+    pub fn ipf_region(self) -> Option<IpfRegion> {
+        use Country::*;
+        match self {
+            Algeria | Botswana | Cameroon | Egypt | Ghana | IvoryCoast | Kenya | Libya
+            | Mauritius | Morocco | Namibia | Nigeria | SierraLeone | SouthAfrica | Tunisia
+            | Uganda | Zambia | Zimbabwe => Some(IpfRegion::AfricanPF),
+
+            Bahrain | Bangladesh | Brunei | China | HongKong | India | Indonesia | Iran | Iraq
+            | Japan | Jordan | Kazakhstan | Kuwait | Kyrgyzstan | Lebanon | Malaysia | Mongolia
+            | Nepal | Oman | Pakistan | Palestine | Philippines | Qatar | SaudiArabia
+            | Singapore | SouthKorea | SriLanka | Syria | Taiwan | Tajikistan | Thailand
+            | Turkmenistan | UAE | Uzbekistan | Vietnam => Some(IpfRegion::AsianPF),
+
+            Austria | Belarus | Belgium | BosniaAndHerzegovina | Bulgaria | Croatia | Cyprus
+            | Czechia | Denmark | England | Estonia | Finland | France | Georgia | Germany
+            | Greece | Hungary | Iceland | Ireland | Israel | Italy | Latvia | Lithuania
+            | Luxembourg | Malta | Moldova | Montenegro | Netherlands | NorthernIreland
+            | NorthMacedonia | Norway | Poland | Portugal | Romania | Russia | Scotland
+            | Serbia | Slovakia | Slovenia | Spain | Sweden | Switzerland | Turkey | UK
+            | Ukraine | Wales => Some(IpfRegion::EPF),
+
+            Argentina | Bolivia | Brazil | Chile | Colombia | Ecuador | Paraguay | Peru
+            | Uruguay | Venezuela => Some(IpfRegion::FESUPO),
+
+            Aruba | Bahamas | Belize | BritishVirginIslands | Canada | CaymanIslands
+            | CostaRica | DominicanRepublic | ElSalvador | Guatemala | Guyana | Honduras
+            | Jamaica | Mexico | Nicaragua | Panama | PuertoRico | TrinidadAndTobago | USA
+            | USVirginIslands => Some(IpfRegion::NAPF),
+
+            AmericanSamoa | Australia | CookIslands | Fiji | NewCaledonia | NewZealand | Niue
+            | PapuaNewGuinea | Samoa | Tahiti | Tonga => Some(IpfRegion::ORPF),
+
+            Abkhazia
+            | Afghanistan
+            | Albania
+            | Angola
+            | Armenia
+            | Azerbaijan
+            | Benin
+            | BurkinaFaso
+            | CaboVerde
+            | Cambodia
+            | CentralAfricanRepublic
+            | Comoros
+            | Congo
+            | Cuba
+            | Czechoslovakia
+            | Djibouti
+            | EastGermany
+            | EastTimor
+            | Eswatini
+            | Ethiopia
+            | Gabon
+            | Gibraltar
+            | Guinea
+            | GuineaBissau
+            | Haiti
+            | Kiribati
+            | Laos
+            | Lesotho
+            | Liberia
+            | Mali
+            | MarshallIslands
+            | Mauritania
+            | Monaco
+            | Myanmar
+            | Nauru
+            | NetherlandsAntilles
+            | Niger
+            | Rhodesia
+            | Rwanda
+            | Senegal
+            | SerbiaAndMontenegro
+            | SolomonIslands
+            | Sudan
+            | Tanzania
+            | TheGambia
+            | Togo
+            | Transnistria
+            | Tuvalu
+            | USSR
+            | Vanuatu
+            | WallisAndFutuna
+            | WestGermany
+            | Yemen
+            | Yugoslavia => None,
+        }
+    }
+
+    /// The weight units most familiar in the country.
+    pub fn default_units(self) -> WeightUnits {
+        use Country::*;
+        // No wildcard: a new Country must choose before it compiles.
+        match self {
+            Liberia | Myanmar | USA => WeightUnits::Lbs,
+            Abkhazia
+            | Afghanistan
+            | Albania
+            | Algeria
+            | AmericanSamoa
+            | Angola
+            | Argentina
+            | Armenia
+            | Aruba
+            | Australia
+            | Azerbaijan
+            | Austria
+            | Bahamas
+            | Bahrain
+            | Bangladesh
+            | Belarus
+            | Belgium
+            | Belize
+            | Benin
+            | Bolivia
+            | BosniaAndHerzegovina
+            | Botswana
+            | Brazil
+            | BritishVirginIslands
+            | Brunei
+            | Bulgaria
+            | BurkinaFaso
+            | CaboVerde
+            | Cambodia
+            | Cameroon
+            | Canada
+            | CaymanIslands
+            | CentralAfricanRepublic
+            | Chile
+            | China
+            | Colombia
+            | Comoros
+            | Congo
+            | CookIslands
+            | CostaRica
+            | Croatia
+            | Cuba
+            | Cyprus
+            | Czechia
+            | Czechoslovakia
+            | Denmark
+            | Djibouti
+            | DominicanRepublic
+            | EastGermany
+            | EastTimor
+            | Ecuador
+            | Egypt
+            | ElSalvador
+            | England
+            | Estonia
+            | Eswatini
+            | Ethiopia
+            | Fiji
+            | Finland
+            | France
+            | Gabon
+            | Georgia
+            | Germany
+            | Ghana
+            | Gibraltar
+            | Greece
+            | Guatemala
+            | Guinea
+            | GuineaBissau
+            | Guyana
+            | Haiti
+            | Honduras
+            | HongKong
+            | Hungary
+            | Iceland
+            | India
+            | Indonesia
+            | Ireland
+            | Israel
+            | Italy
+            | Iran
+            | Iraq
+            | IvoryCoast
+            | Jamaica
+            | Japan
+            | Jordan
+            | Kazakhstan
+            | Kenya
+            | Kiribati
+            | Kuwait
+            | Kyrgyzstan
+            | Laos
+            | Latvia
+            | Lebanon
+            | Lesotho
+            | Libya
+            | Lithuania
+            | Luxembourg
+            | Malaysia
+            | Mali
+            | Malta
+            | MarshallIslands
+            | Mauritania
+            | Mauritius
+            | Mexico
+            | Moldova
+            | Monaco
+            | Mongolia
+            | Montenegro
+            | Morocco
+            | Namibia
+            | Nauru
+            | Nepal
+            | Netherlands
+            | NetherlandsAntilles
+            | NewCaledonia
+            | NewZealand
+            | Nicaragua
+            | Niger
+            | Nigeria
+            | Niue
+            | Norway
+            | NorthernIreland
+            | NorthMacedonia
+            | Oman
+            | Pakistan
+            | Palestine
+            | Panama
+            | PapuaNewGuinea
+            | Paraguay
+            | Peru
+            | Philippines
+            | Poland
+            | Portugal
+            | PuertoRico
+            | Qatar
+            | Rhodesia
+            | Romania
+            | Russia
+            | Rwanda
+            | Samoa
+            | SaudiArabia
+            | Scotland
+            | Senegal
+            | Serbia
+            | SerbiaAndMontenegro
+            | SierraLeone
+            | Singapore
+            | Slovakia
+            | Slovenia
+            | SolomonIslands
+            | SouthAfrica
+            | SouthKorea
+            | Spain
+            | SriLanka
+            | Sudan
+            | Sweden
+            | Syria
+            | Switzerland
+            | Tahiti
+            | Taiwan
+            | Tajikistan
+            | Tanzania
+            | Thailand
+            | TheGambia
+            | Togo
+            | Tonga
+            | Transnistria
+            | TrinidadAndTobago
+            | Tunisia
+            | Turkey
+            | Turkmenistan
+            | Tuvalu
+            | UAE
+            | Uganda
+            | UK
+            | Ukraine
+            | Uruguay
+            | USSR
+            | USVirginIslands
+            | Uzbekistan
+            | Vanuatu
+            | Venezuela
+            | Vietnam
+            | Wales
+            | WallisAndFutuna
+            | WestGermany
+            | Yemen
+            | Yugoslavia
+            | Zambia
+            | Zimbabwe => WeightUnits::Kg,
+        }
+    }
+
+    /// The points system most familiar in the country.
+    ///
+    /// Countries with an IPF affiliate default to IPF GL Points,
+    /// and all others default to Dots.
+    pub fn default_points_system(self) -> PointsSystem {
+        match self.ipf_region() {
+            Some(_) => PointsSystem::Goodlift,
+            None => PointsSystem::Dots,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso_codes() {
+        assert_eq!(Country::USA.to_iso2(), Some("US"));
+        assert_eq!(Country::USA.to_iso3(), Some("USA"));
+        assert_eq!(Country::from_iso2("US"), Some(Country::USA));
+        assert_eq!(Country::from_iso3("USA"), Some(Country::USA));
+
+        assert_eq!(Country::Germany.to_iso2(), Some("DE"));
+        assert_eq!(Country::Germany.to_iso3(), Some("DEU"));
+        assert_eq!(Country::from_iso2("de"), Some(Country::Germany));
+        assert_eq!(Country::from_iso3("DEU"), Some(Country::Germany));
+
+        // Pseudo-countries and unknown codes.
+        assert_eq!(Country::England.to_iso2(), None);
+        assert_eq!(Country::USSR.to_iso3(), None);
+        assert_eq!(Country::from_iso2("XX"), None);
+
+        // Every code round-trips.
+        for country in Country::iter() {
+            if let Some(iso2) = country.to_iso2() {
+                assert_eq!(Country::from_iso2(iso2), Some(country));
+                assert_eq!(
+                    Country::from_iso3(country.to_iso3().unwrap()),
+                    Some(country)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_name_relaxed() {
+        assert_eq!(
+            Country::from_name_relaxed("United States"),
+            Ok(Country::USA)
+        );
+        assert_eq!(
+            Country::from_name_relaxed("united states"),
+            Ok(Country::USA)
+        );
+        assert_eq!(Country::from_name_relaxed("USA"), Ok(Country::USA));
+        assert_eq!(
+            Country::from_name_relaxed("Soviet Union"),
+            Ok(Country::USSR)
+        );
+
+        // Britain as a whole is the UK, not England.
+        assert_eq!(Country::from_name_relaxed("UK"), Ok(Country::UK));
+        assert_eq!(Country::from_name_relaxed("Great Britain"), Ok(Country::UK));
+        assert_eq!(Country::from_name_relaxed("England"), Ok(Country::England));
+        assert_eq!(
+            Country::from_name_relaxed("Northern Ireland"),
+            Ok(Country::NorthernIreland)
+        );
+
+        // ISO codes are accepted as a fallback.
+        assert_eq!(Country::from_name_relaxed("DEU"), Ok(Country::Germany));
+
+        assert!(Country::from_name_relaxed("Atlantis").is_err());
+        assert!(Country::from_name_relaxed("").is_err());
+
+        // The strict parser is unchanged.
+        assert!("United States".parse::<Country>().is_err());
+
+        // Every alias names a country under a non-canonical spelling.
+        for (alias, _) in COUNTRY_ALIASES {
+            assert!(alias.parse::<Country>().is_err(), "{}", alias);
+        }
+    }
+
+    #[test]
+    fn test_continent() {
+        assert_eq!(Country::USA.continent(), Continent::NorthAmerica);
+        assert_eq!(Country::Jamaica.continent(), Continent::NorthAmerica);
+        assert_eq!(Country::Brazil.continent(), Continent::SouthAmerica);
+        assert_eq!(Country::Wales.continent(), Continent::Europe);
+        assert_eq!(Country::Japan.continent(), Continent::Asia);
+        assert_eq!(Country::Kenya.continent(), Continent::Africa);
+        assert_eq!(Country::NewZealand.continent(), Continent::Oceania);
+
+        // Transcontinental countries.
+        assert_eq!(Country::Russia.continent(), Continent::Europe);
+        assert_eq!(Country::Kazakhstan.continent(), Continent::Asia);
+        assert_eq!(Country::Egypt.continent(), Continent::Africa);
+    }
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(Country::iter().count(), 194);
+        assert_eq!(Country::iter().next(), Some(Country::Abkhazia));
+    }
+
+    #[test]
+    fn test_ipf_region() {
+        assert_eq!(Country::Sweden.ipf_region(), Some(IpfRegion::EPF));
+        assert_eq!(Country::Wales.ipf_region(), Some(IpfRegion::EPF));
+        assert_eq!(Country::USA.ipf_region(), Some(IpfRegion::NAPF));
+
+        // Countries without an IPF affiliate.
+        assert_eq!(Country::Abkhazia.ipf_region(), None);
+        assert_eq!(Country::USSR.ipf_region(), None);
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(Country::USA.default_units(), WeightUnits::Lbs);
+        assert_eq!(Country::Germany.default_units(), WeightUnits::Kg);
+
+        assert_eq!(Country::USA.default_points_system(), PointsSystem::Goodlift);
+        assert_eq!(
+            Country::Germany.default_points_system(),
+            PointsSystem::Goodlift
+        );
+        assert_eq!(Country::USSR.default_points_system(), PointsSystem::Dots);
+    }
 }
@@ -254,6 +254,82 @@ impl Date {
 
         whole_previous_year_days + leap_days + this_year_days
     }
+
+    /// Counts the days from this Date until another, accounting for leap years.
+    ///
+    /// This is the negation of [Date::diff_days].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Date;
+    /// let before = "2020-02-27".parse::<Date>().unwrap();
+    /// let after = "2020-03-01".parse::<Date>().unwrap();
+    /// assert_eq!(before.days_between(after), 3);
+    /// assert_eq!(after.days_between(before), -3);
+    /// ```
+    pub fn days_between(self, other: Date) -> i64 {
+        other.diff_days(self)
+    }
+
+    /// Counts the days from another Date until this one, accounting for leap years.
+    ///
+    /// The sign matches `self - other`: the result is negative if the other
+    /// Date is later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Date;
+    /// let before = "2020-02-27".parse::<Date>().unwrap();
+    /// let after = "2020-03-01".parse::<Date>().unwrap();
+    /// assert_eq!(after.diff_days(before), 3);
+    /// assert_eq!(before.diff_days(after), -3);
+    /// ```
+    pub fn diff_days(self, other: Date) -> i64 {
+        i64::from(self.count_days()) - i64::from(other.count_days())
+    }
+
+    /// Gets the Date a number of days later, or earlier if negative.
+    ///
+    /// Uses the proleptic Gregorian calendar, so leap days are counted.
+    /// Results before `0001-01-01` are clamped to that day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Date;
+    /// let date = "2020-02-28".parse::<Date>().unwrap();
+    /// assert_eq!(date.add_days(1), Date::from_parts(2020, 02, 29));
+    /// assert_eq!(date.add_days(2), Date::from_parts(2020, 03, 01));
+    /// ```
+    pub fn add_days(self, n: i64) -> Date {
+        Date::from_count_days((i64::from(self.count_days()) + n).max(1))
+    }
+
+    /// Creates a Date from its [Date::count_days].
+    fn from_count_days(count: i64) -> Date {
+        // Count from 0000-03-01, so that leap days fall at the end of a year.
+        // Each 400-year era then has the same number of days.
+        let days = count + 305;
+        let era = days / 146_097;
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+
+        // Months are counted from March.
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = era * 400 + year_of_era + i64::from(month <= 2);
+
+        Date::from_parts(year as u32, month as u32, day as u32)
+    }
 }
 
 impl fmt::Display for Date {
@@ -343,6 +419,77 @@ impl<'de> Deserialize<'de> for Date {
     }
 }
 
+impl Date {
+    /// Parses a date in any of several common formats.
+    ///
+    /// Accepts "YYYY-MM-DD" and "DD-MM-YYYY", separated by any one of
+    /// '-', '/', or '.', with optional zero-padding of the month and day.
+    /// Meant for importing source spreadsheets: the checker's canonical
+    /// format is "YYYY-MM-DD", parsed by `FromStr`.
+    pub fn from_str_lenient(s: &str) -> Result<Date, ParseDateError> {
+        let s = s.trim();
+        let sep = s
+            .chars()
+            .find(|c| matches!(c, '-' | '/' | '.'))
+            .ok_or(ParseDateError::FormatError)?;
+
+        let v: Vec<&str> = s.split(sep).collect();
+        if v.len() != 3 {
+            return Err(ParseDateError::FormatError);
+        }
+
+        let (year, month, day) = match (v[0].len(), v[2].len()) {
+            (4, 1..=2) => (v[0], v[1], v[2]),
+            (1..=2, 4) => (v[2], v[1], v[0]),
+            _ => return Err(ParseDateError::FormatError),
+        };
+        if month.is_empty() || month.len() > 2 {
+            return Err(ParseDateError::FormatError);
+        }
+
+        format!("{}-{:0>2}-{:0>2}", year, month, day).parse::<Date>()
+    }
+}
+
+/// A `Date` that deserializes from any format accepted by
+/// `Date::from_str_lenient`, but always serializes as "YYYY-MM-DD".
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LenientDate(pub Date);
+
+impl From<LenientDate> for Date {
+    fn from(lenient: LenientDate) -> Date {
+        lenient.0
+    }
+}
+
+impl Serialize for LenientDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+struct LenientDateVisitor;
+
+impl<'de> Visitor<'de> for LenientDateVisitor {
+    type Value = LenientDate;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string in the format YYYY-MM-DD or DD-MM-YYYY")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<LenientDate, E> {
+        Date::from_str_lenient(value)
+            .map(LenientDate)
+            .map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for LenientDate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<LenientDate, D::Error> {
+        deserializer.deserialize_str(LenientDateVisitor)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -474,4 +621,115 @@ mod test {
         assert_eq!(on_leap_day - before_leap_day, 1);
         assert_eq!(after_leap_day - before_leap_day, 2);
     }
+
+    #[test]
+    fn days_between() {
+        let date = Date::from_parts(2019, 4, 6);
+        assert_eq!(date.days_between(date), 0);
+
+        // Across a leap day.
+        let before = Date::from_parts(2019, 12, 31);
+        let after = Date::from_parts(2020, 12, 31);
+        assert_eq!(before.days_between(after), 366);
+        assert_eq!(after.days_between(before), -366);
+
+        // Across a non-leap century.
+        let before = Date::from_parts(1900, 2, 28);
+        let after = Date::from_parts(1900, 3, 1);
+        assert_eq!(before.days_between(after), 1);
+    }
+
+    #[test]
+    fn add_days() {
+        // Across a leap day.
+        let date = Date::from_parts(2020, 2, 28);
+        assert_eq!(date.add_days(1), Date::from_parts(2020, 2, 29));
+        assert_eq!(date.add_days(2), Date::from_parts(2020, 3, 1));
+        assert_eq!(
+            Date::from_parts(2019, 2, 28).add_days(1),
+            Date::from_parts(2019, 3, 1)
+        );
+        assert_eq!(
+            Date::from_parts(1900, 2, 28).add_days(1),
+            Date::from_parts(1900, 3, 1)
+        );
+        assert_eq!(
+            Date::from_parts(2000, 2, 28).add_days(1),
+            Date::from_parts(2000, 2, 29)
+        );
+
+        // Across a year boundary, in both directions.
+        let date = Date::from_parts(2019, 12, 31);
+        assert_eq!(date.add_days(1), Date::from_parts(2020, 1, 1));
+        assert_eq!(date.add_days(366), Date::from_parts(2020, 12, 31));
+        assert_eq!(Date::from_parts(2020, 1, 1).add_days(-1), date);
+        assert_eq!(date.add_days(0), date);
+
+        // The earliest Date is the limit.
+        let first = Date::from_parts(1, 1, 1);
+        assert_eq!(first.add_days(-1), first);
+    }
+
+    #[test]
+    fn diff_days() {
+        let before = Date::from_parts(2019, 12, 31);
+        let after = Date::from_parts(2020, 3, 1);
+        assert_eq!(after.diff_days(before), 61);
+        assert_eq!(before.diff_days(after), -61);
+        assert_eq!(before.diff_days(before), 0);
+
+        // Across a leap day.
+        let before = Date::from_parts(2019, 12, 31);
+        let after = Date::from_parts(2020, 12, 31);
+        assert_eq!(after.diff_days(before), 366);
+        assert_eq!(i64::from(after - before), after.diff_days(before));
+
+        // Across a non-leap century.
+        let before = Date::from_parts(1900, 2, 28);
+        let after = Date::from_parts(1900, 3, 1);
+        assert_eq!(after.diff_days(before), 1);
+
+        // Adding days is the inverse of diffing them.
+        let date = Date::from_parts(2020, 2, 29);
+        for n in -1500..1500 {
+            assert_eq!(date.add_days(n).diff_days(date), n);
+            assert!(date.add_days(n).is_valid());
+        }
+    }
+
+    #[test]
+    fn lenient_formats() {
+        let expected = Date::from_parts(2016, 08, 19);
+        let inputs = [
+            "2016-08-19",
+            "2016/08/19",
+            "2016.08.19",
+            "19-08-2016",
+            "19/08/2016",
+            "19.08.2016",
+            "2016-8-19",
+            " 19/8/2016 ",
+        ];
+
+        for input in &inputs {
+            let json = format!("\"{}\"", input);
+            let date: LenientDate = serde_json::from_str(&json).unwrap();
+            assert_eq!(Date::from(date), expected, "parsing {}", input);
+            assert_eq!(serde_json::to_string(&date).unwrap(), "\"2016-08-19\"");
+        }
+
+        // The strict parser is unchanged.
+        assert!("2016/08/19".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn lenient_errors() {
+        assert!(Date::from_str_lenient("").is_err());
+        assert!(Date::from_str_lenient("20160819").is_err());
+        assert!(Date::from_str_lenient("2016/08-19").is_err());
+        assert!(Date::from_str_lenient("16/08/19").is_err());
+        assert!(Date::from_str_lenient("2016/123/19").is_err());
+        assert!(Date::from_str_lenient("2016/13/19").is_err());
+        assert!(Date::from_str_lenient("19//2016").is_err());
+    }
 }
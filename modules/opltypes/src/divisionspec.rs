@@ -0,0 +1,108 @@
+//! Defines the `DivisionSpec` type.
+
+use crate::{Age, Equipment, Sex};
+
+/// The eligibility requirements of a division.
+///
+/// Each restriction is optional: an unrestricted dimension accepts anything.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivisionSpec {
+    /// The inclusive minimum Age, or `Age::None` for no minimum.
+    pub min_age: Age,
+    /// The inclusive maximum Age, or `Age::None` for no maximum.
+    pub max_age: Age,
+    /// Optional restriction to a single Sex.
+    pub sex: Option<Sex>,
+    /// Optional restriction to certain Equipment.
+    pub equipment: Option<Vec<Equipment>>,
+}
+
+impl Default for DivisionSpec {
+    fn default() -> DivisionSpec {
+        DivisionSpec {
+            min_age: Age::None,
+            max_age: Age::None,
+            sex: None,
+            equipment: None,
+        }
+    }
+}
+
+impl DivisionSpec {
+    /// Whether the Age could fall within the division's age range.
+    ///
+    /// Unknown and Approximate ages are accepted unless they are definitely
+    /// out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::{Age, DivisionSpec};
+    /// let juniors = DivisionSpec {
+    ///     min_age: Age::Exact(19),
+    ///     max_age: Age::Exact(23),
+    ///     ..DivisionSpec::default()
+    /// };
+    /// assert!(juniors.accepts_age(Age::Exact(19)));
+    /// assert!(juniors.accepts_age(Age::Approximate(18)));
+    /// assert!(juniors.accepts_age(Age::None));
+    /// assert!(!juniors.accepts_age(Age::Exact(24)));
+    /// ```
+    pub fn accepts_age(&self, age: Age) -> bool {
+        !age.is_definitely_less_than(self.min_age) && !age.is_definitely_greater_than(self.max_age)
+    }
+
+    /// Whether the Sex is allowed in the division.
+    pub fn accepts_sex(&self, sex: Sex) -> bool {
+        self.sex.is_none_or(|s| s == sex)
+    }
+
+    /// Whether the Equipment is allowed in the division.
+    pub fn accepts_equipment(&self, equipment: Equipment) -> bool {
+        self.equipment
+            .as_ref()
+            .is_none_or(|list| list.contains(&equipment))
+    }
+
+    /// Whether a lifter is eligible for the division.
+    pub fn accepts(&self, age: Age, sex: Sex, equipment: Equipment) -> bool {
+        self.accepts_age(age) && self.accepts_sex(sex) && self.accepts_equipment(equipment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_open_women() -> DivisionSpec {
+        DivisionSpec {
+            min_age: Age::Exact(24),
+            max_age: Age::Exact(39),
+            sex: Some(Sex::F),
+            equipment: Some(vec![Equipment::Raw, Equipment::Wraps]),
+        }
+    }
+
+    #[test]
+    fn test_accepts() {
+        let spec = raw_open_women();
+        assert!(spec.accepts(Age::Exact(30), Sex::F, Equipment::Raw));
+        assert!(spec.accepts(Age::Exact(24), Sex::F, Equipment::Wraps));
+        assert!(spec.accepts(Age::None, Sex::F, Equipment::Raw));
+    }
+
+    #[test]
+    fn test_rejects_each_dimension() {
+        let spec = raw_open_women();
+        assert!(!spec.accepts(Age::Exact(23), Sex::F, Equipment::Raw));
+        assert!(!spec.accepts(Age::Exact(40), Sex::F, Equipment::Raw));
+        assert!(!spec.accepts(Age::Exact(30), Sex::M, Equipment::Raw));
+        assert!(!spec.accepts(Age::Exact(30), Sex::F, Equipment::Single));
+    }
+
+    #[test]
+    fn test_unrestricted() {
+        let spec = DivisionSpec::default();
+        assert!(spec.accepts(Age::Exact(8), Sex::Mx, Equipment::Multi));
+    }
+}
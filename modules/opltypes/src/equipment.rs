@@ -3,7 +3,9 @@
 use std::fmt;
 
 /// The Equipment field.
-#[derive(Clone, Copy, Debug, Deserialize, EnumString, PartialEq, Serialize, PartialOrd)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, EnumString, Eq, Hash, PartialEq, Serialize, PartialOrd,
+)]
 pub enum Equipment {
     /// No supportive material (sleeves allowed).
     Raw,
@@ -26,6 +28,11 @@ pub enum Equipment {
 
     /// Wrist straps for deadlifts.
     Straps,
+
+    /// Equipment was not reported, which is represented by a blank field.
+    #[serde(rename = "")]
+    #[strum(serialize = "")]
+    Unknown,
 }
 
 impl Default for Equipment {
@@ -34,6 +41,46 @@ impl Default for Equipment {
     }
 }
 
+/// The groups of Equipment that federations rank separately.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub enum EquipmentCategory {
+    /// No supportive suits: Raw, Wraps, and Straps.
+    Raw,
+    /// Supportive suits of any kind.
+    Equipped,
+    /// Equipment was not reported.
+    Unknown,
+}
+
+impl Equipment {
+    /// Gets the category of the Equipment.
+    ///
+    /// Raw and Wraps share a category, as in the "Raw+Wraps" rankings
+    /// and the coefficient formulas. Compare the Equipment directly to keep
+    /// them apart.
+    pub fn category(self) -> EquipmentCategory {
+        match self {
+            Equipment::Raw | Equipment::Wraps | Equipment::Straps => EquipmentCategory::Raw,
+            Equipment::Single | Equipment::Multi | Equipment::Unlimited => {
+                EquipmentCategory::Equipped
+            }
+            Equipment::Unknown => EquipmentCategory::Unknown,
+        }
+    }
+
+    /// Whether the Equipment is in the Raw category, which includes Wraps.
+    #[inline]
+    pub fn is_raw(self) -> bool {
+        self.category() == EquipmentCategory::Raw
+    }
+
+    /// Whether the Equipment includes a supportive suit.
+    #[inline]
+    pub fn is_equipped(self) -> bool {
+        self.category() == EquipmentCategory::Equipped
+    }
+}
+
 impl fmt::Display for Equipment {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -43,6 +90,39 @@ impl fmt::Display for Equipment {
             Equipment::Multi => write!(f, "Multi-ply"),
             Equipment::Unlimited => write!(f, "Unlimited"),
             Equipment::Straps => write!(f, "Straps"),
+            Equipment::Unknown => Ok(()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equipment_unknown() {
+        assert_eq!("".parse::<Equipment>().unwrap(), Equipment::Unknown);
+        assert_eq!(Equipment::Unknown.to_string(), "");
+        assert!("Tshirt".parse::<Equipment>().is_err());
+    }
+
+    #[test]
+    fn test_equipment_category() {
+        // Raw and Wraps are grouped together, but remain distinct Equipment.
+        assert_eq!(Equipment::Raw.category(), Equipment::Wraps.category());
+        assert_eq!(Equipment::Straps.category(), EquipmentCategory::Raw);
+        assert_ne!(Equipment::Raw, Equipment::Wraps);
+        assert!(Equipment::Wraps.is_raw());
+        assert!(!Equipment::Wraps.is_equipped());
+
+        assert_eq!(Equipment::Single.category(), EquipmentCategory::Equipped);
+        assert_eq!(Equipment::Multi.category(), EquipmentCategory::Equipped);
+        assert_eq!(Equipment::Unlimited.category(), EquipmentCategory::Equipped);
+        assert!(Equipment::Multi.is_equipped());
+        assert!(!Equipment::Single.is_raw());
+
+        assert_eq!(Equipment::Unknown.category(), EquipmentCategory::Unknown);
+        assert!(!Equipment::Unknown.is_raw());
+        assert!(!Equipment::Unknown.is_equipped());
+    }
+}
@@ -2698,6 +2698,25 @@ impl Federation {
         }
     }
 
+    /// Whether the federation is, or is sanctioned by, the given body.
+    ///
+    /// Follows the chain of sanctioning bodies, so that a federation
+    /// sanctioned by a regional body is also affiliated with its parent.
+    pub fn is_affiliated_with(self, body: Federation, date: Date) -> bool {
+        let mut federation = self;
+        // The chains are short, but bound the walk in case of a cycle.
+        for _ in 0..8 {
+            if federation == body {
+                return true;
+            }
+            match federation.sanctioning_body(date) {
+                Some(parent) if parent != federation => federation = parent,
+                _ => return false,
+            }
+        }
+        false
+    }
+
     /// Helper function for specifying the PointsSystem of federations under WP rules.
     #[inline]
     fn wp_rules_on(date: Date) -> PointsSystem {
@@ -3117,4 +3136,28 @@ mod tests {
         // The default to_string() should be the upper-case form.
         assert_eq!(Federation::WRPF.to_string(), "WRPF");
     }
+
+    #[test]
+    fn affiliations() {
+        let date = Date::from_parts(2020, 01, 01);
+
+        // National affiliates roll up to the IPF, directly or via a region.
+        assert_eq!(
+            Federation::AusPF.sanctioning_body(date),
+            Some(Federation::IPF)
+        );
+        assert!(Federation::AusPF.is_affiliated_with(Federation::IPF, date));
+        assert!(Federation::AsianPF.is_affiliated_with(Federation::IPF, date));
+        assert!(Federation::IPF.is_affiliated_with(Federation::IPF, date));
+        assert!(!Federation::AusPF.is_affiliated_with(Federation::WPC, date));
+
+        // Independent federations have no parent.
+        assert_eq!(Federation::_365Strong.sanctioning_body(date), None);
+        assert!(!Federation::_365Strong.is_affiliated_with(Federation::IPF, date));
+
+        // Affiliations can change over time.
+        let before = Date::from_parts(1990, 01, 01);
+        assert!(Federation::ADFPA.is_affiliated_with(Federation::IPF, date));
+        assert!(!Federation::ADFPA.is_affiliated_with(Federation::IPF, before));
+    }
 }
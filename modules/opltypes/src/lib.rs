@@ -19,7 +19,10 @@ extern crate serde_json;
 
 // Public exports.
 mod age;
-pub use self::age::{Age, PrettyAge};
+pub use self::age::{Age, Birth, PrettyAge};
+
+mod agecategory;
+pub use self::agecategory::AgeCategory;
 
 mod ageclass;
 pub use self::ageclass::AgeClass;
@@ -34,13 +37,16 @@ mod birthyearrange;
 pub use self::birthyearrange::BirthYearRange;
 
 mod country;
-pub use self::country::Country;
+pub use self::country::{Continent, Country, IpfRegion};
 
 mod date;
-pub use self::date::Date;
+pub use self::date::{Date, LenientDate};
+
+mod divisionspec;
+pub use self::divisionspec::DivisionSpec;
 
 mod equipment;
-pub use self::equipment::Equipment;
+pub use self::equipment::{Equipment, EquipmentCategory};
 
 mod event;
 pub use self::event::Event;
@@ -67,21 +73,27 @@ mod sex;
 pub use self::sex::Sex;
 
 pub mod states;
+pub use self::states::looks_swapped;
 
 mod username;
-pub use self::username::Username;
+pub use self::username::{parse_disambiguation, search_normalize, with_disambiguation, Username};
 
 mod weightkg;
-pub use self::weightkg::{WeightAny, WeightKg};
+pub use self::weightkg::{
+    Lift, WeightAny, WeightKg, MAX_PLAUSIBLE_BODYWEIGHT_KG, MIN_PLAUSIBLE_BODYWEIGHT_KG,
+    PLAUSIBLE_BENCH_KG, PLAUSIBLE_DEADLIFT_KG, PLAUSIBLE_SQUAT_KG,
+};
 
 mod weightclasskg;
-pub use self::weightclasskg::{WeightClassAny, WeightClassKg};
+pub use self::weightclasskg::{
+    ClassSet, WeightClassAny, WeightClassKg, IPF_2011_CLASSES_START, IPF_2019_CLASSES_START,
+};
 
 mod writing_system;
 pub use self::writing_system::{infer_writing_system, writing_system, WritingSystem};
 
 /// Units of weight.
-#[derive(Copy, Clone, Debug, EnumString, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, EnumString, Serialize, Deserialize)]
 pub enum WeightUnits {
     /// Kilograms.
     #[serde(rename = "kg")]
@@ -9,17 +9,22 @@ use std::fmt::{self, Write};
 use std::num;
 use std::str::FromStr;
 
+use crate::Sex;
+
 /// Represents numbers describing points, like Wilks and Glossbrenner.
 ///
 /// The database only tracks points to two decimal places.
 /// Instead of storing as `f32`, we can store as `u32 * 100`,
 /// allowing the use of normal registers for what are effectively
 /// floating-point operations, and removing all `dtoa()` calls.
+///
+/// Being integers, Points are totally ordered and safe to sort.
+/// Non-finite formula results, like NaN, are stored as zero.
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialOrd, PartialEq)]
 pub struct Points(i32);
 
 /// Enum of known powerlifting points systems, like Wilks and Glossbrenner.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, EnumIter, PartialEq)]
 pub enum PointsSystem {
     AH,
     Dots,
@@ -37,6 +42,37 @@ pub enum PointsSystem {
     Total,
 }
 
+impl PointsSystem {
+    /// The bodyweight range in kilograms over which the formula is applied,
+    /// as `(min, max)`.
+    ///
+    /// Bodyweights outside the range are clamped to it, since the formulas
+    /// extrapolate wildly past their fitted data. In particular, every
+    /// formula with a range uses a 40kg floor, including for women.
+    ///
+    /// Returns `None` for systems that do not clamp bodyweight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::{PointsSystem, Sex};
+    /// assert_eq!(PointsSystem::Dots.valid_bodyweight_range(Sex::F), Some((40.0, 150.0)));
+    /// assert_eq!(PointsSystem::Total.valid_bodyweight_range(Sex::F), None);
+    /// ```
+    pub fn valid_bodyweight_range(self, sex: Sex) -> Option<(f64, f64)> {
+        match (self, sex) {
+            (PointsSystem::Dots, Sex::M) | (PointsSystem::Dots, Sex::Mx) => Some((40.0, 210.0)),
+            (PointsSystem::Dots, Sex::F) => Some((40.0, 150.0)),
+            // Glossbrenner is piecewise linear at the top, so has no ceiling.
+            (PointsSystem::Glossbrenner, _) => Some((40.0, f64::INFINITY)),
+            // The upper bounds avoid asymptotes.
+            (PointsSystem::Wilks, Sex::M) | (PointsSystem::Wilks, Sex::Mx) => Some((40.0, 201.9)),
+            (PointsSystem::Wilks, Sex::F) => Some((40.0, 154.53)),
+            _ => None,
+        }
+    }
+}
+
 impl From<f32> for Points {
     fn from(f: f32) -> Points {
         if f.is_finite() {
@@ -47,6 +83,10 @@ impl From<f32> for Points {
     }
 }
 
+/// Rounds to the nearest hundredth, with half-way cases rounded away from zero.
+///
+/// This is the single rounding point for every points formula, so the server
+/// and the checker always agree on the stored value.
 impl From<f64> for Points {
     fn from(f: f64) -> Points {
         if f.is_finite() {
@@ -219,4 +259,33 @@ mod tests {
         assert!(w1 < w2);
         assert!(w1.lt(&w2));
     }
+
+    #[test]
+    fn from_f64() {
+        assert_eq!(Points::from(456.789_f64).to_string(), "456.79");
+        assert_eq!(Points::from(-456.789_f64).to_string(), "-456.79");
+        assert_eq!(Points::from(f64::NAN), Points::default());
+        assert_eq!(Points::from(f64::INFINITY), Points::default());
+    }
+
+    #[test]
+    fn sorting_with_non_finite_inputs() {
+        // Non-finite formula results become zero, so sorting never panics.
+        let mut points: Vec<Points> = [300.5, f64::NAN, 450.25, 300.5, f64::NEG_INFINITY]
+            .iter()
+            .map(|&f| Points::from(f))
+            .collect();
+        points.sort();
+
+        let sorted: Vec<String> = points.iter().map(|p| p.to_string()).collect();
+        assert_eq!(sorted, vec!["", "", "300.50", "300.50", "450.25"]);
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let points = Points::from(456.789_f64);
+        let json = serde_json::to_string(&points).unwrap();
+        assert_eq!(json, "\"456.79\"");
+        assert_eq!(serde_json::from_str::<Points>(&json).unwrap(), points);
+    }
 }
@@ -2,14 +2,46 @@
 
 use serde::de::{self, Deserialize, Visitor};
 use serde::ser::Serialize;
-use strum::ParseError;
+use strum::IntoEnumIterator;
 
 use std::fmt;
 
 use crate::Country;
 
+/// Why a State could not be parsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StateParseError {
+    /// The Country is not a known Country.
+    UnknownCountry,
+    /// The Country is known, but has no State data.
+    CountryHasNoStates(Country),
+    /// The State code is not a State of the Country.
+    InvalidStateCode { country: Country, code: String },
+}
+
+impl fmt::Display for StateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StateParseError::UnknownCountry => write!(f, "unknown country"),
+            StateParseError::CountryHasNoStates(country) => {
+                write!(f, "country '{}' has no states", country.to_string())
+            }
+            StateParseError::InvalidStateCode { country, code } => {
+                write!(
+                    f,
+                    "unknown state '{}' for country '{}'",
+                    code,
+                    country.to_string()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateParseError {}
+
 /// The State column.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum State {
     InArgentina(ArgentinaState),
     InAustralia(AustraliaState),
@@ -22,10 +54,15 @@ pub enum State {
     InMexico(MexicoState),
     InNetherlands(NetherlandsState),
     InNewZealand(NewZealandState),
+    InNorthernIreland(NorthernIrelandState),
+    InPoland(PolandState),
     InRomania(RomaniaState),
     InRussia(RussiaState),
+    InScotland(ScotlandState),
     InSouthAfrica(SouthAfricaState),
+    InUkraine(UkraineState),
     InUSA(USAState),
+    InWales(WalesState),
 }
 
 impl State {
@@ -41,25 +78,67 @@ impl State {
     /// let state = State::from_str_and_country("NY", Country::USA).unwrap();
     /// assert_eq!(state, State::InUSA(USAState::NY));
     /// ```
-    pub fn from_str_and_country(s: &str, country: Country) -> Result<State, ParseError> {
+    pub fn from_str_and_country(s: &str, country: Country) -> Result<State, StateParseError> {
         match country {
-            Country::Argentina => Ok(State::InArgentina(s.parse::<ArgentinaState>()?)),
-            Country::Australia => Ok(State::InAustralia(s.parse::<AustraliaState>()?)),
-            Country::Brazil => Ok(State::InBrazil(s.parse::<BrazilState>()?)),
-            Country::Canada => Ok(State::InCanada(s.parse::<CanadaState>()?)),
-            Country::China => Ok(State::InChina(s.parse::<ChinaState>()?)),
-            Country::England => Ok(State::InEngland(s.parse::<EnglandState>()?)),
-            Country::Germany => Ok(State::InGermany(s.parse::<GermanyState>()?)),
-            Country::India => Ok(State::InIndia(s.parse::<IndiaState>()?)),
-            Country::Mexico => Ok(State::InMexico(s.parse::<MexicoState>()?)),
-            Country::Netherlands => Ok(State::InNetherlands(s.parse::<NetherlandsState>()?)),
-            Country::NewZealand => Ok(State::InNewZealand(s.parse::<NewZealandState>()?)),
-            Country::Romania => Ok(State::InRomania(s.parse::<RomaniaState>()?)),
-            Country::Russia => Ok(State::InRussia(s.parse::<RussiaState>()?)),
-            Country::SouthAfrica => Ok(State::InSouthAfrica(s.parse::<SouthAfricaState>()?)),
-            Country::USA => Ok(State::InUSA(s.parse::<USAState>()?)),
-            _ => Err(ParseError::VariantNotFound),
+            Country::Argentina => s.parse::<ArgentinaState>().map(State::InArgentina),
+            Country::Australia => s.parse::<AustraliaState>().map(State::InAustralia),
+            Country::Brazil => s.parse::<BrazilState>().map(State::InBrazil),
+            Country::Canada => s.parse::<CanadaState>().map(State::InCanada),
+            Country::China => s.parse::<ChinaState>().map(State::InChina),
+            Country::England => s.parse::<EnglandState>().map(State::InEngland),
+            Country::Germany => s.parse::<GermanyState>().map(State::InGermany),
+            Country::India => s.parse::<IndiaState>().map(State::InIndia),
+            Country::Mexico => s.parse::<MexicoState>().map(State::InMexico),
+            Country::Netherlands => s.parse::<NetherlandsState>().map(State::InNetherlands),
+            Country::NewZealand => s.parse::<NewZealandState>().map(State::InNewZealand),
+            Country::NorthernIreland => s
+                .parse::<NorthernIrelandState>()
+                .map(State::InNorthernIreland),
+            Country::Poland => s.parse::<PolandState>().map(State::InPoland),
+            Country::Romania => s.parse::<RomaniaState>().map(State::InRomania),
+            Country::Russia => s.parse::<RussiaState>().map(State::InRussia),
+            Country::Scotland => s.parse::<ScotlandState>().map(State::InScotland),
+            Country::SouthAfrica => s.parse::<SouthAfricaState>().map(State::InSouthAfrica),
+            Country::Ukraine => s.parse::<UkraineState>().map(State::InUkraine),
+            Country::USA => s.parse::<USAState>().map(State::InUSA),
+            Country::Wales => s.parse::<WalesState>().map(State::InWales),
+            _ => return Err(StateParseError::CountryHasNoStates(country)),
+        }
+        .map_err(|_| StateParseError::InvalidStateCode {
+            country,
+            code: s.to_string(),
+        })
+    }
+
+    /// Constructs a State for a specific Country, tolerating common
+    /// data-entry mistakes.
+    ///
+    /// Surrounding whitespace and lowercase codes are accepted, as are full
+    /// State names like "California". The checker uses the strict
+    /// from_str_and_country() unless it chooses to recover.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Country;
+    /// # use opltypes::states::{State, USAState};
+    /// let state = State::parse_relaxed(" california ", Country::USA).unwrap();
+    /// assert_eq!(state, State::InUSA(USAState::CA));
+    /// ```
+    pub fn parse_relaxed(s: &str, country: Country) -> Result<State, StateParseError> {
+        let trimmed = s.trim();
+        match Self::from_str_and_country(&trimmed.to_uppercase(), country) {
+            Err(StateParseError::InvalidStateCode { .. }) => (),
+            result => return result,
         }
+
+        let name = trimmed.to_lowercase();
+        Self::iter_for_country(country)
+            .find(|state| state.to_state_name().to_lowercase() == name)
+            .ok_or_else(|| StateParseError::InvalidStateCode {
+                country,
+                code: trimmed.to_string(),
+            })
     }
 
     /// Constructs a State given a full, unambiguous code like "USA-NY".
@@ -75,15 +154,57 @@ impl State {
     /// let state = State::from_full_code("USA-NY").unwrap();
     /// assert_eq!(state, State::InUSA(USAState::NY));
     /// ```
-    pub fn from_full_code(s: &str) -> Result<State, ParseError> {
+    pub fn from_full_code(s: &str) -> Result<State, StateParseError> {
         // The codes are of the form "{Country}-{State}".
-        let parts: Vec<&str> = s.split('-').collect();
-        if parts.len() != 2 {
-            return Err(ParseError::VariantNotFound);
-        }
+        // Only the first hyphen separates the two, so the State code may contain more.
+        // A code without a hyphen has an empty State code.
+        let (country, code) = s.split_once('-').unwrap_or((s, ""));
 
-        let country: Country = parts[0].parse::<Country>()?;
-        Self::from_str_and_country(parts[1], country)
+        let country = country
+            .parse::<Country>()
+            .map_err(|_| StateParseError::UnknownCountry)?;
+        Self::from_str_and_country(code, country)
+    }
+
+    /// Iterates over every State of a Country.
+    ///
+    /// Countries without State data yield nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Country;
+    /// # use opltypes::states::State;
+    /// assert_eq!(State::iter_for_country(Country::Canada).count(), 13);
+    /// assert_eq!(State::iter_for_country(Country::Uzbekistan).count(), 0);
+    /// ```
+    pub fn iter_for_country(country: Country) -> impl Iterator<Item = State> {
+        let states: Vec<State> = match country {
+            Country::Argentina => ArgentinaState::iter().map(State::InArgentina).collect(),
+            Country::Australia => AustraliaState::iter().map(State::InAustralia).collect(),
+            Country::Brazil => BrazilState::iter().map(State::InBrazil).collect(),
+            Country::Canada => CanadaState::iter().map(State::InCanada).collect(),
+            Country::China => ChinaState::iter().map(State::InChina).collect(),
+            Country::England => EnglandState::iter().map(State::InEngland).collect(),
+            Country::Germany => GermanyState::iter().map(State::InGermany).collect(),
+            Country::India => IndiaState::iter().map(State::InIndia).collect(),
+            Country::Mexico => MexicoState::iter().map(State::InMexico).collect(),
+            Country::Netherlands => NetherlandsState::iter().map(State::InNetherlands).collect(),
+            Country::NewZealand => NewZealandState::iter().map(State::InNewZealand).collect(),
+            Country::NorthernIreland => NorthernIrelandState::iter()
+                .map(State::InNorthernIreland)
+                .collect(),
+            Country::Poland => PolandState::iter().map(State::InPoland).collect(),
+            Country::Romania => RomaniaState::iter().map(State::InRomania).collect(),
+            Country::Russia => RussiaState::iter().map(State::InRussia).collect(),
+            Country::Scotland => ScotlandState::iter().map(State::InScotland).collect(),
+            Country::SouthAfrica => SouthAfricaState::iter().map(State::InSouthAfrica).collect(),
+            Country::Ukraine => UkraineState::iter().map(State::InUkraine).collect(),
+            Country::USA => USAState::iter().map(State::InUSA).collect(),
+            Country::Wales => WalesState::iter().map(State::InWales).collect(),
+            _ => vec![],
+        };
+        states.into_iter()
     }
 
     /// Returns the Country for the given State.
@@ -109,10 +230,15 @@ impl State {
             State::InMexico(_) => Country::Mexico,
             State::InNetherlands(_) => Country::Netherlands,
             State::InNewZealand(_) => Country::NewZealand,
+            State::InNorthernIreland(_) => Country::NorthernIreland,
+            State::InPoland(_) => Country::Poland,
             State::InRomania(_) => Country::Romania,
             State::InRussia(_) => Country::Russia,
+            State::InScotland(_) => Country::Scotland,
             State::InSouthAfrica(_) => Country::SouthAfrica,
+            State::InUkraine(_) => Country::Ukraine,
             State::InUSA(_) => Country::USA,
+            State::InWales(_) => Country::Wales,
         }
     }
 
@@ -139,20 +265,259 @@ impl State {
             State::InMexico(s) => s.to_string(),
             State::InNetherlands(s) => s.to_string(),
             State::InNewZealand(s) => s.to_string(),
+            State::InNorthernIreland(s) => s.to_string(),
+            State::InPoland(s) => s.to_string(),
             State::InRomania(s) => s.to_string(),
             State::InRussia(s) => s.to_string(),
+            State::InScotland(s) => s.to_string(),
             State::InSouthAfrica(s) => s.to_string(),
+            State::InUkraine(s) => s.to_string(),
             State::InUSA(s) => s.to_string(),
+            State::InWales(s) => s.to_string(),
+        }
+    }
+
+    /// Returns the full English name of the State, like "New York".
+    ///
+    /// Russian oblasts are not yet named, and fall back to their code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::states::{State, USAState};
+    /// let state = State::from_full_code("USA-NY").unwrap();
+    /// assert_eq!(state.to_state_name(), "New York");
+    /// ```
+    pub fn to_state_name(self) -> &'static str {
+        match self {
+            State::InArgentina(s) => s.to_name(),
+            State::InAustralia(s) => s.to_name(),
+            State::InBrazil(s) => s.to_name(),
+            State::InCanada(s) => s.to_name(),
+            State::InChina(s) => s.to_name(),
+            State::InEngland(s) => s.to_name(),
+            State::InGermany(s) => s.to_name(),
+            State::InIndia(s) => s.to_name(),
+            State::InMexico(s) => s.to_name(),
+            State::InNetherlands(s) => s.to_name(),
+            State::InNewZealand(s) => s.to_name(),
+            State::InNorthernIreland(s) => s.to_name(),
+            State::InPoland(s) => s.to_name(),
+            State::InRomania(s) => s.to_name(),
+            State::InRussia(s) => s.to_name(),
+            State::InScotland(s) => s.to_name(),
+            State::InSouthAfrica(s) => s.to_name(),
+            State::InUkraine(s) => s.to_name(),
+            State::InUSA(s) => s.to_name(),
+            State::InWales(s) => s.to_name(),
+        }
+    }
+
+    /// Returns the ISO 3166-2 code for the State, like "US-NY".
+    ///
+    /// Returns `None` for States without an ISO 3166-2 equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::states::{MexicoState, State, USAState};
+    /// let state = State::InUSA(USAState::NY);
+    /// assert_eq!(state.to_iso_3166_2().unwrap(), "US-NY");
+    /// let state = State::InMexico(MexicoState::NL);
+    /// assert_eq!(state.to_iso_3166_2().unwrap(), "MX-NLE");
+    /// ```
+    pub fn to_iso_3166_2(self) -> Option<String> {
+        // BP regions and the Jervis Bay Territory have no ISO code.
+        if let State::InEngland(_) | State::InAustralia(AustraliaState::JBT) = self {
+            return None;
+        }
+
+        let country = self.to_country();
+        let country_code = iso_3166_1_code(country)?;
+
+        let state = self.to_state_string();
+        let subdivision = ISO_3166_2_ALIASES
+            .iter()
+            .find(|(c, ours, _)| *c == country && *ours == state)
+            .map_or(state.as_str(), |(_, _, iso)| iso);
+
+        Some(format!("{}-{}", country_code, subdivision))
+    }
+
+    /// Constructs a State from an ISO 3166-2 code, like "US-NY".
+    ///
+    /// Where ISO and our codes diverge, the ISO code is translated
+    /// through an alias table. Superseded ISO codes are also accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::states::{SouthAfricaState, State, USAState};
+    /// let state = State::from_iso_3166_2("US-NY").unwrap();
+    /// assert_eq!(state, State::InUSA(USAState::NY));
+    /// let state = State::from_iso_3166_2("ZA-KZN").unwrap();
+    /// assert_eq!(state, State::InSouthAfrica(SouthAfricaState::KZN));
+    /// let state = State::from_iso_3166_2("ZA-NL").unwrap();
+    /// assert_eq!(state, State::InSouthAfrica(SouthAfricaState::KZN));
+    /// assert!(State::from_iso_3166_2("USA-NY").is_none());
+    /// ```
+    pub fn from_iso_3166_2(s: &str) -> Option<State> {
+        let (country_code, subdivision) = s.split_once('-')?;
+
+        // Several Countries may share a code, but not a subdivision code.
+        let shared = ISO_3166_1_SHARED
+            .iter()
+            .filter(|(code, _)| *code == country_code)
+            .map(|(_, country)| *country);
+        Country::from_iso2(country_code)
+            .into_iter()
+            .chain(shared)
+            .find_map(|country| Self::from_iso_and_country(subdivision, country).ok())
+    }
+
+    /// Constructs a State for a specific Country from an ISO 3166-2
+    /// subdivision code, like "NY" for the USA.
+    ///
+    /// Unlike `from_str_and_country()`, this accepts ISO codes that diverge
+    /// from our own, and rejects our codes that are not ISO codes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Country;
+    /// # use opltypes::states::{SouthAfricaState, State};
+    /// let state = State::from_iso_and_country("NL", Country::SouthAfrica).unwrap();
+    /// assert_eq!(state, State::InSouthAfrica(SouthAfricaState::KZN));
+    /// ```
+    pub fn from_iso_and_country(s: &str, country: Country) -> Result<State, StateParseError> {
+        // Translate divergent ISO codes.
+        if let Some((_, ours, _)) = ISO_3166_2_ALIASES
+            .iter()
+            .find(|(c, _, iso)| *c == country && *iso == s)
+        {
+            return Self::from_str_and_country(ours, country);
+        }
+
+        // Our codes that diverge from ISO aren't ISO codes themselves.
+        if ISO_3166_2_ALIASES
+            .iter()
+            .any(|(c, ours, _)| *c == country && *ours == s)
+        {
+            return Err(StateParseError::InvalidStateCode {
+                country,
+                code: s.to_string(),
+            });
         }
+
+        Self::from_str_and_country(s, country)
+    }
+}
+
+/// Countries without their own ISO 3166-1 code, whose States are
+/// subdivisions of another country's code.
+///
+/// England is omitted: its States are BP regions, not ISO subdivisions.
+const ISO_3166_1_SHARED: [(&str, Country); 3] = [
+    ("GB", Country::NorthernIreland),
+    ("GB", Country::Scotland),
+    ("GB", Country::Wales),
+];
+
+/// Gets the ISO 3166-1 alpha-2 code that a Country's States are listed under.
+fn iso_3166_1_code(country: Country) -> Option<&'static str> {
+    country.to_iso2().or_else(|| {
+        ISO_3166_1_SHARED
+            .iter()
+            .find(|(_, c)| *c == country)
+            .map(|(code, _)| *code)
+    })
+}
+
+/// State codes that differ from their ISO 3166-2 subdivision codes,
+/// as `(Country, our code, ISO code)`.
+///
+/// Where several ISO codes map to one State, the current ISO code is first.
+#[rustfmt::skip]
+const ISO_3166_2_ALIASES: &[(Country, &str, &str)] = &[
+    (Country::Argentina, "SA", "A"), (Country::Argentina, "BA", "B"),
+    (Country::Argentina, "CA", "C"), (Country::Argentina, "SL", "D"),
+    (Country::Argentina, "ER", "E"), (Country::Argentina, "LR", "F"),
+    (Country::Argentina, "SE", "G"), (Country::Argentina, "CC", "H"),
+    (Country::Argentina, "SJ", "J"), (Country::Argentina, "CT", "K"),
+    (Country::Argentina, "LP", "L"), (Country::Argentina, "MZ", "M"),
+    (Country::Argentina, "MN", "N"), (Country::Argentina, "FM", "P"),
+    (Country::Argentina, "NQ", "Q"), (Country::Argentina, "RN", "R"),
+    (Country::Argentina, "SF", "S"), (Country::Argentina, "TM", "T"),
+    (Country::Argentina, "CH", "U"), (Country::Argentina, "TF", "V"),
+    (Country::Argentina, "CN", "W"), (Country::Argentina, "CB", "X"),
+    (Country::Argentina, "JY", "Y"), (Country::Argentina, "SC", "Z"),
+
+    (Country::China, "HEN", "HA"), (Country::China, "HUB", "HB"),
+    (Country::China, "HEB", "HE"), (Country::China, "HUN", "HN"),
+    (Country::China, "SAA", "SN"), (Country::China, "SAX", "SX"),
+
+    (Country::Germany, "NRW", "NW"),
+
+    // Dadra and Nagar Haveli merged with Daman and Diu in 2020.
+    (Country::India, "DH", "DH"), (Country::India, "DD", "DH"),
+    (Country::India, "OR", "OD"),
+
+    (Country::Mexico, "AG", "AGU"), (Country::Mexico, "BC", "BCN"),
+    (Country::Mexico, "BS", "BCS"), (Country::Mexico, "CM", "CAM"),
+    (Country::Mexico, "CS", "CHP"), (Country::Mexico, "CH", "CHH"),
+    (Country::Mexico, "CO", "COA"), (Country::Mexico, "CL", "COL"),
+    (Country::Mexico, "DF", "CMX"), (Country::Mexico, "DG", "DUR"),
+    (Country::Mexico, "GT", "GUA"), (Country::Mexico, "GR", "GRO"),
+    (Country::Mexico, "HG", "HID"), (Country::Mexico, "JA", "JAL"),
+    (Country::Mexico, "EM", "MEX"), (Country::Mexico, "MI", "MIC"),
+    (Country::Mexico, "MO", "MOR"), (Country::Mexico, "NA", "NAY"),
+    (Country::Mexico, "NL", "NLE"), (Country::Mexico, "OA", "OAX"),
+    (Country::Mexico, "PU", "PUE"), (Country::Mexico, "QT", "QUE"),
+    (Country::Mexico, "QR", "ROO"), (Country::Mexico, "SL", "SLP"),
+    (Country::Mexico, "SI", "SIN"), (Country::Mexico, "SO", "SON"),
+    (Country::Mexico, "TB", "TAB"), (Country::Mexico, "TM", "TAM"),
+    (Country::Mexico, "TL", "TLA"), (Country::Mexico, "VE", "VER"),
+    (Country::Mexico, "YU", "YUC"), (Country::Mexico, "ZA", "ZAC"),
+
+    (Country::NewZealand, "AKL", "AUK"),
+
+    (Country::SouthAfrica, "GT", "GP"), (Country::SouthAfrica, "GT", "GT"),
+    (Country::SouthAfrica, "KZN", "KZN"), (Country::SouthAfrica, "KZN", "NL"),
+
+    // Ukraine uses numeric ISO codes.
+    (Country::Ukraine, "CK", "71"), (Country::Ukraine, "CH", "74"),
+    (Country::Ukraine, "CV", "77"), (Country::Ukraine, "CR", "43"),
+    (Country::Ukraine, "DP", "12"), (Country::Ukraine, "DT", "14"),
+    (Country::Ukraine, "IF", "26"), (Country::Ukraine, "KH", "63"),
+    (Country::Ukraine, "KS", "65"), (Country::Ukraine, "KM", "68"),
+    (Country::Ukraine, "KR", "35"), (Country::Ukraine, "KO", "32"),
+    (Country::Ukraine, "KV", "30"), (Country::Ukraine, "LH", "09"),
+    (Country::Ukraine, "LV", "46"), (Country::Ukraine, "MY", "48"),
+    (Country::Ukraine, "OD", "51"), (Country::Ukraine, "PL", "53"),
+    (Country::Ukraine, "RV", "56"), (Country::Ukraine, "SM", "59"),
+    (Country::Ukraine, "TP", "61"), (Country::Ukraine, "VI", "05"),
+    (Country::Ukraine, "VO", "07"), (Country::Ukraine, "ZK", "21"),
+    (Country::Ukraine, "ZP", "23"), (Country::Ukraine, "ZT", "18"),
+
+    (Country::USA, "Guam", "GU"),
+];
+
+impl fmt::Display for State {
+    /// Formats the State as its full code, like "USA-NY".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}",
+            self.to_country().to_string(),
+            self.to_state_string()
+        )
     }
 }
 
 impl Serialize for State {
     /// Serialization for the server. The checker uses from_str_and_country().
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let country = self.to_country().to_string();
-        let state = self.to_state_string();
-        format!("{}-{}", country, state).serialize(serializer)
+        serializer.collect_str(self)
     }
 }
 
@@ -180,8 +545,42 @@ impl<'de> Deserialize<'de> for State {
     }
 }
 
+/// Whether the MeetCountry and MeetState columns appear to be swapped.
+///
+/// This is true when the country field parses as a state of the country
+/// named in the state field.
+///
+/// # Examples
+///
+/// ```
+/// # use opltypes::looks_swapped;
+/// assert!(looks_swapped("CA", "USA"));
+/// assert!(!looks_swapped("USA", "CA"));
+/// assert!(!looks_swapped("Canada", "USA"));
+/// assert!(!looks_swapped("", ""));
+/// ```
+pub fn looks_swapped(country_field: &str, state_field: &str) -> bool {
+    match state_field.parse::<Country>() {
+        Ok(country) => State::from_str_and_country(country_field, country).is_ok(),
+        Err(_) => false,
+    }
+}
+
 /// A state in Argentina.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum ArgentinaState {
     /// Ciudad Autónoma de Buenos Aires.
     CA,
@@ -233,8 +632,53 @@ pub enum ArgentinaState {
     TM,
 }
 
+impl ArgentinaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            ArgentinaState::CA => "Ciudad Autónoma de Buenos Aires",
+            ArgentinaState::BA => "Buenos Aires",
+            ArgentinaState::CT => "Catamarca",
+            ArgentinaState::CC => "Chaco",
+            ArgentinaState::CH => "Chubut",
+            ArgentinaState::CB => "Córdoba",
+            ArgentinaState::CN => "Corrientes",
+            ArgentinaState::ER => "Entre Ríos",
+            ArgentinaState::FM => "Formosa",
+            ArgentinaState::JY => "Jujuy",
+            ArgentinaState::LP => "La Pampa",
+            ArgentinaState::LR => "La Rioja",
+            ArgentinaState::MZ => "Mendoza",
+            ArgentinaState::MN => "Misiones",
+            ArgentinaState::NQ => "Neuquén",
+            ArgentinaState::RN => "Río Negro",
+            ArgentinaState::SA => "Salta",
+            ArgentinaState::SJ => "San Juan",
+            ArgentinaState::SL => "San Luis",
+            ArgentinaState::SC => "Santa Cruz",
+            ArgentinaState::SF => "Santa Fe",
+            ArgentinaState::SE => "Santiago del Estero",
+            ArgentinaState::TF => "Tierra del Fuego",
+            ArgentinaState::TM => "Tucumán",
+        }
+    }
+}
+
 /// A state in Australia.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum AustraliaState {
     /// Australian Capital Territory.
     ACT,
@@ -256,8 +700,38 @@ pub enum AustraliaState {
     WA,
 }
 
+impl AustraliaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            AustraliaState::ACT => "Australian Capital Territory",
+            AustraliaState::JBT => "Jervis Bay Territory",
+            AustraliaState::NSW => "New South Wales",
+            AustraliaState::NT => "Northern Territory",
+            AustraliaState::QLD => "Queensland",
+            AustraliaState::SA => "South Australia",
+            AustraliaState::TAS => "Tasmania",
+            AustraliaState::VIC => "Victoria",
+            AustraliaState::WA => "Western Australia",
+        }
+    }
+}
+
 /// A state in Brazil.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum BrazilState {
     /// Acre.
     AC,
@@ -315,16 +789,72 @@ pub enum BrazilState {
     TO,
 }
 
+impl BrazilState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            BrazilState::AC => "Acre",
+            BrazilState::AL => "Alagoas",
+            BrazilState::AP => "Amapá",
+            BrazilState::AM => "Amazonas",
+            BrazilState::BA => "Bahia",
+            BrazilState::CE => "Ceará",
+            BrazilState::DF => "Distrito Federal",
+            BrazilState::ES => "Espírito Santo",
+            BrazilState::GO => "Goiás",
+            BrazilState::MA => "Maranhão",
+            BrazilState::MT => "Mato Grosso",
+            BrazilState::MS => "Mato Grosso do Sul",
+            BrazilState::MG => "Minas Gerais",
+            BrazilState::PA => "Pará",
+            BrazilState::PB => "Paraíba",
+            BrazilState::PR => "Paraná",
+            BrazilState::PE => "Pernambuco",
+            BrazilState::PI => "Piauí",
+            BrazilState::RJ => "Rio de Janeiro",
+            BrazilState::RN => "Rio Grande do Norte",
+            BrazilState::RS => "Rio Grande do Sul",
+            BrazilState::RO => "Rondônia",
+            BrazilState::RR => "Roraima",
+            BrazilState::SC => "Santa Catarina",
+            BrazilState::SP => "São Paulo",
+            BrazilState::SE => "Sergipe",
+            BrazilState::TO => "Tocantins",
+        }
+    }
+}
+
 /// A state in Canada.
 #[rustfmt::skip]
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(Copy, Clone, Debug, EnumIter, EnumString, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToString)]
 pub enum CanadaState {
     AB, BC, MB, NB, NL, NT, NS, NU, ON, PE, QC, SK, YT
 }
 
+impl CanadaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            CanadaState::AB => "Alberta",
+            CanadaState::BC => "British Columbia",
+            CanadaState::MB => "Manitoba",
+            CanadaState::NB => "New Brunswick",
+            CanadaState::NL => "Newfoundland and Labrador",
+            CanadaState::NT => "Northwest Territories",
+            CanadaState::NS => "Nova Scotia",
+            CanadaState::NU => "Nunavut",
+            CanadaState::ON => "Ontario",
+            CanadaState::PE => "Prince Edward Island",
+            CanadaState::QC => "Quebec",
+            CanadaState::SK => "Saskatchewan",
+            CanadaState::YT => "Yukon",
+        }
+    }
+}
+
 /// A province in China.
 #[rustfmt::skip]
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(Copy, Clone, Debug, EnumIter, EnumString, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToString)]
 pub enum ChinaState {
     /// Anhui Province (安徽省, Ānhuī Shěng).
     AH,
@@ -396,11 +926,53 @@ pub enum ChinaState {
     ZJ,
 }
 
+impl ChinaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            ChinaState::AH => "Anhui Province",
+            ChinaState::BJ => "Beijing Municipality",
+            ChinaState::CQ => "Chongqing Municipality",
+            ChinaState::FJ => "Fujian Province",
+            ChinaState::GD => "Guangdong Province",
+            ChinaState::GS => "Gansu Province",
+            ChinaState::GX => "Guangxi Zhuang Autonomous Region",
+            ChinaState::GZ => "Guizhou Province",
+            ChinaState::HEN => "Henan Province",
+            ChinaState::HUB => "Hubei Province",
+            ChinaState::HEB => "Hebei Province",
+            ChinaState::HI => "Hainan Province",
+            ChinaState::HK => "Hong Kong Special Administrative Region",
+            ChinaState::HL => "Heilongjiang Province",
+            ChinaState::HUN => "Hunan Province",
+            ChinaState::JL => "Jilin Province",
+            ChinaState::JS => "Jiangsu Province",
+            ChinaState::JX => "Jiangxi Province",
+            ChinaState::LN => "Liaoning Province",
+            ChinaState::MO => "Macau Special Administrative Region",
+            ChinaState::NM => "Inner Mongolia Autonomous Region",
+            ChinaState::NX => "Ningxia Hui Autonomous Region",
+            ChinaState::QH => "Qinghai Province",
+            ChinaState::SC => "Sichuan Province",
+            ChinaState::SD => "Shandong Province",
+            ChinaState::SH => "Shanghai Municipality",
+            ChinaState::SAA => "Shaanxi Province",
+            ChinaState::SAX => "Shanxi Province",
+            ChinaState::TJ => "Tianjin Municipality",
+            ChinaState::XJ => "Xinjiang Uyghur Autonomous Region",
+            ChinaState::XZ => "Tibet Autonomous Region",
+            ChinaState::YN => "Yunnan Province",
+            ChinaState::ZJ => "Zhejiang Province",
+        }
+    }
+}
+
 /// A region in England, ill-defined and used only by BP.
 ///
-/// This omits other divisions not in England: Scotland, N.Ireland, and Wales.
+/// The other nations of the UK have their own Country and subdivisions:
+/// see `ScotlandState`, `WalesState`, and `NorthernIrelandState`.
 #[rustfmt::skip]
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(Copy, Clone, Debug, EnumIter, EnumString, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToString)]
 pub enum EnglandState {
     /// East Midlands.
     EM,
@@ -422,8 +994,38 @@ pub enum EnglandState {
     YNE,
 }
 
+impl EnglandState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            EnglandState::EM => "East Midlands",
+            EnglandState::GL => "Greater London",
+            EnglandState::NM => "North Midlands",
+            EnglandState::NW => "North West",
+            EnglandState::SE => "South East",
+            EnglandState::SM => "South Midlands",
+            EnglandState::SW => "South West",
+            EnglandState::WM => "West Midlands",
+            EnglandState::YNE => "Yorkshire North East",
+        }
+    }
+}
+
 /// A state in Germany.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum GermanyState {
     /// Baden-Württemberg.
     BW,
@@ -459,8 +1061,45 @@ pub enum GermanyState {
     TH,
 }
 
+impl GermanyState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            GermanyState::BW => "Baden-Württemberg",
+            GermanyState::BY => "Bavaria",
+            GermanyState::BE => "Berlin",
+            GermanyState::BB => "Brandenburg",
+            GermanyState::HB => "Bremen",
+            GermanyState::HE => "Hesse",
+            GermanyState::HH => "Hamburg",
+            GermanyState::MV => "Mecklenburg-Vorpommern",
+            GermanyState::NI => "Lower Saxony",
+            GermanyState::NRW => "North Rhine-Westphalia",
+            GermanyState::RP => "Rhineland-Palatinate",
+            GermanyState::SH => "Schleswig-Holstein",
+            GermanyState::SL => "Saarland",
+            GermanyState::SN => "Saxony",
+            GermanyState::ST => "Saxony-Anhalt",
+            GermanyState::TH => "Thuringia",
+        }
+    }
+}
+
 /// A state in India.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum IndiaState {
     /// Andaman and Nicobar Islands.
     AN,
@@ -534,8 +1173,64 @@ pub enum IndiaState {
     WB,
 }
 
+impl IndiaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            IndiaState::AN => "Andaman and Nicobar Islands",
+            IndiaState::AP => "Andhra Pradesh",
+            IndiaState::AR => "Arunachal Pradesh",
+            IndiaState::AS => "Assam",
+            IndiaState::BR => "Bihar",
+            IndiaState::CG => "Chhattisgarh",
+            IndiaState::CH => "Chandigarh",
+            IndiaState::DD => "Daman and Diu",
+            IndiaState::DH => "Dadra and Nagar Haveli",
+            IndiaState::DL => "Delhi",
+            IndiaState::GA => "Goa",
+            IndiaState::GJ => "Gujarat",
+            IndiaState::HR => "Haryana",
+            IndiaState::HP => "Himachal Pradesh",
+            IndiaState::JK => "Jammu and Kashmir",
+            IndiaState::JH => "Jharkhand",
+            IndiaState::KA => "Karnataka",
+            IndiaState::KL => "Kerala",
+            IndiaState::LD => "Lakshadweep",
+            IndiaState::MP => "Madhya Pradesh",
+            IndiaState::MH => "Maharashtra",
+            IndiaState::MN => "Manipur",
+            IndiaState::ML => "Meghalaya",
+            IndiaState::MZ => "Mizoram",
+            IndiaState::NL => "Nagaland",
+            IndiaState::OR => "Orissa",
+            IndiaState::PB => "Punjab",
+            IndiaState::PY => "Pondicherry / Puducherry",
+            IndiaState::RJ => "Rajasthan",
+            IndiaState::SK => "Sikkim",
+            IndiaState::TN => "Tamil Nadu",
+            IndiaState::TR => "Tripura",
+            IndiaState::UK => "Uttarakhand",
+            IndiaState::UP => "Uttar Pradesh",
+            IndiaState::WB => "West Bengal",
+        }
+    }
+}
+
 /// A state in Mexico.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum MexicoState {
     /// Aguascalientes.
     AG,
@@ -603,8 +1298,61 @@ pub enum MexicoState {
     ZA,
 }
 
+impl MexicoState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            MexicoState::AG => "Aguascalientes",
+            MexicoState::BC => "Baja California",
+            MexicoState::BS => "Baja California Sur",
+            MexicoState::CM => "Campeche",
+            MexicoState::CS => "Chiapas",
+            MexicoState::CH => "Chihuahua",
+            MexicoState::CO => "Coahuila",
+            MexicoState::CL => "Colima",
+            MexicoState::DF => "Mexico City",
+            MexicoState::DG => "Durango",
+            MexicoState::GT => "Guanajuato",
+            MexicoState::GR => "Guerrero",
+            MexicoState::HG => "Hidalgo",
+            MexicoState::JA => "Jalisco",
+            MexicoState::EM => "México",
+            MexicoState::MI => "Michoacán",
+            MexicoState::MO => "Morelos",
+            MexicoState::NA => "Nayarit",
+            MexicoState::NL => "Nuevo León",
+            MexicoState::OA => "Oaxaca",
+            MexicoState::PU => "Puebla",
+            MexicoState::QT => "Querétaro",
+            MexicoState::QR => "Quintana Roo",
+            MexicoState::SL => "San Luis Potosí",
+            MexicoState::SI => "Sinaloa",
+            MexicoState::SO => "Sonora",
+            MexicoState::TB => "Tabasco",
+            MexicoState::TM => "Tamaulipas",
+            MexicoState::TL => "Tlaxcala",
+            MexicoState::VE => "Veracruz",
+            MexicoState::YU => "Yucatán",
+            MexicoState::ZA => "Zacatecas",
+        }
+    }
+}
+
 /// A state in the Netherlands.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum NetherlandsState {
     /// Drenthe.
     DR,
@@ -632,9 +1380,29 @@ pub enum NetherlandsState {
     ZH,
 }
 
+impl NetherlandsState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            NetherlandsState::DR => "Drenthe",
+            NetherlandsState::FL => "Flevoland",
+            NetherlandsState::FR => "Friesland / Fryslân",
+            NetherlandsState::GE => "Gelderland",
+            NetherlandsState::GR => "Groningen",
+            NetherlandsState::LI => "Limburg",
+            NetherlandsState::NB => "North Brabant / Noord-Brabant",
+            NetherlandsState::NH => "North Holland / Noord-Holland",
+            NetherlandsState::OV => "Overijssel / Overissel",
+            NetherlandsState::UT => "Utrecht",
+            NetherlandsState::ZE => "Zeeland",
+            NetherlandsState::ZH => "South Holland / Zuid-Holland",
+        }
+    }
+}
+
 /// A region in New Zealand.
 #[rustfmt::skip]
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(Copy, Clone, Debug, EnumIter, EnumString, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToString)]
 pub enum NewZealandState {
     /// Northland.
     NTL,
@@ -670,8 +1438,178 @@ pub enum NewZealandState {
     STL,
 }
 
+impl NewZealandState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            NewZealandState::NTL => "Northland",
+            NewZealandState::AKL => "Auckland",
+            NewZealandState::WKO => "Waikato",
+            NewZealandState::BOP => "Bay of Plenty",
+            NewZealandState::GIS => "Gisborne",
+            NewZealandState::HKB => "Hawke's Bay",
+            NewZealandState::TKI => "Taranaki",
+            NewZealandState::MWT => "Manawatu-Whanganui",
+            NewZealandState::WGN => "Wellington",
+            NewZealandState::TAS => "Tasman",
+            NewZealandState::NSN => "Nelson",
+            NewZealandState::MBH => "Marlborough",
+            NewZealandState::WTC => "West Coast",
+            NewZealandState::CAN => "Canterbury",
+            NewZealandState::OTA => "Otago",
+            NewZealandState::STL => "Southland",
+        }
+    }
+}
+
+/// A district in Northern Ireland, using ISO 3166-2:GB codes.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+pub enum NorthernIrelandState {
+    /// Armagh City, Banbridge and Craigavon.
+    ABC,
+    /// Ards and North Down.
+    AND,
+    /// Antrim and Newtownabbey.
+    ANN,
+    /// Belfast.
+    BFS,
+    /// Causeway Coast and Glens.
+    CCG,
+    /// Derry City and Strabane.
+    DRS,
+    /// Fermanagh and Omagh.
+    FMO,
+    /// Lisburn and Castlereagh.
+    LBC,
+    /// Mid and East Antrim.
+    MEA,
+    /// Mid Ulster.
+    MUL,
+    /// Newry, Mourne and Down.
+    NMD,
+}
+
+impl NorthernIrelandState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            NorthernIrelandState::ABC => "Armagh City, Banbridge and Craigavon",
+            NorthernIrelandState::AND => "Ards and North Down",
+            NorthernIrelandState::ANN => "Antrim and Newtownabbey",
+            NorthernIrelandState::BFS => "Belfast",
+            NorthernIrelandState::CCG => "Causeway Coast and Glens",
+            NorthernIrelandState::DRS => "Derry City and Strabane",
+            NorthernIrelandState::FMO => "Fermanagh and Omagh",
+            NorthernIrelandState::LBC => "Lisburn and Castlereagh",
+            NorthernIrelandState::MEA => "Mid and East Antrim",
+            NorthernIrelandState::MUL => "Mid Ulster",
+            NorthernIrelandState::NMD => "Newry, Mourne and Down",
+        }
+    }
+}
+
+/// A voivodeship in Poland.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+pub enum PolandState {
+    /// Lower Silesian Voivodeship (dolnośląskie).
+    DS,
+    /// Kuyavian-Pomeranian Voivodeship (kujawsko-pomorskie).
+    KP,
+    /// Lublin Voivodeship (lubelskie).
+    LU,
+    /// Lubusz Voivodeship (lubuskie).
+    LB,
+    /// Łódź Voivodeship (łódzkie).
+    LD,
+    /// Lesser Poland Voivodeship (małopolskie).
+    MA,
+    /// Masovian Voivodeship (mazowieckie).
+    MZ,
+    /// Opole Voivodeship (opolskie).
+    OP,
+    /// Subcarpathian Voivodeship (podkarpackie).
+    PK,
+    /// Podlaskie Voivodeship (podlaskie).
+    PD,
+    /// Pomeranian Voivodeship (pomorskie).
+    PM,
+    /// Silesian Voivodeship (śląskie).
+    SL,
+    /// Holy Cross Voivodeship (świętokrzyskie).
+    SK,
+    /// Warmian-Masurian Voivodeship (warmińsko-mazurskie).
+    WN,
+    /// Greater Poland Voivodeship (wielkopolskie).
+    WP,
+    /// West Pomeranian Voivodeship (zachodniopomorskie).
+    ZP,
+}
+
+impl PolandState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            PolandState::DS => "Lower Silesian Voivodeship",
+            PolandState::KP => "Kuyavian-Pomeranian Voivodeship",
+            PolandState::LU => "Lublin Voivodeship",
+            PolandState::LB => "Lubusz Voivodeship",
+            PolandState::LD => "Łódź Voivodeship",
+            PolandState::MA => "Lesser Poland Voivodeship",
+            PolandState::MZ => "Masovian Voivodeship",
+            PolandState::OP => "Opole Voivodeship",
+            PolandState::PK => "Subcarpathian Voivodeship",
+            PolandState::PD => "Podlaskie Voivodeship",
+            PolandState::PM => "Pomeranian Voivodeship",
+            PolandState::SL => "Silesian Voivodeship",
+            PolandState::SK => "Holy Cross Voivodeship",
+            PolandState::WN => "Warmian-Masurian Voivodeship",
+            PolandState::WP => "Greater Poland Voivodeship",
+            PolandState::ZP => "West Pomeranian Voivodeship",
+        }
+    }
+}
+
 /// A county in Romania.
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum RomaniaState {
     /// Alba.
     AB,
@@ -759,9 +1697,59 @@ pub enum RomaniaState {
     VS,
 }
 
+impl RomaniaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            RomaniaState::AB => "Alba",
+            RomaniaState::AG => "Argeș",
+            RomaniaState::AR => "Arad",
+            RomaniaState::B => "Bucharest",
+            RomaniaState::BC => "Bacău",
+            RomaniaState::BH => "Bihor",
+            RomaniaState::BN => "Bistrița-Năsăud",
+            RomaniaState::BR => "Brăila",
+            RomaniaState::BT => "Botoșani",
+            RomaniaState::BV => "Brașov",
+            RomaniaState::BZ => "Buzău",
+            RomaniaState::CJ => "Cluj",
+            RomaniaState::CL => "Călărași",
+            RomaniaState::CS => "Caraș-Severin",
+            RomaniaState::CT => "Constanța",
+            RomaniaState::CV => "Covasna",
+            RomaniaState::DB => "Dâmbovița",
+            RomaniaState::DJ => "Dolj",
+            RomaniaState::GJ => "Gorj",
+            RomaniaState::GL => "Galați",
+            RomaniaState::GR => "Giurgiu",
+            RomaniaState::HD => "Hunedoara",
+            RomaniaState::HR => "Harghita",
+            RomaniaState::IF => "Ilfov",
+            RomaniaState::IL => "Ialomița",
+            RomaniaState::IS => "Iași",
+            RomaniaState::MH => "Mehedinți",
+            RomaniaState::MM => "Maramureș",
+            RomaniaState::MS => "Mureș",
+            RomaniaState::NT => "Neamț",
+            RomaniaState::OT => "Olt",
+            RomaniaState::PH => "Prahova",
+            RomaniaState::SB => "Sibiu",
+            RomaniaState::SJ => "Sălaj",
+            RomaniaState::SM => "Satu Mare",
+            RomaniaState::SV => "Suceava",
+            RomaniaState::TL => "Tulcea",
+            RomaniaState::TM => "Timiș",
+            RomaniaState::TR => "Teleorman",
+            RomaniaState::VL => "Vâlcea",
+            RomaniaState::VN => "Vrancea",
+            RomaniaState::VS => "Vaslui",
+        }
+    }
+}
+
 /// An oblast in Russia.
 #[rustfmt::skip]
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(Copy, Clone, Debug, EnumIter, EnumString, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToString)]
 pub enum RussiaState {
     AD, AL, BA, BU, CE, CU, DA, IN, KB, KL, KC, KR, KK, KO, ME, MO, SA,
     SE, TA, TY, UD, ALT, KAM, KHA, KDA, KYA, PER, PRI, STA, ZAB, AMU, ARK,
@@ -771,8 +1759,235 @@ pub enum RussiaState {
     VLG, VOR, YAR, MOW, SPE, YEV, CHU, KHM, NEN, YAN
 }
 
+impl RussiaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            RussiaState::AD => "AD",
+            RussiaState::AL => "AL",
+            RussiaState::BA => "BA",
+            RussiaState::BU => "BU",
+            RussiaState::CE => "CE",
+            RussiaState::CU => "CU",
+            RussiaState::DA => "DA",
+            RussiaState::IN => "IN",
+            RussiaState::KB => "KB",
+            RussiaState::KL => "KL",
+            RussiaState::KC => "KC",
+            RussiaState::KR => "KR",
+            RussiaState::KK => "KK",
+            RussiaState::KO => "KO",
+            RussiaState::ME => "ME",
+            RussiaState::MO => "MO",
+            RussiaState::SA => "SA",
+            RussiaState::SE => "SE",
+            RussiaState::TA => "TA",
+            RussiaState::TY => "TY",
+            RussiaState::UD => "UD",
+            RussiaState::ALT => "ALT",
+            RussiaState::KAM => "KAM",
+            RussiaState::KHA => "KHA",
+            RussiaState::KDA => "KDA",
+            RussiaState::KYA => "KYA",
+            RussiaState::PER => "PER",
+            RussiaState::PRI => "PRI",
+            RussiaState::STA => "STA",
+            RussiaState::ZAB => "ZAB",
+            RussiaState::AMU => "AMU",
+            RussiaState::ARK => "ARK",
+            RussiaState::AST => "AST",
+            RussiaState::BEL => "BEL",
+            RussiaState::BRY => "BRY",
+            RussiaState::CHE => "CHE",
+            RussiaState::IRK => "IRK",
+            RussiaState::IVA => "IVA",
+            RussiaState::KGD => "KGD",
+            RussiaState::KLU => "KLU",
+            RussiaState::KEM => "KEM",
+            RussiaState::KIR => "KIR",
+            RussiaState::KOS => "KOS",
+            RussiaState::KGN => "KGN",
+            RussiaState::KRS => "KRS",
+            RussiaState::LEN => "LEN",
+            RussiaState::LIP => "LIP",
+            RussiaState::MAG => "MAG",
+            RussiaState::MOS => "MOS",
+            RussiaState::MUR => "MUR",
+            RussiaState::NIZ => "NIZ",
+            RussiaState::NGR => "NGR",
+            RussiaState::NVS => "NVS",
+            RussiaState::OMS => "OMS",
+            RussiaState::ORE => "ORE",
+            RussiaState::ORL => "ORL",
+            RussiaState::PNZ => "PNZ",
+            RussiaState::PSK => "PSK",
+            RussiaState::ROS => "ROS",
+            RussiaState::RYA => "RYA",
+            RussiaState::SAK => "SAK",
+            RussiaState::SAM => "SAM",
+            RussiaState::SAR => "SAR",
+            RussiaState::SMO => "SMO",
+            RussiaState::SVE => "SVE",
+            RussiaState::TAM => "TAM",
+            RussiaState::TOM => "TOM",
+            RussiaState::TUL => "TUL",
+            RussiaState::TVE => "TVE",
+            RussiaState::TYE => "TYE",
+            RussiaState::TYU => "TYU",
+            RussiaState::ULY => "ULY",
+            RussiaState::VLA => "VLA",
+            RussiaState::VGG => "VGG",
+            RussiaState::VLG => "VLG",
+            RussiaState::VOR => "VOR",
+            RussiaState::YAR => "YAR",
+            RussiaState::MOW => "MOW",
+            RussiaState::SPE => "SPE",
+            RussiaState::YEV => "YEV",
+            RussiaState::CHU => "CHU",
+            RussiaState::KHM => "KHM",
+            RussiaState::NEN => "NEN",
+            RussiaState::YAN => "YAN",
+        }
+    }
+}
+
+/// A council area in Scotland, using ISO 3166-2:GB codes.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+pub enum ScotlandState {
+    /// Aberdeenshire.
+    ABD,
+    /// Aberdeen City.
+    ABE,
+    /// Argyll and Bute.
+    AGB,
+    /// Angus.
+    ANS,
+    /// Clackmannanshire.
+    CLK,
+    /// Dumfries and Galloway.
+    DGY,
+    /// Dundee City.
+    DND,
+    /// East Ayrshire.
+    EAY,
+    /// City of Edinburgh.
+    EDH,
+    /// East Dunbartonshire.
+    EDU,
+    /// East Lothian.
+    ELN,
+    /// Na h-Eileanan Siar.
+    ELS,
+    /// East Renfrewshire.
+    ERW,
+    /// Falkirk.
+    FAL,
+    /// Fife.
+    FIF,
+    /// Glasgow City.
+    GLG,
+    /// Highland.
+    HLD,
+    /// Inverclyde.
+    IVC,
+    /// Midlothian.
+    MLN,
+    /// Moray.
+    MRY,
+    /// North Ayrshire.
+    NAY,
+    /// North Lanarkshire.
+    NLK,
+    /// Orkney Islands.
+    ORK,
+    /// Perth and Kinross.
+    PKN,
+    /// Renfrewshire.
+    RFW,
+    /// South Ayrshire.
+    SAY,
+    /// Scottish Borders.
+    SCB,
+    /// South Lanarkshire.
+    SLK,
+    /// Stirling.
+    STG,
+    /// West Dunbartonshire.
+    WDU,
+    /// West Lothian.
+    WLN,
+    /// Shetland Islands.
+    ZET,
+}
+
+impl ScotlandState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            ScotlandState::ABD => "Aberdeenshire",
+            ScotlandState::ABE => "Aberdeen City",
+            ScotlandState::AGB => "Argyll and Bute",
+            ScotlandState::ANS => "Angus",
+            ScotlandState::CLK => "Clackmannanshire",
+            ScotlandState::DGY => "Dumfries and Galloway",
+            ScotlandState::DND => "Dundee City",
+            ScotlandState::EAY => "East Ayrshire",
+            ScotlandState::EDH => "City of Edinburgh",
+            ScotlandState::EDU => "East Dunbartonshire",
+            ScotlandState::ELN => "East Lothian",
+            ScotlandState::ELS => "Na h-Eileanan Siar",
+            ScotlandState::ERW => "East Renfrewshire",
+            ScotlandState::FAL => "Falkirk",
+            ScotlandState::FIF => "Fife",
+            ScotlandState::GLG => "Glasgow City",
+            ScotlandState::HLD => "Highland",
+            ScotlandState::IVC => "Inverclyde",
+            ScotlandState::MLN => "Midlothian",
+            ScotlandState::MRY => "Moray",
+            ScotlandState::NAY => "North Ayrshire",
+            ScotlandState::NLK => "North Lanarkshire",
+            ScotlandState::ORK => "Orkney Islands",
+            ScotlandState::PKN => "Perth and Kinross",
+            ScotlandState::RFW => "Renfrewshire",
+            ScotlandState::SAY => "South Ayrshire",
+            ScotlandState::SCB => "Scottish Borders",
+            ScotlandState::SLK => "South Lanarkshire",
+            ScotlandState::STG => "Stirling",
+            ScotlandState::WDU => "West Dunbartonshire",
+            ScotlandState::WLN => "West Lothian",
+            ScotlandState::ZET => "Shetland Islands",
+        }
+    }
+}
+
 /// A province in South Africa, using conventional acronyms (non-ISO).
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    ToString,
+)]
 pub enum SouthAfricaState {
     /// Eastern Cape.
     EC,
@@ -780,7 +1995,7 @@ pub enum SouthAfricaState {
     FS,
     /// Gauteng.
     GT,
-    /// KwaZulu-Natal (ISO: NL).
+    /// KwaZulu-Natal (ISO: KZN, formerly NL).
     KZN,
     /// Limpopo.
     LP,
@@ -794,9 +2009,130 @@ pub enum SouthAfricaState {
     WC,
 }
 
+impl SouthAfricaState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            SouthAfricaState::EC => "Eastern Cape",
+            SouthAfricaState::FS => "Free State",
+            SouthAfricaState::GT => "Gauteng",
+            SouthAfricaState::KZN => "KwaZulu-Natal",
+            SouthAfricaState::LP => "Limpopo",
+            SouthAfricaState::MP => "Mpumalanga",
+            SouthAfricaState::NC => "Northern Cape",
+            SouthAfricaState::NW => "North-West",
+            SouthAfricaState::WC => "Western Cape",
+        }
+    }
+}
+
+/// An oblast in Ukraine.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+pub enum UkraineState {
+    /// Cherkasy Oblast.
+    CK,
+    /// Chernihiv Oblast.
+    CH,
+    /// Chernivtsi Oblast.
+    CV,
+    /// Autonomous Republic of Crimea.
+    CR,
+    /// Dnipropetrovsk Oblast.
+    DP,
+    /// Donetsk Oblast.
+    DT,
+    /// Ivano-Frankivsk Oblast.
+    IF,
+    /// Kharkiv Oblast.
+    KH,
+    /// Kherson Oblast.
+    KS,
+    /// Khmelnytskyi Oblast.
+    KM,
+    /// Kirovohrad Oblast.
+    KR,
+    /// Kyiv Oblast.
+    KO,
+    /// Kyiv, the capital city.
+    KV,
+    /// Luhansk Oblast.
+    LH,
+    /// Lviv Oblast.
+    LV,
+    /// Mykolaiv Oblast.
+    MY,
+    /// Odesa Oblast.
+    OD,
+    /// Poltava Oblast.
+    PL,
+    /// Rivne Oblast.
+    RV,
+    /// Sumy Oblast.
+    SM,
+    /// Ternopil Oblast.
+    TP,
+    /// Vinnytsia Oblast.
+    VI,
+    /// Volyn Oblast.
+    VO,
+    /// Zakarpattia Oblast.
+    ZK,
+    /// Zaporizhzhia Oblast.
+    ZP,
+    /// Zhytomyr Oblast.
+    ZT,
+}
+
+impl UkraineState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            UkraineState::CK => "Cherkasy Oblast",
+            UkraineState::CH => "Chernihiv Oblast",
+            UkraineState::CV => "Chernivtsi Oblast",
+            UkraineState::CR => "Autonomous Republic of Crimea",
+            UkraineState::DP => "Dnipropetrovsk Oblast",
+            UkraineState::DT => "Donetsk Oblast",
+            UkraineState::IF => "Ivano-Frankivsk Oblast",
+            UkraineState::KH => "Kharkiv Oblast",
+            UkraineState::KS => "Kherson Oblast",
+            UkraineState::KM => "Khmelnytskyi Oblast",
+            UkraineState::KR => "Kirovohrad Oblast",
+            UkraineState::KO => "Kyiv Oblast",
+            UkraineState::KV => "Kyiv",
+            UkraineState::LH => "Luhansk Oblast",
+            UkraineState::LV => "Lviv Oblast",
+            UkraineState::MY => "Mykolaiv Oblast",
+            UkraineState::OD => "Odesa Oblast",
+            UkraineState::PL => "Poltava Oblast",
+            UkraineState::RV => "Rivne Oblast",
+            UkraineState::SM => "Sumy Oblast",
+            UkraineState::TP => "Ternopil Oblast",
+            UkraineState::VI => "Vinnytsia Oblast",
+            UkraineState::VO => "Volyn Oblast",
+            UkraineState::ZK => "Zakarpattia Oblast",
+            UkraineState::ZP => "Zaporizhzhia Oblast",
+            UkraineState::ZT => "Zhytomyr Oblast",
+        }
+    }
+}
+
 /// A state in the USA.
 #[rustfmt::skip]
-#[derive(Copy, Clone, Debug, EnumString, PartialEq, Serialize, ToString)]
+#[derive(Copy, Clone, Debug, EnumIter, EnumString, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToString)]
 pub enum USAState {
     AL, AK, AZ, AR, CA, CO, CT, DE, DC, FL, GA, HI, ID, IL, IN, IA, KS,
     KY, LA, ME, MD, MA, MI, MN, MS, MO, MT, NE, NV, NH, NJ, NM, NY, NC,
@@ -805,3 +2141,424 @@ pub enum USAState {
     /// Guam is an unincorporated territory of the USA.
     Guam,
 }
+
+impl USAState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            USAState::AL => "Alabama",
+            USAState::AK => "Alaska",
+            USAState::AZ => "Arizona",
+            USAState::AR => "Arkansas",
+            USAState::CA => "California",
+            USAState::CO => "Colorado",
+            USAState::CT => "Connecticut",
+            USAState::DE => "Delaware",
+            USAState::DC => "District of Columbia",
+            USAState::FL => "Florida",
+            USAState::GA => "Georgia",
+            USAState::HI => "Hawaii",
+            USAState::ID => "Idaho",
+            USAState::IL => "Illinois",
+            USAState::IN => "Indiana",
+            USAState::IA => "Iowa",
+            USAState::KS => "Kansas",
+            USAState::KY => "Kentucky",
+            USAState::LA => "Louisiana",
+            USAState::ME => "Maine",
+            USAState::MD => "Maryland",
+            USAState::MA => "Massachusetts",
+            USAState::MI => "Michigan",
+            USAState::MN => "Minnesota",
+            USAState::MS => "Mississippi",
+            USAState::MO => "Missouri",
+            USAState::MT => "Montana",
+            USAState::NE => "Nebraska",
+            USAState::NV => "Nevada",
+            USAState::NH => "New Hampshire",
+            USAState::NJ => "New Jersey",
+            USAState::NM => "New Mexico",
+            USAState::NY => "New York",
+            USAState::NC => "North Carolina",
+            USAState::ND => "North Dakota",
+            USAState::OH => "Ohio",
+            USAState::OK => "Oklahoma",
+            USAState::OR => "Oregon",
+            USAState::PA => "Pennsylvania",
+            USAState::RI => "Rhode Island",
+            USAState::SC => "South Carolina",
+            USAState::SD => "South Dakota",
+            USAState::TN => "Tennessee",
+            USAState::TX => "Texas",
+            USAState::UT => "Utah",
+            USAState::VT => "Vermont",
+            USAState::VA => "Virginia",
+            USAState::WA => "Washington",
+            USAState::WV => "West Virginia",
+            USAState::WI => "Wisconsin",
+            USAState::WY => "Wyoming",
+            USAState::Guam => "Guam",
+        }
+    }
+}
+
+/// A principal area in Wales, using ISO 3166-2:GB codes.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+pub enum WalesState {
+    /// Isle of Anglesey.
+    AGY,
+    /// Bridgend.
+    BGE,
+    /// Blaenau Gwent.
+    BGW,
+    /// Caerphilly.
+    CAY,
+    /// Ceredigion.
+    CGN,
+    /// Carmarthenshire.
+    CMN,
+    /// Cardiff.
+    CRF,
+    /// Conwy.
+    CWY,
+    /// Denbighshire.
+    DEN,
+    /// Flintshire.
+    FLN,
+    /// Gwynedd.
+    GWN,
+    /// Monmouthshire.
+    MON,
+    /// Merthyr Tydfil.
+    MTY,
+    /// Neath Port Talbot.
+    NTL,
+    /// Newport.
+    NWP,
+    /// Pembrokeshire.
+    PEM,
+    /// Powys.
+    POW,
+    /// Rhondda Cynon Taf.
+    RCT,
+    /// Swansea.
+    SWA,
+    /// Torfaen.
+    TOF,
+    /// Vale of Glamorgan.
+    VGL,
+    /// Wrexham.
+    WRX,
+}
+
+impl WalesState {
+    /// The full English name.
+    fn to_name(self) -> &'static str {
+        match self {
+            WalesState::AGY => "Isle of Anglesey",
+            WalesState::BGE => "Bridgend",
+            WalesState::BGW => "Blaenau Gwent",
+            WalesState::CAY => "Caerphilly",
+            WalesState::CGN => "Ceredigion",
+            WalesState::CMN => "Carmarthenshire",
+            WalesState::CRF => "Cardiff",
+            WalesState::CWY => "Conwy",
+            WalesState::DEN => "Denbighshire",
+            WalesState::FLN => "Flintshire",
+            WalesState::GWN => "Gwynedd",
+            WalesState::MON => "Monmouthshire",
+            WalesState::MTY => "Merthyr Tydfil",
+            WalesState::NTL => "Neath Port Talbot",
+            WalesState::NWP => "Newport",
+            WalesState::PEM => "Pembrokeshire",
+            WalesState::POW => "Powys",
+            WalesState::RCT => "Rhondda Cynon Taf",
+            WalesState::SWA => "Swansea",
+            WalesState::TOF => "Torfaen",
+            WalesState::VGL => "Vale of Glamorgan",
+            WalesState::WRX => "Wrexham",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_state_names() {
+        let state = State::from_full_code("Germany-BW").unwrap();
+        assert_eq!(state.to_state_name(), "Baden-Württemberg");
+
+        // Parenthetical details are dropped.
+        let state = State::InChina(ChinaState::BJ);
+        assert_eq!(state.to_state_name(), "Beijing Municipality");
+        let state = State::InSouthAfrica(SouthAfricaState::KZN);
+        assert_eq!(state.to_state_name(), "KwaZulu-Natal");
+
+        // Oblasts without names fall back to the code.
+        let state = State::InRussia(RussiaState::MOW);
+        assert_eq!(state.to_state_name(), "MOW");
+    }
+
+    #[test]
+    fn test_from_full_code() {
+        assert_eq!(
+            State::from_full_code("USA-NY"),
+            Ok(State::InUSA(USAState::NY))
+        );
+        assert_eq!(
+            State::from_full_code("USA"),
+            Err(StateParseError::InvalidStateCode {
+                country: Country::USA,
+                code: "".into()
+            })
+        );
+
+        // Everything after the first hyphen is the State code.
+        assert_eq!(
+            State::from_full_code("USA-NY-X"),
+            Err(StateParseError::InvalidStateCode {
+                country: Country::USA,
+                code: "NY-X".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            State::from_full_code("Atlantis-NY"),
+            Err(StateParseError::UnknownCountry)
+        );
+        assert_eq!(
+            State::from_str_and_country("TA", Country::Uzbekistan),
+            Err(StateParseError::CountryHasNoStates(Country::Uzbekistan))
+        );
+        assert_eq!(
+            State::from_str_and_country("ZZ", Country::Canada),
+            Err(StateParseError::InvalidStateCode {
+                country: Country::Canada,
+                code: "ZZ".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let state = State::InUSA(USAState::NY);
+        assert_eq!(state.to_string(), "USA-NY");
+        assert_eq!(
+            serde_json::to_string(&state).unwrap(),
+            format!("\"{}\"", state)
+        );
+    }
+
+    #[test]
+    fn test_parse_relaxed() {
+        let usa = Country::USA;
+        assert_eq!(
+            State::parse_relaxed("california", usa),
+            Ok(State::InUSA(USAState::CA))
+        );
+        assert_eq!(
+            State::parse_relaxed(" NY ", usa),
+            Ok(State::InUSA(USAState::NY))
+        );
+        assert_eq!(
+            State::parse_relaxed("ny", usa),
+            Ok(State::InUSA(USAState::NY))
+        );
+        assert_eq!(
+            State::parse_relaxed("Atlantis", usa),
+            Err(StateParseError::InvalidStateCode {
+                country: usa,
+                code: "Atlantis".into()
+            })
+        );
+
+        // The strict parser is unchanged.
+        assert!(State::from_str_and_country("california", usa).is_err());
+    }
+
+    #[test]
+    fn test_iter_for_country() {
+        assert_eq!(State::iter_for_country(Country::Canada).count(), 13);
+        assert!(State::iter_for_country(Country::USA).all(|s| s.to_country() == Country::USA));
+        assert!(State::iter_for_country(Country::USA).any(|s| s == State::InUSA(USAState::Guam)));
+        assert_eq!(State::iter_for_country(Country::Uzbekistan).count(), 0);
+    }
+
+    #[test]
+    fn test_from_iso_and_country() {
+        let za = Country::SouthAfrica;
+        let kzn = State::InSouthAfrica(SouthAfricaState::KZN);
+        assert_eq!(State::from_iso_and_country("NL", za), Ok(kzn));
+        assert_eq!(State::from_iso_and_country("KZN", za), Ok(kzn));
+        assert_eq!(
+            State::from_iso_and_country("GP", za),
+            Ok(State::InSouthAfrica(SouthAfricaState::GT))
+        );
+
+        // ISO and our codes match for the USA.
+        let usa = Country::USA;
+        assert_eq!(
+            State::from_iso_and_country("NY", usa),
+            Ok(State::InUSA(USAState::NY))
+        );
+        assert_eq!(
+            State::from_iso_and_country("TX", usa),
+            Ok(State::InUSA(USAState::TX))
+        );
+        assert!(State::from_iso_and_country("XX", usa).is_err());
+
+        // The strict parser is unchanged.
+        assert!(State::from_str_and_country("NL", za).is_err());
+    }
+
+    #[test]
+    fn test_iso_country_codes() {
+        // State codes are prefixed by the Country's own ISO code.
+        for country in Country::iter() {
+            for state in State::iter_for_country(country) {
+                if let (Some(iso), Some(code)) = (state.to_iso_3166_2(), country.to_iso2()) {
+                    assert!(iso.starts_with(&format!("{}-", code)), "{}", iso);
+                }
+            }
+        }
+
+        // The UK nations share a code, but not their subdivisions.
+        assert_eq!(Country::Scotland.to_iso2(), None);
+        let state = State::InNorthernIreland(NorthernIrelandState::BFS);
+        assert_eq!(state.to_iso_3166_2().unwrap(), "GB-BFS");
+        assert_eq!(State::from_iso_3166_2("GB-BFS"), Some(state));
+        assert_eq!(State::from_iso_3166_2("GB-XX"), None);
+    }
+
+    #[test]
+    fn test_poland() {
+        let state = State::from_full_code("Poland-MZ").unwrap();
+        assert_eq!(state, State::InPoland(PolandState::MZ));
+        assert_eq!(state.to_country(), Country::Poland);
+        assert_eq!(state.to_state_string(), "MZ");
+        assert_eq!(serde_json::to_string(&state).unwrap(), "\"Poland-MZ\"");
+        assert_eq!(State::iter_for_country(Country::Poland).count(), 16);
+        assert_eq!(state.to_iso_3166_2().unwrap(), "PL-MZ");
+    }
+
+    #[test]
+    fn test_ukraine() {
+        let state = State::from_str_and_country("KV", Country::Ukraine).unwrap();
+        assert_eq!(state, State::InUkraine(UkraineState::KV));
+        assert_eq!(state.to_country(), Country::Ukraine);
+        assert_eq!(state.to_state_string(), "KV");
+
+        let code = serde_json::to_string(&state).unwrap();
+        assert_eq!(code, "\"Ukraine-KV\"");
+        assert_eq!(State::from_full_code("Ukraine-KV").unwrap(), state);
+        assert_eq!(State::iter_for_country(Country::Ukraine).count(), 26);
+
+        // ISO codes are numeric, so our letter codes aren't accepted.
+        assert_eq!(state.to_iso_3166_2().unwrap(), "UA-30");
+        assert_eq!(State::from_iso_3166_2("UA-30").unwrap(), state);
+        assert!(State::from_iso_3166_2("UA-KV").is_none());
+        let cherkasy = State::InUkraine(UkraineState::CK);
+        assert_eq!(cherkasy.to_iso_3166_2().unwrap(), "UA-71");
+
+        // Every oblast round-trips.
+        for state in State::iter_for_country(Country::Ukraine) {
+            let iso = state.to_iso_3166_2().unwrap();
+            assert_eq!(State::from_iso_3166_2(&iso), Some(state));
+        }
+    }
+
+    #[test]
+    fn test_uk_nations() {
+        let state = State::from_str_and_country("EDH", Country::Scotland).unwrap();
+        assert_eq!(state, State::InScotland(ScotlandState::EDH));
+        assert_eq!(state.to_country(), Country::Scotland);
+        assert_eq!(state.to_state_string(), "EDH");
+        assert_eq!(state.to_state_name(), "City of Edinburgh");
+        assert_eq!(serde_json::to_string(&state).unwrap(), "\"Scotland-EDH\"");
+        assert_eq!(State::from_full_code("Scotland-EDH").unwrap(), state);
+        assert_eq!(state.to_iso_3166_2().unwrap(), "GB-EDH");
+        assert_eq!(State::from_iso_3166_2("GB-EDH").unwrap(), state);
+
+        let state = State::from_str_and_country("CRF", Country::Wales).unwrap();
+        assert_eq!(state, State::InWales(WalesState::CRF));
+        assert_eq!(state.to_country(), Country::Wales);
+        assert_eq!(serde_json::to_string(&state).unwrap(), "\"Wales-CRF\"");
+        assert_eq!(State::from_full_code("Wales-CRF").unwrap(), state);
+        assert_eq!(State::from_iso_3166_2("GB-CRF").unwrap(), state);
+
+        // Northern Ireland's canonical Country name contains no hyphen.
+        let state = State::InNorthernIreland(NorthernIrelandState::BFS);
+        assert_eq!(state.to_string(), "N.Ireland-BFS");
+        assert_eq!(State::from_full_code("N.Ireland-BFS").unwrap(), state);
+
+        assert_eq!(State::iter_for_country(Country::Scotland).count(), 32);
+        assert_eq!(State::iter_for_country(Country::Wales).count(), 22);
+        assert_eq!(
+            State::iter_for_country(Country::NorthernIreland).count(),
+            11
+        );
+
+        // England keeps its BP regions, which have no ISO codes.
+        let state = State::from_full_code("England-GL").unwrap();
+        assert_eq!(state, State::InEngland(EnglandState::GL));
+        assert_eq!(state.to_iso_3166_2(), None);
+    }
+
+    #[test]
+    fn test_map_keys() {
+        let states = [
+            State::InUSA(USAState::NY),
+            State::InCanada(CanadaState::ON),
+            State::InUSA(USAState::CA),
+            State::InUSA(USAState::NY),
+        ];
+
+        let mut hashed: HashMap<State, u32> = HashMap::new();
+        let mut ordered: BTreeMap<State, u32> = BTreeMap::new();
+        for state in states.iter() {
+            *hashed.entry(*state).or_insert(0) += 1;
+            *ordered.entry(*state).or_insert(0) += 1;
+        }
+
+        assert_eq!(hashed.len(), 3);
+        assert_eq!(hashed[&State::InUSA(USAState::NY)], 2);
+
+        // States are grouped by country, in declaration order.
+        let keys: Vec<State> = ordered.keys().copied().collect();
+        assert_eq!(
+            keys,
+            vec![
+                State::InCanada(CanadaState::ON),
+                State::InUSA(USAState::CA),
+                State::InUSA(USAState::NY),
+            ]
+        );
+
+        let mut countries: HashMap<Country, u32> = HashMap::new();
+        for state in states.iter() {
+            *countries.entry(state.to_country()).or_insert(0) += 1;
+        }
+        assert_eq!(countries[&Country::USA], 3);
+        assert_eq!(countries[&Country::Canada], 1);
+    }
+}
@@ -15,6 +15,10 @@ use crate::writing_system::{infer_writing_system, WritingSystem};
 ///  2. Lowercasing.
 ///  3. Replacing non-ASCII chars with ASCII lookalikes.
 ///
+/// Apostrophes and hyphens are stripped rather than replaced, including
+/// their typographic variants, so "O'Brien", "O’Brien", and "OBrien"
+/// all become "obrien".
+///
 #[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Username(AsciiString);
 
@@ -70,19 +74,30 @@ impl Username {
         if name.is_empty() {
             Ok(Username::default())
         } else if infer_writing_system(name) == WritingSystem::Japanese {
-            let ea_id: String = name
-                .chars()
-                .filter(|c| !c.is_whitespace())
-                .map(hira_to_kata_char)
-                .map(|c| (c as u32).to_string())
-                .collect();
-            let s = format!("ea-{}", ea_id);
-            Ok(Username(s.into_ascii_string().unwrap()))
+            Ok(Username(east_asian_id(name).into_ascii_string().unwrap()))
         } else {
             convert_to_ascii(name)
         }
     }
 
+    /// Like [Username::from_name], but transliterating Cyrillic letters.
+    ///
+    /// Transliteration follows BGN/PCGN romanization without diacritics
+    /// or apostrophes, so "Пётр" becomes "petr". Names containing any of the
+    /// Ukrainian letters і, ї, є, or ґ use the Ukrainian table, which
+    /// romanizes и as "y" and г as "h". Other names use the Russian table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::Username;
+    /// let username = Username::from_name_transliterated("Пётр").unwrap();
+    /// assert_eq!(username.as_str(), "petr");
+    /// ```
+    pub fn from_name_transliterated(name: &str) -> Result<Self, String> {
+        Username::from_name(&transliterate_cyrillic(name))
+    }
+
     /// Interprets a [&str] as a [Username]. Used in deserialization.
     pub(crate) fn from_str(s: &str) -> Result<Self, ascii::FromAsciiError<&str>> {
         Ok(Username(s.into_ascii_string()?))
@@ -108,39 +123,64 @@ impl Username {
     /// assert_eq!(variant, 1);
     /// ```
     pub fn to_parts(&self) -> (&AsciiStr, u32) {
-        // Common case first: if no digit at end, it's not a variant.
-        if let Some(ascii_char) = self.0.last() {
-            if !ascii_char.is_ascii_digit() {
-                return (&self.0, 0);
-            }
-        } else {
-            return (&self.0, 0); // Username was the empty string.
-        }
+        let (base, variant) = parse_disambiguation(self.as_str());
+        (&self.0[0..base.len()], variant.unwrap_or(0))
+    }
+}
 
-        // Slow case: the username ends with a digit.
-        //
-        // If the username begins with "ea-", then it's an East Asian name
-        // that is numerically encoded and cannot be disambiguated.
-        if self.0.as_str().starts_with("ea-") {
-            return (&self.0, 0);
-        }
+/// Creates the username for a Name, followed by a disambiguation number.
+///
+/// Disambiguation numbers distinguish different lifters sharing a Name,
+/// following the "John Smith #1" convention of the Name field.
+/// Since variant numbers begin at 1, a zero adds no suffix.
+///
+/// East Asian usernames are numerically encoded and cannot be disambiguated.
+///
+/// # Examples
+///
+/// ```
+/// # use opltypes::with_disambiguation;
+/// assert_eq!(with_disambiguation("John Smith", 1).unwrap(), "johnsmith1");
+/// ```
+pub fn with_disambiguation(name: &str, n: u32) -> Result<String, String> {
+    let username = Username::from_name(name)?;
+    if n == 0 {
+        Ok(username.into())
+    } else {
+        Ok(format!("{}{}", username, n))
+    }
+}
 
-        // Definitely a variant.
-        //
-        // Walk the string backwards looking for the first index
-        // of an ASCII digit.
-        let mut start: usize = self.len() - 1;
-        for i in (0..start).rev() {
-            if self.0[i].is_ascii_digit() {
-                start = i;
-            } else {
-                break;
-            }
-        }
+/// Splits a username into its base and disambiguation number, if any.
+///
+/// # Examples
+///
+/// ```
+/// # use opltypes::parse_disambiguation;
+/// assert_eq!(parse_disambiguation("johnsmith1"), ("johnsmith", Some(1)));
+/// assert_eq!(parse_disambiguation("johnsmith"), ("johnsmith", None));
+/// ```
+pub fn parse_disambiguation(username: &str) -> (&str, Option<u32>) {
+    // Common case first: if no digit at end, it's not a variant.
+    match username.as_bytes().last() {
+        Some(c) if c.is_ascii_digit() => (),
+        _ => return (username, None),
+    }
 
-        let variant = self.0.as_str()[start..].parse::<u32>().unwrap_or(0);
-        (&self.0[0..start], variant)
+    // Slow case: the username ends with a digit.
+    //
+    // If the username begins with "ea-", then it's an East Asian name
+    // that is numerically encoded and cannot be disambiguated.
+    if username.starts_with("ea-") {
+        return (username, None);
     }
+
+    // Definitely a variant: split off the trailing digits.
+    let start = username
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .len();
+    let variant = username[start..].parse::<u32>().ok().filter(|&n| n > 0);
+    (&username[0..start], variant)
 }
 
 impl Serialize for Username {
@@ -188,35 +228,9 @@ fn convert_to_ascii(name: &str) -> Result<Username, String> {
             }
         }
 
-        // A single UTF-8 char can expand to multiple ASCII chars.
-        let s: &str = match letter {
-            'á' | 'ä' | 'å' | 'ą' | 'ã' | 'à' | 'â' | 'ā' | 'ắ' | 'ấ' | 'ầ' | 'ặ' | 'ạ' | 'ă'
-            | 'ả' | 'ậ' | 'ằ' | 'ẩ' => "a",
-            'æ' => "ae",
-            'ć' | 'ç' | 'č' | 'ĉ' | 'ċ' => "c",
-            'đ' | 'ð' | 'ď' => "d",
-            'é' | 'ê' | 'ë' | 'è' | 'ě' | 'ę' | 'ē' | 'ế' | 'ễ' | 'ể' | 'ề' | 'ệ' | 'ė' | 'ə' => {
-                "e"
-            }
-            'ğ' | 'ģ' => "g",
-            'î' | 'í' | 'ï' | 'ì' | 'ї' | 'ī' | 'ĩ' | 'ị' | 'ı' | 'į' => "i",
-            'ķ' => "k",
-            'ľ' | 'ĺ' | 'ļ' | 'ŀ' | 'ł' => "l",
-            'ñ' | 'ń' | 'ň' | 'ņ' => "n",
-            'ø' | 'ô' | 'ö' | 'ó' | 'ő' | 'õ' | 'ò' | 'ỗ' | 'ọ' | 'ơ' | 'ồ' | 'ớ' | 'ố' | 'ō'
-            | 'ŏ' | 'ờ' | 'ộ' | 'ợ' => "o",
-            'ř' => "r",
-            'ß' => "ss",
-            'š' | 'ś' | 'ș' | 'ş' => "s",
-            'ț' | 'ť' | 'ţ' => "t",
-            'þ' => "th",
-            'ü' | 'ů' | 'ú' | 'ù' | 'ū' | 'ű' | 'ư' | 'ứ' | 'ũ' | 'ữ' | 'ự' | 'ừ' | 'ử' => {
-                "u"
-            }
-            'ý' | 'ỳ' | 'ỹ' | 'ỷ' => "y",
-            'ž' | 'ż' | 'ź' => "z",
-            '\u{307}' => "", // A Turkish critical mark.
-            _ => {
+        let s: &str = match ascii_lookalike(letter) {
+            Some(s) => s,
+            None => {
                 return Err(format!(
                     "Unknown character '{}' ({:?}) in '{}'",
                     letter,
@@ -232,9 +246,158 @@ fn convert_to_ascii(name: &str) -> Result<Username, String> {
     Ok(Username(ascii_name))
 }
 
+/// Gets the ASCII lookalike of a lowercase non-ASCII letter, if it has one.
+fn ascii_lookalike(letter: char) -> Option<&'static str> {
+    // A single UTF-8 char can expand to multiple ASCII chars.
+    let s: &str = match letter {
+        'á' | 'ä' | 'å' | 'ą' | 'ã' | 'à' | 'â' | 'ā' | 'ắ' | 'ấ' | 'ầ' | 'ặ' | 'ạ' | 'ă' | 'ả'
+        | 'ậ' | 'ằ' | 'ẩ' => "a",
+        'æ' => "ae",
+        'ć' | 'ç' | 'č' | 'ĉ' | 'ċ' => "c",
+        'đ' | 'ð' | 'ď' => "d",
+        'é' | 'ê' | 'ë' | 'è' | 'ě' | 'ę' | 'ē' | 'ế' | 'ễ' | 'ể' | 'ề' | 'ệ' | 'ė' | 'ə' => {
+            "e"
+        }
+        'ğ' | 'ģ' => "g",
+        'î' | 'í' | 'ï' | 'ì' | 'ї' | 'ī' | 'ĩ' | 'ị' | 'ı' | 'į' => "i",
+        'ķ' => "k",
+        'ľ' | 'ĺ' | 'ļ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ň' | 'ņ' => "n",
+        'ø' | 'ô' | 'ö' | 'ó' | 'ő' | 'õ' | 'ò' | 'ỗ' | 'ọ' | 'ơ' | 'ồ' | 'ớ' | 'ố' | 'ō' | 'ŏ'
+        | 'ờ' | 'ộ' | 'ợ' => "o",
+        'ř' => "r",
+        'ß' => "ss",
+        'š' | 'ś' | 'ș' | 'ş' => "s",
+        'ț' | 'ť' | 'ţ' => "t",
+        'þ' => "th",
+        'ü' | 'ů' | 'ú' | 'ù' | 'ū' | 'ű' | 'ư' | 'ứ' | 'ũ' | 'ữ' | 'ự' | 'ừ' | 'ử' => {
+            "u"
+        }
+        'ý' | 'ỳ' | 'ỹ' | 'ỷ' => "y",
+        'ž' | 'ż' | 'ź' => "z",
+        '\u{307}' => "", // A Turkish critical mark.
+        _ => return None,
+    };
+    Some(s)
+}
+
+/// Replaces lowercased Cyrillic letters with their BGN/PCGN romanization.
+///
+/// Characters outside the Cyrillic tables are kept as-is.
+fn transliterate_cyrillic(name: &str) -> String {
+    let lowercase = name.to_lowercase();
+    let ukrainian = lowercase.contains(['і', 'ї', 'є', 'ґ']);
+
+    let mut latin = String::with_capacity(lowercase.len());
+    for letter in lowercase.chars() {
+        let s: &str = match letter {
+            'а' => "a",
+            'б' => "b",
+            'в' => "v",
+            'г' if ukrainian => "h",
+            'г' | 'ґ' => "g",
+            'д' => "d",
+            'е' | 'ё' | 'э' => "e",
+            'є' => "ye",
+            'ж' => "zh",
+            'з' => "z",
+            'и' if ukrainian => "y",
+            'и' | 'і' => "i",
+            'ї' => "yi",
+            'й' | 'ы' => "y",
+            'к' => "k",
+            'л' => "l",
+            'м' => "m",
+            'н' => "n",
+            'о' => "o",
+            'п' => "p",
+            'р' => "r",
+            'с' => "s",
+            'т' => "t",
+            'у' => "u",
+            'ф' => "f",
+            'х' => "kh",
+            'ц' => "ts",
+            'ч' => "ch",
+            'ш' => "sh",
+            'щ' => "shch",
+            'ъ' | 'ь' => "",
+            'ю' => "yu",
+            'я' => "ya",
+            _ => {
+                latin.push(letter);
+                continue;
+            }
+        };
+        latin.push_str(s);
+    }
+    latin
+}
+
+/// Encodes a Japanese name as the numeric "ea-" identifier used in usernames.
+fn east_asian_id(name: &str) -> String {
+    let ea_id: String = name
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(hira_to_kata_char)
+        .map(|c| (c as u32).to_string())
+        .collect();
+    format!("ea-{}", ea_id)
+}
+
+/// Normalizes a search query to compare against usernames.
+///
+/// The query is normalized the same way as a Name when creating a Username,
+/// so "José Pérez" and "jose perez" both become "joseperez".
+/// Characters without an ASCII lookalike are kept, rather than rejected.
+///
+/// # Examples
+///
+/// ```
+/// # use opltypes::search_normalize;
+/// assert_eq!(search_normalize("José Pérez"), "joseperez");
+/// ```
+pub fn search_normalize(query: &str) -> String {
+    if infer_writing_system(query) == WritingSystem::Japanese {
+        return east_asian_id(query);
+    }
+
+    let mut normalized = String::with_capacity(query.len());
+    for letter in query.to_lowercase().chars() {
+        if is_exception(letter) {
+            continue;
+        }
+        if letter.is_ascii_alphanumeric() {
+            normalized.push(letter);
+        } else if let Some(s) = ascii_lookalike(letter) {
+            normalized.push_str(s);
+        } else if !letter.is_whitespace() {
+            normalized.push(letter);
+        }
+    }
+    normalized
+}
+
 /// Whether the character should be silently omitted.
+///
+/// Besides the ASCII apostrophe and hyphen, this includes the curly quotes,
+/// modifier letter apostrophe, and Unicode dashes that word processors
+/// substitute for them.
 fn is_exception(letter: char) -> bool {
-    matches!(letter, ' ' | '\\' | '#' | '.' | '-' | '\'')
+    matches!(
+        letter,
+        ' ' | '\\'
+            | '#'
+            | '.'
+            | '-'
+            | '\''
+            | '\u{2018}' // Left single quotation mark.
+            | '\u{2019}' // Right single quotation mark.
+            | '\u{02bc}' // Modifier letter apostrophe.
+            | '\u{2010}' // Hyphen.
+            | '\u{2011}' // Non-breaking hyphen.
+            | '\u{2013}' // En dash.
+    )
 }
 
 const HIRAGANA_START: u32 = 0x3041;
@@ -315,6 +478,78 @@ mod tests {
         assert!(Username::from_name("松浦すぐる").is_ok());
     }
 
+    #[test]
+    fn search() {
+        assert_eq!(search_normalize("José"), search_normalize("jose"));
+        assert_eq!(search_normalize("José Pérez"), "joseperez");
+        assert_eq!(
+            search_normalize("Auðunn Jónsson"),
+            Username::from_name("Auðunn Jónsson").unwrap().as_str()
+        );
+
+        // Japanese names use the same numeric encoding as usernames.
+        assert_eq!(search_normalize("武田 裕介"), "ea-27494300003502920171");
+
+        // Other scripts without lookalikes pass through, lowercased.
+        assert_eq!(search_normalize("김 민수"), "김민수");
+        assert_eq!(search_normalize("Иван"), "иван");
+    }
+
+    #[test]
+    fn apostrophes_and_hyphens() {
+        let u = |name| Username::from_name(name).unwrap();
+        assert_eq!(u("O'Brien"), u("OBrien"));
+        assert_eq!(u("O\u{2019}Brien"), u("obrien"));
+        assert_eq!(u("O\u{2018}Brien"), u("O\u{02bc}BRIEN"));
+
+        assert_eq!(u("Anna Smith-Jones"), u("Anna SmithJones"));
+        assert_eq!(u("Anna Smith\u{2010}Jones"), u("anna smithjones"));
+        assert_eq!(u("Anna Smith\u{2011}Jones"), u("Anna Smith\u{2013}Jones"));
+
+        // Pin the exact output, since usernames are used in URLs.
+        assert_eq!(u("Mary O'Brien-Kelly").as_str(), "maryobrienkelly");
+        assert_eq!(u("Anna Smith\u{2010}Jones").as_str(), "annasmithjones");
+        assert_eq!(search_normalize("O\u{2019}Brien"), "obrien");
+    }
+
+    #[test]
+    fn disambiguation_api() {
+        let username = with_disambiguation("John Smith", 1).unwrap();
+        assert_eq!(username, "johnsmith1");
+        assert_eq!(parse_disambiguation(&username), ("johnsmith", Some(1)));
+
+        let username = with_disambiguation("John Smith", 12).unwrap();
+        assert_eq!(parse_disambiguation(&username), ("johnsmith", Some(12)));
+
+        assert_eq!(with_disambiguation("John Smith", 0).unwrap(), "johnsmith");
+        assert_eq!(parse_disambiguation("johnsmith"), ("johnsmith", None));
+        assert_eq!(parse_disambiguation(""), ("", None));
+
+        // East Asian usernames end in digits, but are never variants.
+        let ea = Username::from_name("武田 裕介").unwrap();
+        assert_eq!(parse_disambiguation(ea.as_str()), (ea.as_str(), None));
+    }
+
+    #[test]
+    fn cyrillic_transliteration() {
+        let u = |name| Username::from_name_transliterated(name).unwrap();
+
+        // Russian.
+        assert_eq!(u("Пётр").as_str(), "petr");
+        assert_eq!(u("Иван Петров").as_str(), "ivanpetrov");
+        assert_eq!(u("Александр Щербаков").as_str(), "aleksandrshcherbakov");
+        assert_eq!(u("Юлия Соловьёва").as_str(), "yuliyasoloveva");
+
+        // Ukrainian.
+        assert_eq!(u("Андрій Мельник").as_str(), "andriymelnyk");
+        assert_eq!(u("Ігор Гнатів").as_str(), "ihorhnativ");
+        assert_eq!(u("Євгенія Марʼїна").as_str(), "yevheniyamaryina");
+
+        // Latin names are unaffected, and the default stays strict.
+        assert_eq!(u("Ed Coan"), Username::from_name("Ed Coan").unwrap());
+        assert!(Username::from_name("Пётр").is_err());
+    }
+
     #[test]
     fn disambig() {
         assert_eq!(
@@ -9,10 +9,10 @@ use std::fmt::{self, Write};
 use std::num;
 use std::str::FromStr;
 
-use crate::{WeightAny, WeightKg, WeightUnits};
+use crate::{Date, Sex, WeightAny, WeightKg, WeightUnits};
 
 /// The definition of the "WeightClassKg" column.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum WeightClassKg {
     /// A class defined as being under or equal to a maximum weight.
     UnderOrEqual(WeightKg),
@@ -28,6 +28,185 @@ impl Default for WeightClassKg {
     }
 }
 
+/// A system of weight classes used by federations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClassSet {
+    /// The classes used by most non-IPF federations.
+    Traditional,
+    /// The traditional classes, with extra women's heavyweight classes.
+    Expanded,
+    /// The IPF classes in effect since 2019.
+    Ipf,
+    /// The IPF classes in effect from 2011 through 2018.
+    ///
+    /// These differ from the current classes only for women.
+    Ipf2011,
+    /// The classes used by World Para Powerlifting.
+    Para,
+    /// The classes used by World Powerlifting.
+    Wp,
+}
+
+const fn kg(raw: i32) -> WeightKg {
+    WeightKg::from_raw(raw)
+}
+
+const TRADITIONAL_MEN: [WeightKg; 11] = [
+    kg(52_00),
+    kg(56_00),
+    kg(60_00),
+    kg(67_50),
+    kg(75_00),
+    kg(82_50),
+    kg(90_00),
+    kg(100_00),
+    kg(110_00),
+    kg(125_00),
+    kg(140_00),
+];
+const TRADITIONAL_WOMEN: [WeightKg; 9] = [
+    kg(44_00),
+    kg(48_00),
+    kg(52_00),
+    kg(56_00),
+    kg(60_00),
+    kg(67_50),
+    kg(75_00),
+    kg(82_50),
+    kg(90_00),
+];
+const EXPANDED_WOMEN: [WeightKg; 11] = [
+    kg(44_00),
+    kg(48_00),
+    kg(52_00),
+    kg(56_00),
+    kg(60_00),
+    kg(67_50),
+    kg(75_00),
+    kg(82_50),
+    kg(90_00),
+    kg(100_00),
+    kg(110_00),
+];
+const IPF_MEN: [WeightKg; 8] = [
+    kg(53_00),
+    kg(59_00),
+    kg(66_00),
+    kg(74_00),
+    kg(83_00),
+    kg(93_00),
+    kg(105_00),
+    kg(120_00),
+];
+const IPF_WOMEN: [WeightKg; 8] = [
+    kg(43_00),
+    kg(47_00),
+    kg(52_00),
+    kg(57_00),
+    kg(63_00),
+    kg(69_00),
+    kg(76_00),
+    kg(84_00),
+];
+const IPF_2011_WOMEN: [WeightKg; 7] = [
+    kg(43_00),
+    kg(47_00),
+    kg(52_00),
+    kg(57_00),
+    kg(63_00),
+    kg(72_00),
+    kg(84_00),
+];
+const PARA_MEN: [WeightKg; 9] = [
+    kg(49_00),
+    kg(54_00),
+    kg(59_00),
+    kg(65_00),
+    kg(72_00),
+    kg(80_00),
+    kg(88_00),
+    kg(97_00),
+    kg(107_00),
+];
+const PARA_WOMEN: [WeightKg; 9] = [
+    kg(41_00),
+    kg(45_00),
+    kg(50_00),
+    kg(55_00),
+    kg(61_00),
+    kg(67_00),
+    kg(73_00),
+    kg(79_00),
+    kg(86_00),
+];
+const WP_MEN: [WeightKg; 7] = [
+    kg(62_00),
+    kg(69_00),
+    kg(77_00),
+    kg(85_00),
+    kg(94_00),
+    kg(105_00),
+    kg(120_00),
+];
+const WP_WOMEN: [WeightKg; 7] = [
+    kg(48_00),
+    kg(53_00),
+    kg(58_00),
+    kg(64_00),
+    kg(72_00),
+    kg(84_00),
+    kg(100_00),
+];
+
+/// The first day of the IPF classes, replacing the traditional classes.
+pub const IPF_2011_CLASSES_START: Date = Date::from_parts(2011, 1, 1);
+
+/// The first day of the current IPF classes, which split the women's 72 class.
+pub const IPF_2019_CLASSES_START: Date = Date::from_parts(2019, 1, 1);
+
+impl ClassSet {
+    /// Gets the classes an IPF meet used on a date.
+    ///
+    /// Meets on a cutover date use the new classes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::{ClassSet, Date, Sex};
+    /// let date = Date::from_parts(2010, 12, 31);
+    /// assert_eq!(ClassSet::ipf_on(date, Sex::M), ClassSet::Traditional);
+    /// ```
+    pub fn ipf_on(date: Date, sex: Sex) -> ClassSet {
+        if date < IPF_2011_CLASSES_START {
+            ClassSet::Traditional
+        } else if date < IPF_2019_CLASSES_START && sex == Sex::F {
+            ClassSet::Ipf2011
+        } else {
+            ClassSet::Ipf
+        }
+    }
+
+    /// Gets the maximum bodyweight of each class for a sex, in increasing order.
+    ///
+    /// The last class is followed by a superheavyweight class over its maximum.
+    /// Mx lifters use the men's classes.
+    pub fn upper_bounds(self, sex: Sex) -> &'static [WeightKg] {
+        let women = sex == Sex::F;
+        match (self, women) {
+            (ClassSet::Traditional, false) | (ClassSet::Expanded, false) => &TRADITIONAL_MEN,
+            (ClassSet::Traditional, true) => &TRADITIONAL_WOMEN,
+            (ClassSet::Expanded, true) => &EXPANDED_WOMEN,
+            (ClassSet::Ipf, false) | (ClassSet::Ipf2011, false) => &IPF_MEN,
+            (ClassSet::Ipf, true) => &IPF_WOMEN,
+            (ClassSet::Ipf2011, true) => &IPF_2011_WOMEN,
+            (ClassSet::Para, false) => &PARA_MEN,
+            (ClassSet::Para, true) => &PARA_WOMEN,
+            (ClassSet::Wp, false) => &WP_MEN,
+            (ClassSet::Wp, true) => &WP_WOMEN,
+        }
+    }
+}
+
 /// Displayable, unit-less variant of WeightClassKg.
 ///
 /// Becasue the type of the weight is forgotten, these weights
@@ -91,6 +270,32 @@ impl WeightClassKg {
         }
     }
 
+    /// Gets the class a bodyweight falls into under a system of classes.
+    ///
+    /// Each class includes its maximum, so 74.00kg is in the 74 class.
+    /// Bodyweights above the top class are in the superheavyweight class,
+    /// and a missing bodyweight has no class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::{ClassSet, Sex, WeightClassKg, WeightKg};
+    /// let bw = WeightKg::from_raw(73_90);
+    /// let class = WeightClassKg::from_weight(bw, Sex::M, ClassSet::Ipf);
+    /// assert_eq!(class, WeightClassKg::UnderOrEqual(WeightKg::from_i32(74)));
+    /// ```
+    pub fn from_weight(bodyweight: WeightKg, sex: Sex, class_set: ClassSet) -> WeightClassKg {
+        if bodyweight <= WeightKg::from_i32(0) {
+            return WeightClassKg::None;
+        }
+
+        let bounds = class_set.upper_bounds(sex);
+        match bounds.iter().find(|&&max| bodyweight <= max) {
+            Some(&max) => WeightClassKg::UnderOrEqual(max),
+            None => WeightClassKg::Over(bounds[bounds.len() - 1]),
+        }
+    }
+
     pub fn matches_bodyweight(self, bw: WeightKg) -> bool {
         match self {
             WeightClassKg::UnderOrEqual(cls) => bw <= cls,
@@ -289,4 +494,69 @@ mod tests {
         assert_kg_to_lbs("84", "185");
         assert_kg_to_lbs("84+", "185+");
     }
+
+    #[test]
+    fn from_weight() {
+        let class = |bw, sex, set| WeightClassKg::from_weight(WeightKg::from_raw(bw), sex, set);
+        let under = |kg| WeightClassKg::UnderOrEqual(WeightKg::from_raw(kg));
+        let over = |kg| WeightClassKg::Over(WeightKg::from_raw(kg));
+
+        // Classes include their maximum.
+        assert_eq!(class(73_90, Sex::M, ClassSet::Ipf), under(74_00));
+        assert_eq!(class(74_00, Sex::M, ClassSet::Ipf), under(74_00));
+        assert_eq!(class(74_01, Sex::M, ClassSet::Ipf), under(83_00));
+        assert_eq!(class(74_90, Sex::M, ClassSet::Traditional), under(75_00));
+        assert_eq!(class(40_00, Sex::F, ClassSet::Ipf), under(43_00));
+        assert_eq!(class(67_50, Sex::F, ClassSet::Traditional), under(67_50));
+
+        // Heavy lifters land in the superheavyweight class.
+        assert_eq!(class(160_00, Sex::M, ClassSet::Ipf), over(120_00));
+        assert_eq!(class(150_00, Sex::M, ClassSet::Traditional), over(140_00));
+        assert_eq!(class(95_00, Sex::F, ClassSet::Traditional), over(90_00));
+        assert_eq!(class(95_00, Sex::F, ClassSet::Expanded), under(100_00));
+        assert_eq!(class(90_00, Sex::F, ClassSet::Para), over(86_00));
+        assert_eq!(class(130_00, Sex::Mx, ClassSet::Wp), over(120_00));
+
+        // A missing bodyweight has no class.
+        assert_eq!(class(0, Sex::M, ClassSet::Ipf), WeightClassKg::None);
+    }
+
+    #[test]
+    fn ipf_on() {
+        let men = |y, m, d| ClassSet::ipf_on(Date::from_parts(y, m, d), Sex::M);
+        let women = |y, m, d| ClassSet::ipf_on(Date::from_parts(y, m, d), Sex::F);
+
+        assert_eq!(men(2010, 12, 31), ClassSet::Traditional);
+        assert_eq!(men(2011, 1, 1), ClassSet::Ipf);
+        assert_eq!(men(2015, 6, 1), ClassSet::Ipf);
+
+        assert_eq!(women(2010, 12, 31), ClassSet::Traditional);
+        assert_eq!(women(2011, 1, 1), ClassSet::Ipf2011);
+        assert_eq!(women(2018, 12, 31), ClassSet::Ipf2011);
+        assert_eq!(women(2019, 1, 1), ClassSet::Ipf);
+
+        // The same 80kg man is bucketed differently in each era.
+        let bw = WeightKg::from_i32(80);
+        let class = |set| WeightClassKg::from_weight(bw, Sex::M, set);
+        assert_eq!(
+            class(men(2009, 6, 1)),
+            WeightClassKg::UnderOrEqual(WeightKg::from_raw(82_50))
+        );
+        assert_eq!(
+            class(men(2012, 6, 1)),
+            WeightClassKg::UnderOrEqual(WeightKg::from_i32(83))
+        );
+
+        // A 70kg woman was in the 72 class until 2019.
+        let bw = WeightKg::from_i32(70);
+        let class = |set| WeightClassKg::from_weight(bw, Sex::F, set);
+        assert_eq!(
+            class(women(2018, 6, 1)),
+            WeightClassKg::UnderOrEqual(WeightKg::from_i32(72))
+        );
+        assert_eq!(
+            class(women(2019, 6, 1)),
+            WeightClassKg::UnderOrEqual(WeightKg::from_i32(76))
+        );
+    }
 }
@@ -13,7 +13,7 @@ use std::num;
 use std::ops;
 use std::str::FromStr;
 
-use crate::WeightUnits;
+use crate::{Sex, WeightUnits};
 
 /// Represents numbers describing absolute weights.
 ///
@@ -21,7 +21,7 @@ use crate::WeightUnits;
 /// Instead of storing as `f32`, we can store as `i32 * 100`,
 /// allowing the use of normal registers for what are effectively
 /// floating-point operations, and removing all `dtoa()` calls.
-#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq, Hash)]
 pub struct WeightKg(i32);
 
 /// Represents numbers describing absolute weights in their final
@@ -32,6 +32,29 @@ pub struct WeightKg(i32);
 #[derive(Copy, Clone, Debug)]
 pub struct WeightAny(i32);
 
+/// Bodyweights below this are flagged as implausible.
+pub const MIN_PLAUSIBLE_BODYWEIGHT_KG: WeightKg = WeightKg::from_i32(15);
+
+/// Bodyweights above this are flagged as implausible.
+pub const MAX_PLAUSIBLE_BODYWEIGHT_KG: WeightKg = WeightKg::from_i32(300);
+
+/// Squats above this weight are flagged for review.
+pub const PLAUSIBLE_SQUAT_KG: WeightKg = WeightKg::from_i32(500);
+
+/// Bench presses above this weight are flagged for review.
+pub const PLAUSIBLE_BENCH_KG: WeightKg = WeightKg::from_i32(400);
+
+/// Deadlifts above this weight are flagged for review.
+pub const PLAUSIBLE_DEADLIFT_KG: WeightKg = WeightKg::from_i32(460);
+
+/// One of the three competition lifts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Lift {
+    Squat,
+    Bench,
+    Deadlift,
+}
+
 impl Serialize for WeightKg {
     /// Serialize with two decimal places, exactly as in the original.
     ///
@@ -161,6 +184,65 @@ impl WeightKg {
         }
     }
 
+    /// Parses a weight in pounds, with an optional "lb" or "lbs" suffix.
+    ///
+    /// The pounds are converted at the exact factor of 0.45359237kg
+    /// and rounded to the nearest hundredth of a kilogram.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opltypes::WeightKg;
+    /// let w = WeightKg::from_lbs_str("198lb").unwrap();
+    /// assert_eq!(w, WeightKg::from_raw(89_81));
+    /// ```
+    pub fn from_lbs_str(s: &str) -> Result<WeightKg, num::ParseFloatError> {
+        let s = strip_lbs_suffix(s).unwrap_or(s).trim_end();
+        if s.is_empty() {
+            Ok(WeightKg(0))
+        } else {
+            Ok(WeightKg::from_f64(s.parse::<f64>()? * 0.45359237))
+        }
+    }
+
+    /// Parses a weight in kilograms, or in pounds if suffixed by "lb" or "lbs".
+    ///
+    /// Meant for importing source spreadsheets. The checker's canonical
+    /// format is kilograms without a suffix, parsed by `FromStr`.
+    pub fn from_str_relaxed(s: &str) -> Result<WeightKg, num::ParseFloatError> {
+        let s = s.trim();
+        if strip_lbs_suffix(s).is_some() {
+            WeightKg::from_lbs_str(s)
+        } else {
+            let kg = s.strip_suffix("kg").unwrap_or(s).trim_end();
+            kg.parse::<WeightKg>()
+        }
+    }
+
+    /// Whether the weight is a plausible bodyweight for the sex.
+    ///
+    /// The bounds are inclusive, and currently shared by all sexes.
+    pub fn is_plausible_bodyweight(self, sex: Sex) -> bool {
+        let (min, max) = match sex {
+            Sex::M | Sex::F | Sex::Mx => (MIN_PLAUSIBLE_BODYWEIGHT_KG, MAX_PLAUSIBLE_BODYWEIGHT_KG),
+        };
+        min <= self && self <= max
+    }
+
+    /// Whether the weight is plausible for the lift.
+    ///
+    /// Only a handful of lifters have ever exceeded these bounds, so larger
+    /// weights are more often typos, like a misplaced decimal point.
+    /// Failed attempts are negative, so the bar weight is compared.
+    pub fn is_plausible_lift(self, lift: Lift) -> bool {
+        let max = match lift {
+            Lift::Squat => PLAUSIBLE_SQUAT_KG,
+            Lift::Bench => PLAUSIBLE_BENCH_KG,
+            Lift::Deadlift => PLAUSIBLE_DEADLIFT_KG,
+        };
+        self.abs() <= max
+    }
+
     /// Whether the weight is negative, representing a failed lift.
     #[inline]
     pub fn is_failed(self) -> bool {
@@ -222,6 +304,11 @@ impl WeightKg {
     }
 }
 
+/// Removes a trailing pounds unit, if present.
+fn strip_lbs_suffix(s: &str) -> Option<&str> {
+    s.strip_suffix("lbs").or_else(|| s.strip_suffix("lb"))
+}
+
 impl fmt::Display for WeightKg {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         WeightAny(self.0).fmt(f)
@@ -425,6 +512,36 @@ mod tests {
         assert!(w.0 == -12346);
     }
 
+    #[test]
+    fn from_lbs_str() {
+        // 198lb is 89.811289kg, stored as 89.81.
+        let w = WeightKg::from_lbs_str("198lb").unwrap();
+        assert_eq!(w.0, 89_81);
+        assert_eq!(WeightKg::from_lbs_str("198lbs").unwrap(), w);
+        assert_eq!(WeightKg::from_lbs_str("198 lbs").unwrap(), w);
+        assert_eq!(WeightKg::from_lbs_str("198").unwrap(), w);
+        assert_eq!(w.as_lbs().0, 198_00);
+
+        // 100lb is 45.359237kg, which rounds up to 45.36.
+        assert_eq!(WeightKg::from_lbs_str("100lb").unwrap().0, 45_36);
+        assert_eq!(WeightKg::from_lbs_str("-100lb").unwrap().0, -45_36);
+        assert_eq!(WeightKg::from_lbs_str("").unwrap().0, 0);
+        assert!(WeightKg::from_lbs_str("198kg").is_err());
+    }
+
+    #[test]
+    fn from_str_relaxed() {
+        let lbs = WeightKg::from_lbs_str("198").unwrap();
+        assert_eq!(WeightKg::from_str_relaxed("198lb").unwrap(), lbs);
+        assert_eq!(WeightKg::from_str_relaxed(" 198 lbs ").unwrap(), lbs);
+        assert_eq!(WeightKg::from_str_relaxed("90kg").unwrap().0, 90_00);
+        assert_eq!(WeightKg::from_str_relaxed("90").unwrap().0, 90_00);
+
+        // The strict parser still rejects suffixes.
+        assert!("198lb".parse::<WeightKg>().is_err());
+        assert!("90kg".parse::<WeightKg>().is_err());
+    }
+
     /// Some results that are initially reported in LBS wind
     /// up giving slightly-under Kg values.
     #[test]
@@ -512,4 +629,33 @@ mod tests {
         let w2 = "200".parse::<WeightKg>().unwrap();
         assert!(w1 < w2);
     }
+
+    #[test]
+    fn plausible_bodyweight() {
+        for &sex in &[Sex::M, Sex::F, Sex::Mx] {
+            assert!(MIN_PLAUSIBLE_BODYWEIGHT_KG.is_plausible_bodyweight(sex));
+            assert!(MAX_PLAUSIBLE_BODYWEIGHT_KG.is_plausible_bodyweight(sex));
+            assert!(!WeightKg::from_raw(14_99).is_plausible_bodyweight(sex));
+            assert!(!WeightKg::from_raw(300_01).is_plausible_bodyweight(sex));
+        }
+    }
+
+    #[test]
+    fn plausible_lift() {
+        let bounds = [
+            (Lift::Squat, PLAUSIBLE_SQUAT_KG),
+            (Lift::Bench, PLAUSIBLE_BENCH_KG),
+            (Lift::Deadlift, PLAUSIBLE_DEADLIFT_KG),
+        ];
+        for &(lift, max) in &bounds {
+            let over = max + WeightKg::from_raw(1);
+            assert!(max.is_plausible_lift(lift));
+            assert!(!over.is_plausible_lift(lift));
+
+            // Failed attempts compare the bar weight.
+            let zero = WeightKg::from_i32(0);
+            assert!((zero - max).is_plausible_lift(lift));
+            assert!(!(zero - over).is_plausible_lift(lift));
+        }
+    }
 }
@@ -42,20 +42,29 @@ let meetErrorPre: HTMLElement;
 let entriesErrorPre: HTMLElement;
 
 // The checker::Message type is an object that distinguishes between
-// "Error" type messages and "Warning" type messages.
+// "Error", "Warning", and "Info" type messages.
 //
 // It's defined in Rust, in checker/src/lib.rs.
+interface MessageBody {
+  text: string;
+  fixable: boolean;
+};
+
 interface Message {
-  Error?: string;
-  Warning?: string;
+  Error?: MessageBody;
+  Warning?: MessageBody;
+  Info?: MessageBody;
 };
 
 // Converts a Message object to a simple, uncolored string, for the moment.
 function msg2str(msg: Message): string {
     if (msg.hasOwnProperty("Error")) {
-        return "Error: " + msg["Error"];
+        return "Error: " + msg["Error"].text;
+    }
+    if (msg.hasOwnProperty("Info")) {
+        return "Info: " + msg["Info"].text;
     }
-    return "Warning: " + msg["Warning"];
+    return "Warning: " + msg["Warning"].text;
 }
 
 function runChecker(): void {
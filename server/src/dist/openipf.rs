@@ -54,8 +54,13 @@ fn default_openipf_rankings_query() -> opldb::query::direct::RankingsQuery {
             year: YearFilter::AllYears,
             event: EventFilter::FullPower,
             state: None,
+            tested: TestedFilter::AllEntries,
+            min_bodyweight_kg: None,
         },
         order_by: OrderBy::Goodlift,
+        tie_breaks: None,
+        offset: 0,
+        limit: 0,
     }
 }
 
@@ -16,41 +16,20 @@ pub struct RankingsSlice<'db> {
     pub rows: Vec<JsEntryRow<'db>>,
 }
 
-pub fn query_slice<'db>(
+/// Gets the page of rows selected by the query, along with the total length
+/// of its ranking.
+pub fn query_page<'db>(
     opldb: &'db OplDb,
     locale: &'db Locale,
     selection: &RankingsQuery,
     defaults: &RankingsQuery,
-    start_row: usize, // Inclusive.
-    end_row: usize,   // Inclusive. Can be out-of-bounds.
 ) -> RankingsSlice<'db> {
-    const ROW_LIMIT: usize = 100;
-    let mut end_row = end_row;
-
     // TODO: Use a better algorithm, don't generate everything.
     let list = algorithms::full_sorted_uniqued(selection, opldb);
     let total_length = list.0.len();
 
-    // Limit the request size to something sane.
-    // Arithmetic can't overflow since it's already been compared to total_length.
-    if end_row - start_row + 1 > ROW_LIMIT {
-        end_row = start_row + (ROW_LIMIT - 1);
-    }
-
-    // The request must be in-bounds.
-    if end_row >= total_length {
-        if total_length > 0 {
-            end_row = total_length - 1;
-        } else {
-            end_row = 0;
-        }
-    }
-    if (start_row > end_row) || (start_row >= total_length) {
-        return RankingsSlice {
-            total_length,
-            rows: vec![],
-        };
-    }
+    // The full list is sorted before slicing, so pages are stable.
+    let range = selection.page_range(total_length);
 
     // Figure out the points system to be used.
     let points_system = if selection.order_by.is_by_points() {
@@ -60,11 +39,32 @@ pub fn query_slice<'db>(
         PointsSystem::from(defaults.order_by)
     };
 
-    let rows: Vec<JsEntryRow> = list.0[start_row..=end_row]
+    let rows: Vec<JsEntryRow> = list.0[range.clone()]
         .iter()
-        .zip(start_row..)
+        .zip(range.start..)
         .map(|(&n, i)| JsEntryRow::from(opldb, locale, opldb.entry(n), i as u32, points_system))
         .collect();
 
     RankingsSlice { total_length, rows }
 }
+
+pub fn query_slice<'db>(
+    opldb: &'db OplDb,
+    locale: &'db Locale,
+    selection: &RankingsQuery,
+    defaults: &RankingsQuery,
+    start_row: usize, // Inclusive.
+    end_row: usize,   // Inclusive. Can be out-of-bounds.
+) -> RankingsSlice<'db> {
+    if start_row > end_row {
+        let total_length = algorithms::full_sorted_uniqued(selection, opldb).0.len();
+        return RankingsSlice {
+            total_length,
+            rows: vec![],
+        };
+    }
+
+    let limit = (end_row - start_row).saturating_add(1);
+    let selection = selection.with_page(start_row, limit);
+    query_page(opldb, locale, &selection, defaults)
+}
@@ -0,0 +1,260 @@
+//! Logic for comparing two lifters side by side.
+
+use langpack::{localized_name, Language, Locale, LocalizeNumber};
+use opldb::{self, Entry, Meet};
+use opltypes::*;
+
+use crate::pages::lifter::PersonalRecords;
+
+/// The best results of a lifter, across all their non-disqualified entries.
+///
+/// These are the lifter's `PersonalRecords`, without the meets they were set at.
+/// Metrics without any qualifying entry are `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LifterBests {
+    pub squat: Option<WeightKg>,
+    pub bench: Option<WeightKg>,
+    pub deadlift: Option<WeightKg>,
+    pub total: Option<WeightKg>,
+    pub wilks: Option<Points>,
+    pub dots: Option<Points>,
+}
+
+impl LifterBests {
+    pub fn from_entries(meets: &[Meet], entries: &[&Entry]) -> LifterBests {
+        let records =
+            |system| PersonalRecords::from_entries(meets, entries, system, WeightUnits::Kg);
+        let wilks = records(PointsSystem::Wilks);
+        let dots = records(PointsSystem::Dots);
+
+        LifterBests {
+            squat: wilks.squat.map(|r| r.value),
+            bench: wilks.bench.map(|r| r.value),
+            deadlift: wilks.deadlift.map(|r| r.value),
+            total: wilks.total.map(|r| r.value),
+            wilks: wilks.points.map(|r| r.value),
+            dots: dots.points.map(|r| r.value),
+        }
+    }
+}
+
+/// Which of the two compared lifters has the better result.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum Winner {
+    First,
+    Second,
+    Tie,
+}
+
+/// Decides the winner of a metric.
+///
+/// A lifter with a result beats a lifter without one.
+/// If neither lifter has a result, there is no winner.
+fn winner<T: Ord>(first: Option<T>, second: Option<T>) -> Option<Winner> {
+    match (first, second) {
+        (None, None) => None,
+        (Some(_), None) => Some(Winner::First),
+        (None, Some(_)) => Some(Winner::Second),
+        (Some(a), Some(b)) => Some(match a.cmp(&b) {
+            std::cmp::Ordering::Greater => Winner::First,
+            std::cmp::Ordering::Less => Winner::Second,
+            std::cmp::Ordering::Equal => Winner::Tie,
+        }),
+    }
+}
+
+/// The winner of each metric.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+pub struct Winners {
+    pub squat: Option<Winner>,
+    pub bench: Option<Winner>,
+    pub deadlift: Option<Winner>,
+    pub total: Option<Winner>,
+    pub wilks: Option<Winner>,
+    pub dots: Option<Winner>,
+}
+
+impl Winners {
+    pub fn from(first: &LifterBests, second: &LifterBests) -> Winners {
+        Winners {
+            squat: winner(first.squat, second.squat),
+            bench: winner(first.bench, second.bench),
+            deadlift: winner(first.deadlift, second.deadlift),
+            total: winner(first.total, second.total),
+            wilks: winner(first.wilks, second.wilks),
+            dots: winner(first.dots, second.dots),
+        }
+    }
+}
+
+/// One lifter's side of the comparison.
+#[derive(Serialize)]
+pub struct ComparedLifter<'a> {
+    pub localized_name: &'a str,
+    pub lifter: &'a opldb::Lifter,
+    pub squat: Option<langpack::LocalizedWeightAny>,
+    pub bench: Option<langpack::LocalizedWeightAny>,
+    pub deadlift: Option<langpack::LocalizedWeightAny>,
+    pub total: Option<langpack::LocalizedWeightAny>,
+    pub wilks: Option<langpack::LocalizedPoints>,
+    pub dots: Option<langpack::LocalizedPoints>,
+}
+
+impl<'a> ComparedLifter<'a> {
+    fn new(locale: &'a Locale, lifter: &'a opldb::Lifter, bests: &LifterBests) -> Self {
+        let units = locale.units;
+        let format = locale.number_format;
+
+        ComparedLifter {
+            localized_name: localized_name(lifter, locale.language),
+            lifter,
+            squat: bests.squat.map(|kg| kg.as_type(units).in_format(format)),
+            bench: bests.bench.map(|kg| kg.as_type(units).in_format(format)),
+            deadlift: bests.deadlift.map(|kg| kg.as_type(units).in_format(format)),
+            total: bests.total.map(|kg| kg.as_type(units).in_format(format)),
+            wilks: bests.wilks.map(|pt| pt.in_format(format)),
+            dots: bests.dots.map(|pt| pt.in_format(format)),
+        }
+    }
+}
+
+/// The context object for comparing two lifters.
+#[derive(Serialize)]
+pub struct Context<'a> {
+    pub urlprefix: &'static str,
+    pub page_title: String,
+    pub page_description: &'a str,
+    pub language: Language,
+    pub strings: &'a langpack::Translations,
+    pub units: WeightUnits,
+
+    pub first: ComparedLifter<'a>,
+    pub second: ComparedLifter<'a>,
+    pub winners: Winners,
+}
+
+impl<'a> Context<'a> {
+    /// Compares two lifters by username.
+    ///
+    /// Returns `None` if either username is unknown.
+    pub fn new(
+        opldb: &'a opldb::OplDb,
+        locale: &'a Locale,
+        first_username: &str,
+        second_username: &str,
+        entry_filter: Option<fn(&'a opldb::OplDb, &'a Entry) -> bool>, /* For use by
+                                                                        * distributions.
+                                                                        */
+    ) -> Option<Context<'a>> {
+        // Load each lifter the way the lifter page does.
+        let load = |username: &str| {
+            let lifter_id = opldb.lifter_id(username)?;
+            let mut entries = opldb.entries_for_lifter(lifter_id);
+            if let Some(f) = entry_filter {
+                entries.retain(|e| f(opldb, e));
+            }
+            let bests = LifterBests::from_entries(opldb.meets(), &entries);
+            Some((opldb.lifter(lifter_id), bests))
+        };
+
+        let (first_lifter, first_bests) = load(first_username)?;
+        let (second_lifter, second_bests) = load(second_username)?;
+
+        let first = ComparedLifter::new(locale, first_lifter, &first_bests);
+        let second = ComparedLifter::new(locale, second_lifter, &second_bests);
+
+        Some(Context {
+            urlprefix: "/",
+            page_title: format!("{} vs. {}", first.localized_name, second.localized_name),
+            page_description: &locale.strings.html_header.description,
+            language: locale.language,
+            strings: locale.strings,
+            units: locale.units,
+            first,
+            second,
+            winners: Winners::from(&first_bests, &second_bests),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::num::NonZeroU8;
+
+    fn meets() -> Vec<Meet> {
+        vec![Meet {
+            path: "wrpf/a".into(),
+            federation: Federation::WRPF,
+            date: Date::from_parts(2020, 6, 1),
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 0,
+        }]
+    }
+
+    fn entry(squat: i32, bench: i32, deadlift: i32, place: Place) -> Entry {
+        Entry {
+            best3squatkg: WeightKg::from_i32(squat),
+            best3benchkg: WeightKg::from_i32(bench),
+            best3deadliftkg: WeightKg::from_i32(deadlift),
+            totalkg: WeightKg::from_i32(squat + bench + deadlift),
+            wilks: Points::from_i32((squat + bench + deadlift) / 2),
+            place,
+            ..Entry::default()
+        }
+    }
+
+    #[test]
+    fn test_winners() {
+        let meets = meets();
+        let first_place = Place::P(NonZeroU8::new(1).unwrap());
+
+        // The first lifter squats more, but the second pulls more.
+        // The second lifter's huge squat was disqualified.
+        let first = [
+            entry(250, 150, 250, first_place),
+            entry(240, 160, 240, first_place),
+        ];
+        let second = [
+            entry(200, 160, 300, first_place),
+            entry(400, 0, 0, Place::DQ),
+        ];
+
+        let first_bests = LifterBests::from_entries(&meets, &first.iter().collect::<Vec<_>>());
+        let second_bests = LifterBests::from_entries(&meets, &second.iter().collect::<Vec<_>>());
+        assert_eq!(first_bests.squat, Some(WeightKg::from_i32(250)));
+        assert_eq!(second_bests.squat, Some(WeightKg::from_i32(200)));
+
+        let winners = Winners::from(&first_bests, &second_bests);
+        assert_eq!(winners.squat, Some(Winner::First));
+        assert_eq!(winners.bench, Some(Winner::Tie));
+        assert_eq!(winners.deadlift, Some(Winner::Second));
+        assert_eq!(winners.total, Some(Winner::Second));
+        assert_eq!(winners.wilks, Some(Winner::Second));
+
+        // Neither lifter has any DOTS.
+        assert_eq!(winners.dots, None);
+    }
+
+    #[test]
+    fn test_lifter_without_entries() {
+        let meets = meets();
+        let first = [entry(250, 150, 250, Place::P(NonZeroU8::new(1).unwrap()))];
+        let second = [entry(300, 200, 300, Place::DQ)];
+
+        let first_bests = LifterBests::from_entries(&meets, &first.iter().collect::<Vec<_>>());
+        let second_bests = LifterBests::from_entries(&meets, &second.iter().collect::<Vec<_>>());
+        assert_eq!(second_bests, LifterBests::default());
+        assert_eq!(second_bests.total, None);
+
+        let winners = Winners::from(&first_bests, &second_bests);
+        assert_eq!(winners.squat, Some(Winner::First));
+        assert_eq!(winners.total, Some(Winner::First));
+        assert_eq!(winners.dots, None);
+    }
+}
@@ -1,6 +1,8 @@
 //! Logic for the data page.
 
 use langpack::{Language, Locale};
+use opldb::{Entry, Meet, OplDb};
+use opltypes::Date;
 
 /// The context object passed to `templates/data.html.tera`
 #[derive(Serialize)]
@@ -25,3 +27,78 @@ impl<'a> Context<'a> {
         }
     }
 }
+
+/// Aggregate statistics describing the extent of the dataset.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+pub struct Coverage {
+    /// The dates of the earliest and latest meets, or `None` if there are no meets.
+    pub span: Option<(Date, Date)>,
+    pub num_meets: usize,
+    pub num_entries: usize,
+}
+
+/// Calculates the date span and size of the dataset in a single pass.
+pub fn coverage(meets: &[Meet], entries: &[Entry]) -> Coverage {
+    let span = meets.iter().fold(None, |span, meet| match span {
+        None => Some((meet.date, meet.date)),
+        Some((earliest, latest)) => Some((meet.date.min(earliest), meet.date.max(latest))),
+    });
+
+    Coverage {
+        span,
+        num_meets: meets.len(),
+        num_entries: entries.len(),
+    }
+}
+
+/// Calculates the date span and size of the whole database.
+pub fn dataset_coverage(opldb: &OplDb) -> Coverage {
+    coverage(opldb.meets(), opldb.entries())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opltypes::*;
+
+    fn meet(date: Date) -> Meet {
+        Meet {
+            path: "test/1".into(),
+            federation: Federation::WRPF,
+            date,
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 0,
+        }
+    }
+
+    #[test]
+    fn test_coverage() {
+        let meets = [
+            meet(Date::from_parts(2016, 8, 19)),
+            meet(Date::from_parts(1964, 4, 1)),
+            meet(Date::from_parts(2021, 1, 30)),
+            meet(Date::from_parts(1999, 12, 31)),
+        ];
+        let entries = [Entry::default(), Entry::default(), Entry::default()];
+
+        let c = coverage(&meets, &entries);
+        assert_eq!(
+            c.span,
+            Some((Date::from_parts(1964, 4, 1), Date::from_parts(2021, 1, 30)))
+        );
+        assert_eq!(c.num_meets, 4);
+        assert_eq!(c.num_entries, 3);
+    }
+
+    #[test]
+    fn test_coverage_empty() {
+        let c = coverage(&[], &[]);
+        assert_eq!(c.span, None);
+        assert_eq!(c.num_meets, 0);
+        assert_eq!(c.num_entries, 0);
+    }
+}
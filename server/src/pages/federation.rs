@@ -0,0 +1,108 @@
+//! Logic for a federation's landing page, listing all of its meets.
+
+use langpack::{Language, Locale};
+use opldb::{self, Meet};
+use opltypes::*;
+
+use crate::pages::meetlist::MeetInfo;
+
+/// Gets a federation's meets, latest first.
+///
+/// Meets on the same date are ordered by MeetPath, so the list is stable.
+fn sorted_meets(meets: &[Meet], federation: Federation) -> Vec<&Meet> {
+    let mut meets: Vec<&Meet> = meets
+        .iter()
+        .filter(|m| m.federation == federation)
+        .collect();
+    meets.sort_unstable_by(|a, b| a.date.cmp(&b.date).reverse().then(a.path.cmp(&b.path)));
+    meets
+}
+
+/// The context object for a federation's landing page.
+#[derive(Serialize)]
+pub struct FederationContext<'db> {
+    pub urlprefix: &'static str,
+    pub page_title: String,
+    pub page_description: &'db str,
+    pub language: Language,
+    pub strings: &'db langpack::Translations,
+    pub units: WeightUnits,
+
+    pub federation: Federation,
+    pub meets: Vec<MeetInfo<'db>>,
+}
+
+impl<'db> FederationContext<'db> {
+    pub fn new(
+        opldb: &'db opldb::OplDb,
+        locale: &'db Locale,
+        federation: Federation,
+    ) -> FederationContext<'db> {
+        Self::from_meets(opldb.meets(), locale, federation)
+    }
+
+    /// Builds the context from a list of meets of any federation.
+    ///
+    /// A federation without any meets gets an empty list.
+    pub fn from_meets(
+        meets: &'db [Meet],
+        locale: &'db Locale,
+        federation: Federation,
+    ) -> FederationContext<'db> {
+        FederationContext {
+            urlprefix: "/",
+            page_title: format!("{} {}", federation, locale.strings.page_titles.meets),
+            page_description: &locale.strings.html_header.description,
+            language: locale.language,
+            strings: locale.strings,
+            units: locale.units,
+            federation,
+            meets: sorted_meets(meets, federation)
+                .into_iter()
+                .map(|m| MeetInfo::from(m, locale.strings))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use langpack::LangInfo;
+
+    fn meet(federation: Federation, path: &str, date: Date) -> Meet {
+        Meet {
+            path: path.into(),
+            federation,
+            date,
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 10,
+        }
+    }
+
+    #[test]
+    fn test_meets_latest_first() {
+        let meets = [
+            meet(Federation::WRPF, "wrpf/b", Date::from_parts(2020, 5, 1)),
+            meet(Federation::WRPF, "wrpf/c", Date::from_parts(2021, 1, 1)),
+            meet(Federation::USAPL, "usapl/a", Date::from_parts(2022, 1, 1)),
+            meet(Federation::WRPF, "wrpf/a", Date::from_parts(2020, 5, 1)),
+        ];
+
+        let langinfo = LangInfo::default();
+        let locale = Locale::new(&langinfo, Language::en, WeightUnits::Kg);
+        let context = FederationContext::from_meets(&meets, &locale, Federation::WRPF);
+
+        let paths: Vec<&str> = context.meets.iter().map(|m| m.path).collect();
+        assert_eq!(paths, vec!["wrpf/c", "wrpf/a", "wrpf/b"]);
+        assert_eq!(context.meets[0].date, "2021-01-01");
+        assert_eq!(context.meets[0].num_lifters, 10);
+
+        let context = FederationContext::from_meets(&meets, &locale, Federation::USPA);
+        assert!(context.meets.is_empty());
+    }
+}
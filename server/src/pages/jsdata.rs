@@ -44,6 +44,19 @@ pub struct JsEntryRow<'db> {
     /// Any kind of points: Wilks, McCulloch, etc.
     /// Only one points system is used at a time.
     pub points: langpack::LocalizedPoints,
+
+    /// The Dots, regardless of the selected points system.
+    pub dots: Option<langpack::LocalizedPoints>,
+    /// The Glossbrenner, regardless of the selected points system.
+    pub glossbrenner: Option<langpack::LocalizedPoints>,
+}
+
+/// Gets an entry's points, or `None` if it lacks the bodyweight or total.
+pub fn optional_points(entry: &Entry, system: PointsSystem, units: WeightUnits) -> Option<Points> {
+    if entry.bodyweightkg.is_zero() || entry.totalkg.is_zero() {
+        return None;
+    }
+    Some(entry.points(system, units))
 }
 
 /// Serialize to a compact but definitely less-helpful format
@@ -82,6 +95,8 @@ impl<'db> Serialize for JsEntryRow<'db> {
         seq.serialize_element(&self.deadlift)?;
         seq.serialize_element(&self.total)?;
         seq.serialize_element(&self.points)?;
+        seq.serialize_element(&self.dots)?;
+        seq.serialize_element(&self.glossbrenner)?;
 
         seq.end()
     }
@@ -143,6 +158,45 @@ impl<'db> JsEntryRow<'db> {
                 .in_format(number_format),
             total: entry.totalkg.as_type(units).in_format(number_format),
             points: entry.points(points_system, units).in_format(number_format),
+            dots: optional_points(entry, PointsSystem::Dots, units)
+                .map(|p| p.in_format(number_format)),
+            glossbrenner: optional_points(entry, PointsSystem::Glossbrenner, units)
+                .map(|p| p.in_format(number_format)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optional_points() {
+        let entry = Entry {
+            sex: Sex::M,
+            bodyweightkg: WeightKg::from_i32(90),
+            totalkg: WeightKg::from_i32(600),
+            dots: Points::from_i32(390),
+            glossbrenner: Points::from_i32(370),
+            ..Entry::default()
+        };
+        let dots = optional_points(&entry, PointsSystem::Dots, WeightUnits::Kg);
+        let glossbrenner = optional_points(&entry, PointsSystem::Glossbrenner, WeightUnits::Kg);
+        assert_eq!(dots, Some(Points::from_i32(390)));
+        assert_eq!(glossbrenner, Some(Points::from_i32(370)));
+
+        // Without a bodyweight, the coefficients can't be calculated.
+        let entry = Entry {
+            bodyweightkg: WeightKg::from_i32(0),
+            ..entry
+        };
+        assert_eq!(
+            optional_points(&entry, PointsSystem::Dots, WeightUnits::Kg),
+            None
+        );
+        assert_eq!(
+            optional_points(&entry, PointsSystem::Glossbrenner, WeightUnits::Kg),
+            None
+        );
+    }
+}
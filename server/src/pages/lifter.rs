@@ -1,7 +1,7 @@
 //! Logic for each lifter's personal page.
 
 use langpack::{localized_name, Language, Locale, LocalizeNumber};
-use opldb::{self, Entry};
+use opldb::{self, Entry, Meet};
 use opltypes::*;
 
 use crate::pages::meet::points_column_title; // FIXME: This should not be defined there.
@@ -24,6 +24,121 @@ pub struct Context<'a> {
 
     pub bests: Vec<PersonalBestsRow<'a>>,
     pub meet_results: Vec<MeetResultsRow<'a>>,
+    pub timeline: Vec<TimelinePoint>,
+}
+
+/// A point in the lifter's progression over time, for charting.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+pub struct TimelinePoint {
+    pub date: Date,
+    /// The best total up to and including this entry.
+    pub best_total: WeightKg,
+    /// The best points up to and including this entry.
+    pub best_points: Points,
+}
+
+/// Calculates the progression of a lifter's best total and points, oldest first.
+///
+/// Each entry gets its own point, even if several share a date.
+/// Entries on the same date are ordered by MeetPath.
+pub fn timeline(
+    meets: &[Meet],
+    entries: &[&Entry],
+    points_system: PointsSystem,
+    units: WeightUnits,
+) -> Vec<TimelinePoint> {
+    let meet = |e: &Entry| &meets[e.meet_id as usize];
+
+    let mut sorted: Vec<&Entry> = entries.to_vec();
+    sorted.sort_by(|a, b| {
+        let (a, b) = (meet(a), meet(b));
+        a.date.cmp(&b.date).then(a.path.cmp(&b.path))
+    });
+
+    let mut best_total = WeightKg::from_i32(0);
+    let mut best_points = Points::from_i32(0);
+    sorted
+        .into_iter()
+        .map(|e| {
+            best_total = best_total.max(e.totalkg);
+            best_points = best_points.max(e.points(points_system, units));
+            TimelinePoint {
+                date: meet(e).date,
+                best_total,
+                best_points,
+            }
+        })
+        .collect()
+}
+
+/// A personal record, and the meet where it was first set.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PersonalRecord<T> {
+    pub value: T,
+    pub meet_id: u32,
+}
+
+/// A lifter's all-time personal records.
+///
+/// Records without any successful lift are `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PersonalRecords {
+    pub squat: Option<PersonalRecord<WeightKg>>,
+    pub bench: Option<PersonalRecord<WeightKg>>,
+    pub deadlift: Option<PersonalRecord<WeightKg>>,
+    pub total: Option<PersonalRecord<WeightKg>>,
+    pub points: Option<PersonalRecord<Points>>,
+}
+
+impl PersonalRecords {
+    /// Finds the best successful result of each kind across a lifter's entries.
+    ///
+    /// Disqualified entries are ignored, and failed attempts never count.
+    /// If a record was matched later, the earliest meet keeps it.
+    pub fn from_entries(
+        meets: &[Meet],
+        entries: &[&Entry],
+        points_system: PointsSystem,
+        units: WeightUnits,
+    ) -> PersonalRecords {
+        let record = |value: &dyn Fn(&Entry) -> WeightKg| best_with_meet(meets, entries, value);
+
+        PersonalRecords {
+            squat: record(&|e| e.highest_squatkg()),
+            bench: record(&|e| e.highest_benchkg()),
+            deadlift: record(&|e| e.highest_deadliftkg()),
+            total: record(&|e| e.totalkg),
+            points: best_with_meet(meets, entries, |e| e.points(points_system, units)),
+        }
+    }
+}
+
+/// Gets the largest positive value across non-disqualified entries,
+/// with the earliest meet where it occurred.
+///
+/// Failed attempts are negative, so they can never be best.
+fn best_with_meet<T, F>(meets: &[Meet], entries: &[&Entry], value: F) -> Option<PersonalRecord<T>>
+where
+    T: Copy + Ord + Default,
+    F: Fn(&Entry) -> T,
+{
+    let meet = |e: &Entry| &meets[e.meet_id as usize];
+
+    entries
+        .iter()
+        .filter(|e| !e.place.is_dq())
+        .map(|e| (value(e), e))
+        .filter(|(v, _)| *v > T::default())
+        .min_by(|(a, ea), (b, eb)| {
+            let (ma, mb) = (meet(ea), meet(eb));
+            b.cmp(a)
+                .then(ma.date.cmp(&mb.date))
+                .then(ma.path.cmp(&mb.path))
+        })
+        .map(|(value, e)| PersonalRecord {
+            value,
+            meet_id: e.meet_id,
+        })
 }
 
 /// A row in the Best Lifts table.
@@ -442,6 +557,7 @@ impl<'a> Context<'a> {
         };
 
         let bests = calculate_bests(locale, points_system, &entries);
+        let timeline = timeline(opldb.meets(), &entries, points_system, locale.units);
 
         // Determine if any of the entries have attempt information.
         // If a federation only reports Bests, we don't want lots of empty columns.
@@ -482,6 +598,194 @@ impl<'a> Context<'a> {
             points_column_title: points_column_title(points_system, locale, points_system),
             bests,
             meet_results,
+            timeline,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meet(path: &str, date: Date) -> Meet {
+        Meet {
+            path: path.into(),
+            federation: Federation::WRPF,
+            date,
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 0,
+        }
+    }
+
+    fn entry(meet_id: u32, total: i32) -> Entry {
+        Entry {
+            meet_id,
+            totalkg: WeightKg::from_i32(total),
+            wilks: Points::from_i32(total / 2),
+            ..Entry::default()
         }
     }
+
+    #[test]
+    fn test_timeline() {
+        let meets = [
+            meet("wrpf/c", Date::from_parts(2021, 6, 1)),
+            meet("wrpf/a", Date::from_parts(2019, 6, 1)),
+            meet("wrpf/b", Date::from_parts(2020, 6, 1)),
+        ];
+        let entries = [entry(0, 550), entry(1, 500), entry(2, 480)];
+        let entries: Vec<&Entry> = entries.iter().collect();
+
+        let points = timeline(&meets, &entries, PointsSystem::Wilks, WeightUnits::Kg);
+        let dates: Vec<Date> = points.iter().map(|p| p.date).collect();
+        assert_eq!(
+            dates,
+            vec![
+                Date::from_parts(2019, 6, 1),
+                Date::from_parts(2020, 6, 1),
+                Date::from_parts(2021, 6, 1),
+            ]
+        );
+
+        // The bad second meet keeps the earlier bests.
+        let totals: Vec<WeightKg> = points.iter().map(|p| p.best_total).collect();
+        assert_eq!(
+            totals,
+            vec![
+                WeightKg::from_i32(500),
+                WeightKg::from_i32(500),
+                WeightKg::from_i32(550),
+            ]
+        );
+        assert_eq!(points[1].best_points, Points::from_i32(250));
+        assert_eq!(points[2].best_points, Points::from_i32(275));
+    }
+
+    #[test]
+    fn test_timeline_same_date() {
+        let date = Date::from_parts(2020, 6, 1);
+        let meets = [meet("wrpf/b", date), meet("wrpf/a", date)];
+        let entries = [entry(0, 400), entry(1, 300)];
+
+        // The order of the input entries doesn't matter.
+        let forward: Vec<&Entry> = entries.iter().collect();
+        let backward: Vec<&Entry> = entries.iter().rev().collect();
+        let points = timeline(&meets, &forward, PointsSystem::Wilks, WeightUnits::Kg);
+        assert_eq!(
+            points,
+            timeline(&meets, &backward, PointsSystem::Wilks, WeightUnits::Kg)
+        );
+
+        // Both meets are kept, ordered by MeetPath.
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].best_total, WeightKg::from_i32(300));
+        assert_eq!(points[1].best_total, WeightKg::from_i32(400));
+    }
+
+    #[test]
+    fn test_personal_records() {
+        let meets = [
+            meet("wrpf/a", Date::from_parts(2019, 6, 1)),
+            meet("wrpf/b", Date::from_parts(2020, 6, 1)),
+            meet("wrpf/c", Date::from_parts(2021, 6, 1)),
+            meet("wrpf/d", Date::from_parts(2022, 6, 1)),
+        ];
+        let lifts = |meet_id, squat, bench, deadlift, place| {
+            let mut e = entry(meet_id, squat + bench + deadlift);
+            e.best3squatkg = WeightKg::from_i32(squat);
+            e.best3benchkg = WeightKg::from_i32(bench);
+            e.best3deadliftkg = WeightKg::from_i32(deadlift);
+            e.place = place;
+            e
+        };
+
+        let entries = [
+            // The oldest meet sets a bench record that is only matched later.
+            lifts(0, 200, 150, 250, Place::G),
+            lifts(1, 220, 150, 240, Place::G),
+            // A 4th attempt deadlift counts towards the record.
+            Entry {
+                deadlift4kg: WeightKg::from_i32(270),
+                ..lifts(2, 210, 140, 260, Place::G)
+            },
+            // The huge squat was disqualified.
+            lifts(3, 300, 0, 0, Place::DQ),
+        ];
+        let entries: Vec<&Entry> = entries.iter().collect();
+
+        let records =
+            PersonalRecords::from_entries(&meets, &entries, PointsSystem::Wilks, WeightUnits::Kg);
+        let record = |kg, meet_id| {
+            Some(PersonalRecord {
+                value: WeightKg::from_i32(kg),
+                meet_id,
+            })
+        };
+        assert_eq!(records.squat, record(220, 1));
+        assert_eq!(records.bench, record(150, 0));
+        assert_eq!(records.deadlift, record(270, 2));
+        assert_eq!(records.total, record(610, 1));
+        assert_eq!(
+            records.points,
+            Some(PersonalRecord {
+                value: Points::from_i32(305),
+                meet_id: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_personal_records_without_lifts() {
+        let meets = [meet("wrpf/a", Date::from_parts(2019, 6, 1))];
+
+        // A bench-only lifter whose opener failed.
+        let entries = [Entry {
+            best3benchkg: WeightKg::from_i32(150),
+            squat1kg: WeightKg::from_i32(-200),
+            place: Place::G,
+            ..entry(0, 150)
+        }];
+        let entries: Vec<&Entry> = entries.iter().collect();
+
+        let records =
+            PersonalRecords::from_entries(&meets, &entries, PointsSystem::Wilks, WeightUnits::Kg);
+        assert_eq!(records.squat, None);
+        assert_eq!(records.deadlift, None);
+        assert_eq!(records.bench.unwrap().value, WeightKg::from_i32(150));
+
+        let none = PersonalRecords::from_entries(&meets, &[], PointsSystem::Wilks, WeightUnits::Kg);
+        assert_eq!(none, PersonalRecords::default());
+    }
+
+    #[test]
+    fn test_bests_in_both_units() {
+        let langinfo = langpack::LangInfo::default();
+        let render = |units| {
+            let locale = Locale::new(&langinfo, Language::en, units);
+            let total = Some(WeightKg::from_i32(600));
+            let row = PersonalBestsRow::new(&locale, "Raw", None, None, None, total, None);
+            serde_json::to_string(&row.total).unwrap()
+        };
+
+        // Only the presentation changes: the total is stored in kilograms.
+        assert_eq!(render(WeightUnits::Kg), "\"600\"");
+        assert_eq!(render(WeightUnits::Lbs), "\"1322.7\"");
+
+        // Weight classes use their conventional pounds labels.
+        let class = |units| {
+            let class = WeightClassKg::UnderOrEqual(WeightKg::from_i32(90));
+            serde_json::to_string(
+                &class
+                    .as_type(units)
+                    .in_format(langpack::NumberFormat::ArabicPeriod),
+            )
+            .unwrap()
+        };
+        assert_eq!(class(WeightUnits::Kg), "\"90\"");
+        assert_eq!(class(WeightUnits::Lbs), "\"198\"");
+    }
 }
@@ -314,6 +314,7 @@ fn order_by_equipment(a: Equipment) -> u32 {
         Equipment::Multi => 3,
         Equipment::Unlimited => 4,
         Equipment::Straps => 5,
+        Equipment::Unknown => 6,
     }
 }
 
@@ -2,12 +2,18 @@
 
 // Common objects.
 pub mod jsdata;
+pub mod percentiles;
+pub mod projector;
+pub mod rivalries;
+pub mod topn;
 
 // Template context providers.
+pub mod comparison;
 pub mod contact;
 pub mod data;
 pub mod disambiguation;
 pub mod faq;
+pub mod federation;
 pub mod lifter;
 pub mod lifter_csv;
 pub mod meet;
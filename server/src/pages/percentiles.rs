@@ -0,0 +1,211 @@
+//! Logic for computing the distribution of points within a class.
+//!
+//! Used by "how do I stack up" charts, which show where a lifter falls
+//! within their sex/equipment/weightclass bucket.
+
+use opldb::query::direct::*;
+use opldb::{algorithms, OplDb};
+use opltypes::*;
+
+/// The percentiles reported for a bucket, in increasing order.
+pub const PERCENTILES: [u8; 5] = [10, 25, 50, 75, 90];
+
+/// The points value at a given percentile of a bucket.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+pub struct Breakpoint {
+    pub percentile: u8,
+    pub points: Points,
+}
+
+/// Calculates percentile breakpoints over a set of points.
+///
+/// Uses the nearest-rank method. A percentile is only reported if the bucket
+/// is large enough to distinguish it from its neighbor at the extreme:
+/// for example, the 10th percentile needs at least 10 values. Small buckets
+/// therefore return fewer breakpoints, and an empty bucket returns none.
+pub fn breakpoints(points: &[Points]) -> Vec<Breakpoint> {
+    let mut sorted: Vec<Points> = points.to_vec();
+    sorted.sort_unstable();
+    let n = sorted.len();
+
+    PERCENTILES
+        .iter()
+        .filter(|&&p| n * (p.min(100 - p) as usize) >= 100)
+        .map(|&p| Breakpoint {
+            percentile: p,
+            points: nearest_rank(&sorted, p),
+        })
+        .collect()
+}
+
+/// Gets the value at a percentile of a non-empty sorted list.
+fn nearest_rank(sorted: &[Points], percentile: u8) -> Points {
+    // Nearest rank: the smallest value with at least p% at or below it.
+    let rank = (sorted.len() * percentile as usize).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+/// Gets the points value at a percentile of a bucket, by nearest rank.
+///
+/// Returns `None` for an empty bucket.
+pub fn percentile_points(points: &[Points], percentile: u8) -> Option<Points> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let mut sorted: Vec<Points> = points.to_vec();
+    sorted.sort_unstable();
+    Some(nearest_rank(&sorted, percentile.min(100)))
+}
+
+/// Normalizes points to a 0-100 score by percentile rank within a bucket.
+///
+/// The score is the percentage of the bucket at or below the given points,
+/// so the strongest lifter scores 100 and the median lifter scores about 50.
+/// Because only the rank matters, scores are comparable across formulas.
+///
+/// Returns `None` for an empty bucket.
+pub fn strength_score(points: Points, bucket: &[Points]) -> Option<f32> {
+    if bucket.is_empty() {
+        return None;
+    }
+
+    let at_or_below = bucket.iter().filter(|&&p| p <= points).count();
+    Some(at_or_below as f32 * 100.0 / bucket.len() as f32)
+}
+
+/// Gets the points of each lifter's best entry for a rankings query.
+pub fn query_points(opldb: &OplDb, query: &RankingsQuery) -> Vec<Points> {
+    let system = PointsSystem::from(query.order_by);
+    let list = algorithms::full_sorted_uniqued(query, opldb);
+    list.0
+        .iter()
+        .map(|&n| opldb.entry(n).points(system, WeightUnits::Kg))
+        .collect()
+}
+
+/// Gets the points of each lifter's best entry within a class bucket,
+/// matching the rankings.
+fn class_points(
+    opldb: &OplDb,
+    sex: SexFilter,
+    equipment: EquipmentFilter,
+    weightclasses: WeightClassFilter,
+    order_by: OrderBy,
+) -> Vec<Points> {
+    let query = RankingsQuery {
+        filter: EntryFilter {
+            sex,
+            equipment,
+            weightclasses,
+            ..EntryFilter::default()
+        },
+        order_by,
+        ..RankingsQuery::default()
+    };
+    query_points(opldb, &query)
+}
+
+/// Calculates percentile breakpoints of a points system for a class bucket.
+///
+/// Each lifter contributes only their best entry within the bucket,
+/// matching the rankings.
+pub fn class_breakpoints(
+    opldb: &OplDb,
+    sex: SexFilter,
+    equipment: EquipmentFilter,
+    weightclasses: WeightClassFilter,
+    order_by: OrderBy,
+) -> Vec<Breakpoint> {
+    breakpoints(&class_points(
+        opldb,
+        sex,
+        equipment,
+        weightclasses,
+        order_by,
+    ))
+}
+
+/// Calculates the strength score of points within a class bucket.
+pub fn class_strength_score(
+    opldb: &OplDb,
+    points: Points,
+    sex: SexFilter,
+    equipment: EquipmentFilter,
+    weightclasses: WeightClassFilter,
+    order_by: OrderBy,
+) -> Option<f32> {
+    strength_score(
+        points,
+        &class_points(opldb, sex, equipment, weightclasses, order_by),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakpoint(percentile: u8, points: i32) -> Breakpoint {
+        Breakpoint {
+            percentile,
+            points: Points::from_i32(points),
+        }
+    }
+
+    #[test]
+    fn test_breakpoints_uniform() {
+        // The points 1..=100, in scrambled order.
+        let points: Vec<Points> = (1..=100)
+            .map(|i| Points::from_i32((i * 37) % 100 + 1))
+            .collect();
+
+        assert_eq!(
+            breakpoints(&points),
+            vec![
+                breakpoint(10, 10),
+                breakpoint(25, 25),
+                breakpoint(50, 50),
+                breakpoint(75, 75),
+                breakpoint(90, 90),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_small_buckets() {
+        assert!(breakpoints(&[]).is_empty());
+        assert!(breakpoints(&[Points::from_i32(300)]).is_empty());
+
+        let two = [Points::from_i32(400), Points::from_i32(300)];
+        assert_eq!(breakpoints(&two), vec![breakpoint(50, 300)]);
+
+        let four: Vec<Points> = (1..=4).map(|i| Points::from_i32(i * 100)).collect();
+        assert_eq!(
+            breakpoints(&four),
+            vec![
+                breakpoint(25, 100),
+                breakpoint(50, 200),
+                breakpoint(75, 300),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strength_score() {
+        // The points 1..=99, in scrambled order.
+        let points: Vec<Points> = (1..=99)
+            .map(|i| Points::from_i32((i * 37) % 99 + 1))
+            .collect();
+
+        let top = strength_score(Points::from_i32(99), &points).unwrap();
+        assert!((top - 100.0).abs() < 0.01);
+
+        let median = strength_score(Points::from_i32(50), &points).unwrap();
+        assert!((median - 50.0).abs() < 1.0);
+
+        let bottom = strength_score(Points::from_i32(1), &points).unwrap();
+        assert!(bottom < 2.0);
+
+        assert_eq!(strength_score(Points::from_i32(300), &[]), None);
+    }
+}
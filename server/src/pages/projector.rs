@@ -0,0 +1,121 @@
+//! Logic for projecting the total needed to reach a points goal.
+//!
+//! Used for setting season goals, like "what total do I need to be in the
+//! top 10% of my class by Dots?"
+
+use opldb::query::direct::*;
+use opldb::OplDb;
+use opltypes::*;
+
+use crate::pages::percentiles;
+
+/// Totals are projected in half-kilogram steps, up to 2000kg.
+const MAX_STEPS: i32 = 4000;
+
+/// Gets the total after some number of half-kilogram steps.
+fn total_at_step(step: i32) -> WeightKg {
+    WeightKg::from_raw(step * 50)
+}
+
+/// Calculates the full-power points of a hypothetical lifter.
+pub fn points_for_total(
+    system: PointsSystem,
+    sex: Sex,
+    equipment: Equipment,
+    bodyweight: WeightKg,
+    total: WeightKg,
+) -> Points {
+    let event = Event::sbd();
+    coefficients::points_for(system, sex, equipment, event, bodyweight, total, Age::None)
+}
+
+/// Finds the smallest total that scores at least the target points.
+///
+/// Points are non-decreasing in the total for every points system, so this
+/// inverts any of them by binary search. Returns `None` if the target is
+/// out of reach.
+pub fn required_total<F>(target: Points, points_for_total: F) -> Option<WeightKg>
+where
+    F: Fn(WeightKg) -> Points,
+{
+    let (mut lo, mut hi) = (0, MAX_STEPS);
+    if points_for_total(total_at_step(hi)) < target {
+        return None;
+    }
+
+    // Invariant: the total at `hi` always reaches the target.
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if points_for_total(total_at_step(mid)) >= target {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Some(total_at_step(hi))
+}
+
+/// Projects the total needed at a bodyweight to reach a percentile of
+/// a rankings bucket, under the bucket's points system.
+pub fn projected_total(
+    opldb: &OplDb,
+    query: &RankingsQuery,
+    percentile: u8,
+    sex: Sex,
+    equipment: Equipment,
+    bodyweight: WeightKg,
+) -> Option<WeightKg> {
+    let bucket = percentiles::query_points(opldb, query);
+    let target = percentiles::percentile_points(&bucket, percentile)?;
+
+    let system = PointsSystem::from(query.order_by);
+    required_total(target, |total| {
+        points_for_total(system, sex, equipment, bodyweight, total)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_total() {
+        // A bucket of 80kg men totalling 400kg through 800kg.
+        let bw = WeightKg::from_i32(80);
+        let bucket: Vec<Points> = (0..=40)
+            .map(|i| WeightKg::from_i32(400 + i * 10))
+            .map(|total| points_for_total(PointsSystem::Dots, Sex::M, Equipment::Raw, bw, total))
+            .collect();
+
+        let dots = |total| points_for_total(PointsSystem::Dots, Sex::M, Equipment::Raw, bw, total);
+        let total_at = |percentile| {
+            let target = percentiles::percentile_points(&bucket, percentile).unwrap();
+            required_total(target, dots)
+        };
+
+        // At the same bodyweight, the projection recovers the bucket's totals.
+        assert_eq!(total_at(50), Some(WeightKg::from_i32(600)));
+        assert_eq!(total_at(90), Some(WeightKg::from_i32(760)));
+        assert_eq!(total_at(100), Some(WeightKg::from_i32(800)));
+
+        // A lighter lifter needs less total for the same points.
+        let target = percentiles::percentile_points(&bucket, 50).unwrap();
+        let light = WeightKg::from_i32(60);
+        let lighter = required_total(target, |total| {
+            points_for_total(PointsSystem::Dots, Sex::M, Equipment::Raw, light, total)
+        });
+        assert!(lighter.unwrap() < WeightKg::from_i32(600));
+    }
+
+    #[test]
+    fn test_required_total_out_of_reach() {
+        let bw = WeightKg::from_i32(80);
+        let wilks =
+            |total| points_for_total(PointsSystem::Wilks, Sex::M, Equipment::Raw, bw, total);
+        assert_eq!(required_total(Points::from_i32(5000), wilks), None);
+        assert_eq!(
+            required_total(Points::from_i32(0), wilks),
+            Some(WeightKg::from_i32(0))
+        );
+    }
+}
@@ -3,8 +3,12 @@
 use langpack::Language;
 use opldb::query::direct::*;
 use opltypes::states::State;
+use serde::Serialize;
 
-use crate::pages::api_rankings::query_slice;
+use crate::pages::api_rankings::{query_page, RankingsSlice};
+use crate::pages::jsdata::JsEntryRow;
+
+use std::error;
 
 /// Flattened version of the RankingsQuery database object.
 ///
@@ -60,6 +64,13 @@ pub struct Context<'db, 'a> {
     pub selection: RankingsWidgets,
     pub default_selection: &'a RankingsQuery,
     pub initial_data: String,
+
+    /// The index of the first row in `initial_data`, counting from zero.
+    pub offset: usize,
+    /// How many rows are in `initial_data`.
+    pub page_length: usize,
+    /// How many rows are in the full ranking.
+    pub total_length: usize,
 }
 
 impl<'db, 'a> Context<'db, 'a> {
@@ -70,8 +81,8 @@ impl<'db, 'a> Context<'db, 'a> {
         defaults: &'a RankingsQuery,
         use_ipf_equipment: bool,
     ) -> Option<Context<'db, 'a>> {
-        // Inline the top 100 to avoid another round-trip.
-        let mut slice = query_slice(opldb, locale, selection, defaults, 0, 99);
+        // Inline the selected page, by default the top 100, to avoid another round-trip.
+        let mut slice = query_page(opldb, locale, selection, defaults);
 
         // If this is for the IPF, use different names for some equipment.
         if use_ipf_equipment {
@@ -95,6 +106,147 @@ impl<'db, 'a> Context<'db, 'a> {
             selection: RankingsWidgets::from(selection),
             default_selection: defaults,
             initial_data: serde_json::to_string(&slice).ok()?,
+            offset: selection.offset.min(slice.total_length),
+            page_length: slice.rows.len(),
+            total_length: slice.total_length,
         })
     }
 }
+
+/// One row of a rankings CSV export, in the order of the on-screen table.
+///
+/// Borrows the localized values of a `JsEntryRow`, so the CSV is formatted
+/// exactly as the page is. Missing values are written as empty cells.
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct CsvRow<'r, 'db> {
+    rank: &'r langpack::LocalizedOrdinal,
+    name: &'db str,
+    federation: opltypes::Federation,
+    date: &'r str,
+    meet_country: &'db str,
+    meet_state: Option<&'db str>,
+    lifter_country: Option<&'db str>,
+    lifter_state: Option<State>,
+    sex: &'db str,
+    age: &'r opltypes::PrettyAge,
+    equipment: &'db str,
+    division: Option<&'db str>,
+    weight_class: &'r langpack::LocalizedWeightClassAny,
+    bodyweight: &'r langpack::LocalizedWeightAny,
+    squat: &'r langpack::LocalizedWeightAny,
+    bench: &'r langpack::LocalizedWeightAny,
+    deadlift: &'r langpack::LocalizedWeightAny,
+    total: &'r langpack::LocalizedWeightAny,
+    points: &'r langpack::LocalizedPoints,
+    dots: Option<&'r langpack::LocalizedPoints>,
+    glossbrenner: Option<&'r langpack::LocalizedPoints>,
+}
+
+impl<'r, 'db> From<&'r JsEntryRow<'db>> for CsvRow<'r, 'db> {
+    fn from(row: &'r JsEntryRow<'db>) -> Self {
+        CsvRow {
+            rank: &row.rank,
+            name: row.name,
+            federation: row.federation,
+            date: &row.date,
+            meet_country: row.meet_country,
+            meet_state: row.meet_state,
+            lifter_country: row.lifter_country,
+            lifter_state: row.lifter_state,
+            sex: row.sex,
+            age: &row.age,
+            equipment: row.equipment,
+            division: row.division,
+            weight_class: &row.weightclass,
+            bodyweight: &row.bodyweight,
+            squat: &row.squat,
+            bench: &row.bench,
+            deadlift: &row.deadlift,
+            total: &row.total,
+            points: &row.points,
+            dots: row.dots.as_ref(),
+            glossbrenner: row.glossbrenner.as_ref(),
+        }
+    }
+}
+
+/// Exports the rows of a rankings view as a CSV file, in their sorted order.
+pub fn to_csv(slice: &RankingsSlice) -> Result<String, Box<dyn error::Error>> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
+    for row in &slice.rows {
+        wtr.serialize(CsvRow::from(row))?;
+    }
+    Ok(String::from_utf8(wtr.into_inner()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use langpack::{LocalizeNumber, NumberFormat};
+    use opltypes::*;
+
+    #[test]
+    fn test_to_csv() {
+        let format = NumberFormat::ArabicPeriod;
+        let weight = |kg: i32| {
+            WeightKg::from_i32(kg)
+                .as_type(WeightUnits::Kg)
+                .in_format(format)
+        };
+        let row = JsEntryRow {
+            sorted_index: 0,
+            rank: langpack::LocalizedOrdinal::from(1, langpack::Language::en, Sex::M),
+            name: "Test User",
+            username: "testuser",
+            instagram: None,
+            vkontakte: None,
+            color: None,
+            flair: None,
+            lifter_country: Some("USA"),
+            lifter_state: None,
+            federation: Federation::WRPF,
+            date: "2020-01-01".into(),
+            meet_country: "USA",
+            meet_state: Some("NY"),
+            path: "wrpf/2001",
+            sex: "M",
+            equipment: "Raw",
+            age: PrettyAge::from(Age::Exact(30)),
+            division: None,
+            bodyweight: weight(0),
+            weightclass: WeightClassKg::UnderOrEqual(WeightKg::from_i32(90))
+                .as_type(WeightUnits::Kg)
+                .in_format(format),
+            squat: weight(200),
+            bench: weight(150),
+            deadlift: weight(250),
+            total: weight(600),
+            points: Points::from_i32(400).in_format(format),
+            dots: Some(Points::from_i32(390).in_format(format)),
+            glossbrenner: None,
+        };
+        let slice = RankingsSlice {
+            total_length: 1,
+            rows: vec![row],
+        };
+
+        let csv = to_csv(&slice).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some(
+                "Rank,Name,Federation,Date,MeetCountry,MeetState,LifterCountry,LifterState,\
+                 Sex,Age,Equipment,Division,WeightClass,Bodyweight,Squat,Bench,Deadlift,Total,Points,\
+                 Dots,Glossbrenner"
+            )
+        );
+
+        // The missing bodyweight, division, state, and points are empty cells.
+        assert_eq!(
+            lines.next(),
+            Some("1st,Test User,WRPF,2020-01-01,USA,NY,USA,,M,30,Raw,,90,,200,150,250,600,400.00,390.00,")
+        );
+        assert_eq!(lines.next(), None);
+    }
+}
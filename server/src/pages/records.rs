@@ -8,6 +8,7 @@ use opltypes::states::*;
 use opltypes::*;
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::path;
 use std::str::FromStr;
@@ -756,3 +757,189 @@ impl<'db> Context<'db> {
         }
     }
 }
+
+/// Identifies a category of all-time records.
+pub type RecordsKey = (Sex, Equipment, WeightClassKg);
+
+/// The best value of a single lift within a category.
+///
+/// The record holder and meet are given by the Entry.
+#[derive(Copy, Clone, Debug)]
+pub struct Record<'db> {
+    pub value: WeightKg,
+    pub entry: &'db Entry,
+}
+
+/// The all-time records of a single category.
+#[derive(Default)]
+pub struct ClassRecords<'db> {
+    pub squat: Option<Record<'db>>,
+    pub bench: Option<Record<'db>>,
+    pub deadlift: Option<Record<'db>>,
+    pub total: Option<Record<'db>>,
+}
+
+/// Maybe replaces a record with the given Entry.
+fn integrate_record<'db, F>(
+    record: &mut Option<Record<'db>>,
+    meets: &[Meet],
+    entry: &'db Entry,
+    value: WeightKg,
+    compare: F,
+) where
+    F: Fn(&[Meet], &Entry, &Entry) -> Ordering,
+{
+    if record.is_none_or(|r| compare(meets, entry, r.entry) == Ordering::Less) {
+        *record = Some(Record { value, entry });
+    }
+}
+
+impl<'db> ClassRecords<'db> {
+    fn integrate(&mut self, meets: &[Meet], entry: &'db Entry) {
+        // The filters exclude disqualified entries and missing lifts.
+        if algorithms::filter_squat(entry) {
+            let value = entry.highest_squatkg();
+            integrate_record(&mut self.squat, meets, entry, value, algorithms::cmp_squat);
+        }
+        if algorithms::filter_bench(entry) {
+            let value = entry.highest_benchkg();
+            integrate_record(&mut self.bench, meets, entry, value, algorithms::cmp_bench);
+        }
+        if algorithms::filter_deadlift(entry) {
+            let value = entry.highest_deadliftkg();
+            integrate_record(
+                &mut self.deadlift,
+                meets,
+                entry,
+                value,
+                algorithms::cmp_deadlift,
+            );
+        }
+        if entry.event.is_full_power() && algorithms::filter_total(entry) {
+            let value = entry.totalkg;
+            integrate_record(&mut self.total, meets, entry, value, algorithms::cmp_total);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.squat.is_none()
+            && self.bench.is_none()
+            && self.deadlift.is_none()
+            && self.total.is_none()
+    }
+}
+
+/// All-time records for every sex, equipment, and weight class.
+///
+/// Entries are bucketed by their official WeightClassKg.
+/// Categories without any record-eligible entries are omitted.
+pub struct RecordsContext<'db> {
+    pub records: HashMap<RecordsKey, ClassRecords<'db>>,
+}
+
+impl<'db> RecordsContext<'db> {
+    /// Scans the entries for records.
+    pub fn from_entries<I>(meets: &[Meet], entries: I) -> RecordsContext<'db>
+    where
+        I: IntoIterator<Item = &'db Entry>,
+    {
+        let mut records: HashMap<RecordsKey, ClassRecords<'db>> = HashMap::new();
+        for entry in entries {
+            if entry.weightclasskg == WeightClassKg::None {
+                continue;
+            }
+            let key = (entry.sex, entry.equipment, entry.weightclasskg);
+            records.entry(key).or_default().integrate(meets, entry);
+        }
+
+        records.retain(|_, r| !r.is_empty());
+        RecordsContext { records }
+    }
+
+    /// Scans the whole database for records.
+    pub fn new(opldb: &'db OplDb) -> RecordsContext<'db> {
+        Self::from_entries(opldb.meets(), opldb.entries())
+    }
+
+    /// Gets the records of a category, if it has any.
+    pub fn get(
+        &self,
+        sex: Sex,
+        equipment: Equipment,
+        weightclass: WeightClassKg,
+    ) -> Option<&ClassRecords<'db>> {
+        self.records.get(&(sex, equipment, weightclass))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::num::NonZeroU8;
+
+    #[test]
+    fn test_records_context() {
+        let meets = vec![Meet {
+            path: "test/1".into(),
+            federation: Federation::WRPF,
+            date: Date::from_parts(2020, 1, 1),
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 0,
+        }];
+
+        let class = |kg| WeightClassKg::UnderOrEqual(WeightKg::from_i32(kg));
+        let entry = |lifter_id, equipment, weightclass, squat, bench, deadlift, place| Entry {
+            lifter_id,
+            sex: Sex::M,
+            event: Event::sbd(),
+            equipment,
+            weightclasskg: weightclass,
+            bodyweightkg: WeightKg::from_i32(89),
+            best3squatkg: WeightKg::from_i32(squat),
+            best3benchkg: WeightKg::from_i32(bench),
+            best3deadliftkg: WeightKg::from_i32(deadlift),
+            totalkg: WeightKg::from_i32(if place == Place::DQ {
+                0
+            } else {
+                squat + bench + deadlift
+            }),
+            place,
+            ..Entry::default()
+        };
+        let first = Place::P(NonZeroU8::new(1).unwrap());
+
+        let entries = vec![
+            entry(0, Equipment::Raw, class(90), 250, 150, 300, first),
+            entry(1, Equipment::Raw, class(90), 260, 140, 290, first),
+            // A disqualified entry can't set records.
+            entry(2, Equipment::Raw, class(90), 400, 300, 400, Place::DQ),
+            // A missing bench doesn't displace the record.
+            entry(3, Equipment::Raw, class(90), 0, 0, 310, first),
+            // Equipped lifters have their own records.
+            entry(4, Equipment::Single, class(90), 350, 250, 320, first),
+            // A class with only a disqualified entry is omitted.
+            entry(5, Equipment::Raw, class(100), 300, 200, 300, Place::DQ),
+        ];
+
+        let cx = RecordsContext::from_entries(&meets, &entries);
+        assert_eq!(cx.records.len(), 2);
+        assert!(cx.get(Sex::M, Equipment::Raw, class(100)).is_none());
+        assert!(cx.get(Sex::F, Equipment::Raw, class(90)).is_none());
+
+        let raw = cx.get(Sex::M, Equipment::Raw, class(90)).unwrap();
+        let squat = raw.squat.unwrap();
+        assert_eq!(squat.value, WeightKg::from_i32(260));
+        assert_eq!(squat.entry.lifter_id, 1);
+        assert_eq!(raw.bench.unwrap().entry.lifter_id, 0);
+        assert_eq!(raw.deadlift.unwrap().entry.lifter_id, 3);
+        assert_eq!(raw.total.unwrap().value, WeightKg::from_i32(700));
+
+        let single = cx.get(Sex::M, Equipment::Single, class(90)).unwrap();
+        assert_eq!(single.total.unwrap().entry.lifter_id, 4);
+    }
+}
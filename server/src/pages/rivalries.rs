@@ -0,0 +1,211 @@
+//! Logic for finding a lifter's most frequent head-to-head opponents.
+
+use opldb::{Entry, OplDb};
+use opltypes::*;
+
+use std::collections::{HashMap, HashSet};
+
+/// Opponents must have been faced at least this many times to be a rivalry.
+pub const MIN_SHARED_MEETS: u32 = 2;
+
+/// A frequent opponent, with the lifter's record against them.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+pub struct Rivalry {
+    /// The LifterID of the opponent.
+    pub lifter_id: u32,
+    /// How many meets both lifters competed in, in the same division.
+    pub meets: u32,
+    /// How many times the lifter placed ahead of the opponent.
+    pub wins: u32,
+    /// How many times the opponent placed ahead of the lifter.
+    pub losses: u32,
+}
+
+/// Whether two entries at the same meet were placed against each other.
+fn same_division(a: &Entry, b: &Entry) -> bool {
+    a.meet_id == b.meet_id
+        && a.sex == b.sex
+        && a.event == b.event
+        && a.equipment == b.equipment
+        && a.weightclasskg == b.weightclasskg
+        && a.division == b.division
+}
+
+/// Whether the Place outranks another Place in the same division.
+///
+/// Placed lifters outrank disqualified lifters.
+/// Guest lifters are not compared against anyone.
+fn outranks(a: Place, b: Place) -> bool {
+    match (a, b) {
+        (Place::P(a), Place::P(b)) => a < b,
+        (Place::P(_), p) => p.is_dq(),
+        _ => false,
+    }
+}
+
+/// Ranks the opponents of a lifter by how often they met in the same division.
+///
+/// The `entries_for_meet` callback provides all the entries for a MeetID.
+/// Rivalries are ordered by number of shared meets, most first.
+///
+/// A meet is only counted once per opponent, even if both lifters entered
+/// several of the same divisions there. The first shared division decides
+/// the result for that meet.
+pub fn find_rivalries<'db, F>(
+    lifter_id: u32,
+    lifter_entries: &[&'db Entry],
+    entries_for_meet: F,
+) -> Vec<Rivalry>
+where
+    F: Fn(u32) -> Vec<&'db Entry>,
+{
+    let mut by_opponent: HashMap<u32, Rivalry> = HashMap::new();
+    let mut counted: HashSet<(u32, u32)> = HashSet::new();
+
+    for entry in lifter_entries {
+        for other in entries_for_meet(entry.meet_id) {
+            if other.lifter_id == lifter_id || !same_division(entry, other) {
+                continue;
+            }
+            if !counted.insert((other.lifter_id, entry.meet_id)) {
+                continue;
+            }
+
+            let rivalry = by_opponent.entry(other.lifter_id).or_insert(Rivalry {
+                lifter_id: other.lifter_id,
+                meets: 0,
+                wins: 0,
+                losses: 0,
+            });
+
+            rivalry.meets += 1;
+            if outranks(entry.place, other.place) {
+                rivalry.wins += 1;
+            } else if outranks(other.place, entry.place) {
+                rivalry.losses += 1;
+            }
+        }
+    }
+
+    let mut rivalries: Vec<Rivalry> = by_opponent
+        .into_values()
+        .filter(|r| r.meets >= MIN_SHARED_MEETS)
+        .collect();
+
+    // Most frequent first, with ties broken by LifterID for stability.
+    rivalries.sort_unstable_by(|a, b| b.meets.cmp(&a.meets).then(a.lifter_id.cmp(&b.lifter_id)));
+    rivalries
+}
+
+/// Ranks the opponents of a lifter by how often they met in the same division.
+pub fn rivalries(opldb: &OplDb, lifter_id: u32) -> Vec<Rivalry> {
+    let entries = opldb.entries_for_lifter(lifter_id);
+    find_rivalries(lifter_id, &entries, |meet_id| {
+        opldb.entries_for_meet(meet_id)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::num::NonZeroU8;
+
+    fn entry(meet_id: u32, lifter_id: u32, place: u8) -> Entry {
+        Entry {
+            meet_id,
+            lifter_id,
+            place: Place::P(NonZeroU8::new(place).unwrap()),
+            ..Entry::default()
+        }
+    }
+
+    #[test]
+    fn test_rivalries() {
+        let entries = [
+            // Lifter 0 beats lifter 1 twice, but loses once.
+            entry(0, 0, 1),
+            entry(0, 1, 2),
+            entry(1, 0, 2),
+            entry(1, 1, 1),
+            entry(2, 0, 1),
+            entry(2, 1, 2),
+            // Lifter 0 mostly loses to lifter 2.
+            entry(2, 2, 3),
+            entry(3, 0, 2),
+            entry(3, 2, 1),
+            entry(4, 0, 3),
+            entry(4, 2, 1),
+            // Lifter 3 was only faced once.
+            entry(4, 3, 2),
+        ];
+
+        let lifter_entries: Vec<&Entry> = entries.iter().filter(|e| e.lifter_id == 0).collect();
+        let for_meet = |meet_id| entries.iter().filter(|e| e.meet_id == meet_id).collect();
+        let rivalries = find_rivalries(0, &lifter_entries, for_meet);
+
+        assert_eq!(
+            rivalries,
+            vec![
+                Rivalry {
+                    lifter_id: 1,
+                    meets: 3,
+                    wins: 2,
+                    losses: 1
+                },
+                Rivalry {
+                    lifter_id: 2,
+                    meets: 3,
+                    wins: 1,
+                    losses: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rivalries_require_same_division() {
+        let mut entries = [
+            entry(0, 0, 1),
+            entry(0, 1, 1),
+            entry(1, 0, 1),
+            entry(1, 1, 1),
+        ];
+        entries[1].equipment = Equipment::Raw;
+        entries[3].equipment = Equipment::Raw;
+
+        let lifter_entries: Vec<&Entry> = entries.iter().filter(|e| e.lifter_id == 0).collect();
+        let for_meet = |meet_id| entries.iter().filter(|e| e.meet_id == meet_id).collect();
+        assert!(find_rivalries(0, &lifter_entries, for_meet).is_empty());
+    }
+
+    #[test]
+    fn test_rivalries_count_meets_once() {
+        // Both lifters entered the Open and Masters divisions at each meet.
+        let mut entries = [
+            entry(0, 0, 1),
+            entry(0, 1, 2),
+            entry(0, 0, 1),
+            entry(0, 1, 2),
+            entry(1, 0, 2),
+            entry(1, 1, 1),
+            entry(1, 0, 2),
+            entry(1, 1, 1),
+        ];
+        for i in [2, 3, 6, 7] {
+            entries[i].division = Some("Masters".into());
+        }
+
+        let lifter_entries: Vec<&Entry> = entries.iter().filter(|e| e.lifter_id == 0).collect();
+        let for_meet = |meet_id| entries.iter().filter(|e| e.meet_id == meet_id).collect();
+        assert_eq!(
+            find_rivalries(0, &lifter_entries, for_meet),
+            vec![Rivalry {
+                lifter_id: 1,
+                meets: 2,
+                wins: 1,
+                losses: 1
+            }]
+        );
+    }
+}
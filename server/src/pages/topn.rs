@@ -0,0 +1,201 @@
+//! Streaming construction of the top of a rankings list.
+//!
+//! Materializing a full rankings list sorts every matching entry.
+//! When only the first few rows are displayed, a bounded list can instead
+//! be maintained while streaming over the entries, keeping memory use
+//! proportional to the number of rows requested.
+
+use opldb::algorithms::{self, TieBreak};
+use opldb::query::direct::*;
+use opldb::{Entry, Meet, OplDb};
+
+use std::cmp::Ordering;
+
+/// A bounded, sorted list of the best entries seen so far, one per lifter.
+pub struct TopN<'db, F> {
+    capacity: usize,
+    meets: &'db [Meet],
+    compare: F,
+    /// Best first, with at most one entry per lifter.
+    rows: Vec<&'db Entry>,
+}
+
+impl<'db, F> TopN<'db, F>
+where
+    F: Fn(&[Meet], &Entry, &Entry) -> Ordering,
+{
+    /// Creates an empty list holding at most `capacity` rows.
+    ///
+    /// The `compare` function orders better entries as `Ordering::Less`,
+    /// matching the comparators in `opldb::algorithms`.
+    pub fn new(capacity: usize, meets: &'db [Meet], compare: F) -> Self {
+        TopN {
+            capacity,
+            meets,
+            compare,
+            rows: Vec::with_capacity(capacity + 1),
+        }
+    }
+
+    /// Offers an entry to the list, keeping it only if it ranks in the top.
+    pub fn push(&mut self, entry: &'db Entry) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let meets = self.meets;
+        let compare = &self.compare;
+
+        // Each lifter is only listed by their best entry.
+        if let Some(i) = self
+            .rows
+            .iter()
+            .position(|e| e.lifter_id == entry.lifter_id)
+        {
+            if compare(meets, entry, self.rows[i]) != Ordering::Less {
+                return;
+            }
+            self.rows.remove(i);
+        } else if self.rows.len() == self.capacity {
+            let worst = self.rows[self.rows.len() - 1];
+            if compare(meets, entry, worst) != Ordering::Less {
+                return;
+            }
+        }
+
+        let index = self
+            .rows
+            .partition_point(|e| compare(meets, e, entry) != Ordering::Greater);
+        self.rows.insert(index, entry);
+        self.rows.truncate(self.capacity);
+    }
+
+    /// Gets the rows, best first.
+    pub fn into_rows(self) -> Vec<&'db Entry> {
+        self.rows
+    }
+}
+
+/// Streams over entries, returning the best `n` lifters under a rankings sort.
+///
+/// The result matches the first `n` rows of the fully-materialized rankings
+/// with the same sort and tie-breaks.
+pub fn streaming_top_n<'db, I>(
+    entries: I,
+    meets: &'db [Meet],
+    order_by: OrderBy,
+    tie_breaks: &[TieBreak],
+    n: usize,
+) -> Vec<&'db Entry>
+where
+    I: IntoIterator<Item = &'db Entry>,
+{
+    let compare = |meets: &[Meet], a: &Entry, b: &Entry| {
+        algorithms::cmp_with_tie_breaks(order_by, tie_breaks, meets, a, b)
+    };
+    let belongs = algorithms::filter_for(order_by);
+
+    let mut top = TopN::new(n, meets, compare);
+    for entry in entries.into_iter().filter(|e| belongs(e)) {
+        top.push(entry);
+    }
+    top.into_rows()
+}
+
+/// Gets the first `n` rows of the rankings for a query, without sorting
+/// the full list.
+///
+/// The entries matching the filter are still gathered from the caches,
+/// which are borrowed unless a filter needs an owned intersection.
+pub fn rankings_top_n<'db>(opldb: &'db OplDb, query: &RankingsQuery, n: usize) -> Vec<&'db Entry> {
+    let tie_breaks = query
+        .tie_breaks
+        .unwrap_or_else(|| algorithms::default_tie_breaks(query.order_by));
+    let indices = algorithms::entry_indices_for(&query.filter, opldb);
+    let entries = indices.0.iter().map(|&i| opldb.entry(i));
+    streaming_top_n(entries, opldb.meets(), query.order_by, tie_breaks, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opldb::Lifter;
+    use opltypes::*;
+
+    fn meet(date: Date) -> Meet {
+        Meet {
+            path: "test/1".into(),
+            federation: Federation::WRPF,
+            date,
+            country: Country::USA,
+            state: None,
+            town: None,
+            name: "Test Meet".into(),
+            ruleset: RuleSet::default(),
+            num_unique_lifters: 0,
+        }
+    }
+
+    fn lifter(i: u32) -> Lifter {
+        Lifter {
+            name: format!("Lifter {}", i).as_str().into(),
+            cyrillic_name: None,
+            greek_name: None,
+            japanese_name: None,
+            korean_name: None,
+            username: Username::from_name(&format!("lifter{}", i)).unwrap(),
+            instagram: None,
+            vkontakte: None,
+            color: None,
+            flair: None,
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_materialized() {
+        let meets: Vec<Meet> = (1..=4)
+            .map(|month| meet(Date::from_parts(2020, month, 1)))
+            .collect();
+
+        // Scatter lifters across meets, with repeat entries, ties, and a DQ.
+        let mut entries: Vec<Entry> = (0..60)
+            .map(|i| Entry {
+                meet_id: i % 4,
+                lifter_id: (i * 7) % 23,
+                equipment: Equipment::Raw,
+                bodyweightkg: WeightKg::from_i32(((i * 11) % 40 + 60) as i32),
+                totalkg: WeightKg::from_i32(((i * 37) % 50 + 300) as i32),
+                place: if i == 13 { Place::DQ } else { Place::G },
+                ..Entry::default()
+            })
+            .collect();
+        entries.sort_by_key(|e| e.meet_id);
+        let db = OplDb::from_parts((0..23).map(lifter).collect(), meets, entries);
+
+        const LIGHTER_FIRST: [TieBreak; 2] = [TieBreak::LowerBodyweight, TieBreak::LowerLifterId];
+        let by_total = RankingsQuery::default().with_order_by(OrderBy::Total);
+        let queries = [
+            // Served from the constant-time cache.
+            by_total,
+            // Sorted from the filtered entries.
+            by_total.with_year(YearFilter::OneYear(2020)),
+            // Sorted with custom tie-breaks.
+            by_total.with_tie_breaks(&LIGHTER_FIRST),
+        ];
+
+        for query in &queries {
+            let materialized = algorithms::full_sorted_uniqued(query, &db);
+            for &n in &[0, 1, 5, 10, 23, 100] {
+                let streamed: Vec<*const Entry> = rankings_top_n(&db, query, n)
+                    .into_iter()
+                    .map(|e| e as *const Entry)
+                    .collect();
+                let expected: Vec<*const Entry> = materialized.0[..n.min(materialized.0.len())]
+                    .iter()
+                    .map(|&i| db.entry(i) as *const Entry)
+                    .collect();
+                assert_eq!(streamed, expected, "{:?}, n = {}", query, n);
+            }
+        }
+    }
+}